@@ -0,0 +1,37 @@
+-- The original distributions table only held lines; keep them as distribution_lines
+-- and introduce a header table carrying the lifecycle.
+ALTER TABLE distributions RENAME TO distribution_lines;
+
+CREATE TABLE IF NOT EXISTS distributions (
+    id TEXT PRIMARY KEY,
+    creator VARCHAR(42) NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'draft'
+        CHECK (status IN ('draft', 'submitted', 'confirmed', 'failed')),
+    tx_hash TEXT,
+    chain_id BIGINT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Headers for lines registered before this migration; their creator is unknown
+INSERT INTO distributions (id, creator)
+SELECT DISTINCT distribution_id, '0x0000000000000000000000000000000000000000'
+FROM distribution_lines;
+
+DELETE FROM distribution_lines a
+USING distribution_lines b
+WHERE a.ctid < b.ctid
+  AND a.distribution_id = b.distribution_id
+  AND a.address = b.address
+  AND a.badge_name = b.badge_name;
+
+-- TGC has 18 decimals, so amounts in base units overflow BIGINT past ~9.22 TGC.
+-- NUMERIC(78,0) holds any uint256.
+ALTER TABLE distribution_lines ADD COLUMN IF NOT EXISTS amount NUMERIC(78,0);
+ALTER TABLE distribution_lines
+    ADD CONSTRAINT fk_distribution FOREIGN KEY (distribution_id)
+    REFERENCES distributions(id) ON DELETE CASCADE;
+ALTER TABLE distribution_lines
+    ADD CONSTRAINT unique_distribution_line UNIQUE (distribution_id, address, badge_name);
+
+CREATE INDEX IF NOT EXISTS idx_distributions_status ON distributions(status);
@@ -0,0 +1,2 @@
+-- Justification for badge attestations, reason for contribution token mints
+ALTER TABLE distribution_lines ADD COLUMN IF NOT EXISTS reason TEXT;
@@ -0,0 +1,11 @@
+-- Full-text search over profile name, description and GitHub login.
+-- The expression must match SEARCH_DOCUMENT in postgres_profile_repository.rs.
+CREATE INDEX IF NOT EXISTS idx_profiles_search ON profiles USING GIN (
+    to_tsvector('simple', COALESCE(name, '') || ' ' || COALESCE(description, '') || ' ' || COALESCE(github_login, ''))
+);
+
+-- Keyset pagination orders by created_at then address
+CREATE INDEX IF NOT EXISTS idx_profiles_created_at ON profiles (created_at, address COLLATE "C");
+
+-- has_badges filter looks up lines by recipient
+CREATE INDEX IF NOT EXISTS idx_distribution_lines_address_lower ON distribution_lines (LOWER(address));
@@ -0,0 +1,10 @@
+-- Full-text search over project name and description.
+-- The expression must match SEARCH_DOCUMENT in postgres_project_repository.rs.
+CREATE INDEX IF NOT EXISTS idx_projects_search ON projects USING GIN (
+    to_tsvector('simple', name || ' ' || description)
+);
+
+-- Keyset pagination orders by the sort column, then id
+DROP INDEX IF EXISTS idx_projects_created_at;
+CREATE INDEX IF NOT EXISTS idx_projects_created_at_id ON projects (created_at, id);
+CREATE INDEX IF NOT EXISTS idx_projects_updated_at_id ON projects (updated_at, id);
@@ -0,0 +1,139 @@
+-- Wallets attached to a project with a role. Invitations are rows in the
+-- 'invited' status until the invitee accepts them.
+--
+-- Member addresses are stored in EIP-55 checksum form, which need not match
+-- the casing a profile was created with, so members are matched to profiles
+-- case-insensitively instead of through a foreign key.
+CREATE TABLE IF NOT EXISTS project_members (
+    project_id UUID NOT NULL,
+    address VARCHAR(42) NOT NULL,
+    role VARCHAR(20) NOT NULL CHECK (role IN ('maintainer', 'contributor', 'reviewer')),
+    status VARCHAR(20) NOT NULL CHECK (status IN ('invited', 'active')),
+    invited_by VARCHAR(42),
+    invited_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    joined_at TIMESTAMPTZ,
+    PRIMARY KEY (project_id, address),
+    CONSTRAINT fk_project FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_members_address ON project_members(LOWER(address));
+CREATE UNIQUE INDEX IF NOT EXISTS idx_project_members_project_address
+    ON project_members(project_id, LOWER(address));
+
+
+CREATE OR REPLACE FUNCTION delete_profile_memberships()
+RETURNS TRIGGER AS $$
+BEGIN
+    DELETE FROM project_members WHERE LOWER(address) = LOWER(OLD.address);
+    RETURN OLD;
+END;
+$$ LANGUAGE plpgsql;
+
+
+CREATE TRIGGER trigger_delete_profile_memberships
+    AFTER DELETE ON profiles
+    FOR EACH ROW
+    EXECUTE FUNCTION delete_profile_memberships();
+
+
+-- EIP-55 checksum of a 0x-prefixed address: hex letters are uppercased where
+-- the matching nibble of keccak256(lowercase hex) is 8 or more. Only used to
+-- seed the existing creators below.
+CREATE FUNCTION pg_temp.eip55_checksum(address TEXT)
+RETURNS TEXT AS $$
+DECLARE
+    hex TEXT := LOWER(SUBSTRING(address FROM 3));
+    round_constants BIGINT[] := ARRAY[
+        x'0000000000000001', x'0000000000008082', x'800000000000808A', x'8000000080008000',
+        x'000000000000808B', x'0000000080000001', x'8000000080008081', x'8000000000008009',
+        x'000000000000008A', x'0000000000000088', x'0000000080008009', x'000000008000000A',
+        x'000000008000808B', x'800000000000008B', x'8000000000008089', x'8000000000008003',
+        x'8000000000008002', x'8000000000000080', x'000000000000800A', x'800000008000000A',
+        x'8000000080008081', x'8000000000008080', x'0000000080000001', x'8000000080008008'
+    ]::BIT(64)[]::BIGINT[];
+    -- Rotation offsets, indexed by x + 5y
+    rotations INT[] := ARRAY[
+        0, 1, 62, 28, 27,
+        36, 44, 6, 55, 20,
+        3, 10, 43, 25, 39,
+        41, 45, 15, 21, 8,
+        18, 2, 61, 56, 14
+    ];
+    state BIGINT[] := array_fill(0::BIGINT, ARRAY[25]);
+    b BIGINT[] := array_fill(0::BIGINT, ARRAY[25]);
+    c BIGINT[] := array_fill(0::BIGINT, ARRAY[5]);
+    d BIGINT;
+    lane BIGINT;
+    n INT;
+    byte INT;
+    nibble INT;
+    checksummed TEXT := '0x';
+BEGIN
+    IF address !~ '^0x[0-9a-fA-F]{40}$' THEN
+        RETURN address;
+    END IF;
+
+    -- Absorb the 40 ASCII bytes with keccak padding into the 136-byte rate
+    FOR i IN 0..135 LOOP
+        byte := CASE
+            WHEN i < 40 THEN ASCII(SUBSTRING(hex FROM i + 1 FOR 1))
+            WHEN i = 40 THEN 1
+            ELSE 0
+        END;
+        IF i = 135 THEN
+            byte := byte | 128;
+        END IF;
+        state[i / 8 + 1] := state[i / 8 + 1] # (byte::BIGINT << (8 * (i % 8)));
+    END LOOP;
+
+    FOR round IN 1..24 LOOP
+        -- theta
+        FOR x IN 0..4 LOOP
+            c[x + 1] := state[x + 1] # state[x + 6] # state[x + 11] # state[x + 16] # state[x + 21];
+        END LOOP;
+        FOR x IN 0..4 LOOP
+            lane := c[(x + 1) % 5 + 1];
+            d := c[(x + 4) % 5 + 1] # ((lane << 1) | ((lane >> 63) & 1));
+            FOR y IN 0..4 LOOP
+                state[x + 5 * y + 1] := state[x + 5 * y + 1] # d;
+            END LOOP;
+        END LOOP;
+        -- rho and pi
+        FOR x IN 0..4 LOOP
+            FOR y IN 0..4 LOOP
+                lane := state[x + 5 * y + 1];
+                n := rotations[x + 5 * y + 1];
+                IF n > 0 THEN
+                    lane := (lane << n) | ((lane >> (64 - n)) & ~(-1::BIGINT << n));
+                END IF;
+                b[y + 5 * ((2 * x + 3 * y) % 5) + 1] := lane;
+            END LOOP;
+        END LOOP;
+        -- chi
+        FOR x IN 0..4 LOOP
+            FOR y IN 0..4 LOOP
+                state[x + 5 * y + 1] := b[x + 5 * y + 1]
+                    # (~b[(x + 1) % 5 + 5 * y + 1] & b[(x + 2) % 5 + 5 * y + 1]);
+            END LOOP;
+        END LOOP;
+        -- iota
+        state[1] := state[1] # round_constants[round];
+    END LOOP;
+
+    FOR i IN 0..39 LOOP
+        byte := ((state[i / 16 + 1] >> (8 * ((i / 2) % 8))) & 255)::INT;
+        nibble := CASE WHEN i % 2 = 0 THEN byte >> 4 ELSE byte & 15 END;
+        checksummed := checksummed || CASE
+            WHEN nibble >= 8 THEN UPPER(SUBSTRING(hex FROM i + 1 FOR 1))
+            ELSE SUBSTRING(hex FROM i + 1 FOR 1)
+        END;
+    END LOOP;
+    RETURN checksummed;
+END;
+$$ LANGUAGE plpgsql IMMUTABLE;
+
+-- Existing creators become the maintainers of their projects
+INSERT INTO project_members (project_id, address, role, status, invited_at, joined_at)
+SELECT id, pg_temp.eip55_checksum(creator), 'maintainer', 'active', created_at, created_at
+FROM projects
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,35 @@
+-- Governed lifecycle: proposal → under_review → approved → ongoing → completed → archived,
+-- with rejected as the other outcome of a review.
+ALTER TABLE projects DROP CONSTRAINT IF EXISTS projects_status_check;
+ALTER TABLE projects ADD CONSTRAINT projects_status_check CHECK (
+    status IN ('proposal', 'under_review', 'approved', 'ongoing', 'completed', 'archived', 'rejected')
+);
+
+-- Every status change; from_status is NULL for the entry written at creation
+CREATE TABLE IF NOT EXISTS project_history (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    project_id UUID NOT NULL,
+    from_status VARCHAR(50),
+    to_status VARCHAR(50) NOT NULL,
+    actor VARCHAR(42) NOT NULL,
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- Insertion order, for entries written within the same microsecond
+    seq BIGSERIAL NOT NULL,
+    CONSTRAINT fk_project FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_history_project ON project_history(project_id, created_at, seq);
+
+INSERT INTO project_history (project_id, from_status, to_status, actor, created_at)
+SELECT id, NULL, status, creator, created_at
+FROM projects;
+
+-- Reviewer approvals of the current review round
+CREATE TABLE IF NOT EXISTS project_approvals (
+    project_id UUID NOT NULL,
+    reviewer VARCHAR(42) NOT NULL,
+    approved_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (project_id, reviewer),
+    CONSTRAINT fk_project FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
@@ -0,0 +1,11 @@
+-- GitHub repositories a project tracks. Names are the bare repository names
+-- used by /admin/github/sync (under GITHUB_OWNER), matching github_issues.repo.
+CREATE TABLE IF NOT EXISTS project_repos (
+    project_id UUID NOT NULL,
+    repo TEXT NOT NULL,
+    added_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (project_id, repo),
+    CONSTRAINT fk_project FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_repos_repo ON project_repos(repo);
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    application::dtos::project_dtos::ProjectMemberResponse,
+    domain::{
+        entities::projects::ProjectId, errors::AppError,
+        repositories::project_repository::ProjectRepository, value_objects::WalletAddress,
+    },
+};
+
+/// Accepts the requester's pending invitation to the project.
+pub async fn accept_project_invitation(
+    repository: Arc<dyn ProjectRepository>,
+    requester_address: String,
+    project_id: String,
+) -> Result<ProjectMemberResponse, AppError> {
+    // Parse project ID
+    let id = Uuid::parse_str(&project_id)
+        .map_err(|_| AppError::invalid_field("id", "Invalid project ID"))?;
+    let project_id = ProjectId::from_uuid(id);
+
+    let requester = WalletAddress::new(requester_address).map_err(|e| {
+        AppError::invalid_field("address", format!("Invalid wallet address: {}", e))
+    })?;
+
+    let mut member = repository
+        .find_member(&project_id, &requester)
+        .await?
+        .ok_or_else(|| AppError::not_found("No invitation to this project"))?;
+
+    member.accept()?;
+    repository.update_member(&member).await?;
+
+    Ok(member.into())
+}
@@ -0,0 +1,78 @@
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        dtos::project_dtos::{ProjectApprovalResponse, ProjectApprovalsResponse},
+        queries::get_project_approvals::current_approvals,
+    },
+    domain::{
+        entities::projects::{ProjectApproval, ProjectId, ProjectStatus, ReviewPolicy},
+        errors::AppError,
+        repositories::project_repository::ProjectRepository,
+        value_objects::WalletAddress,
+    },
+};
+
+/// Records the requester's approval of a project under review.
+pub async fn approve_project(
+    repository: Arc<dyn ProjectRepository>,
+    policy: ReviewPolicy,
+    requester_address: String,
+    project_id: String,
+) -> Result<ProjectApprovalsResponse, AppError> {
+    // Parse project ID
+    let id = Uuid::parse_str(&project_id)
+        .map_err(|_| AppError::invalid_field("id", "Invalid project ID"))?;
+    let project_id = ProjectId::from_uuid(id);
+
+    // Approvals are stored like member addresses, in checksum form
+    let requester = WalletAddress::new(requester_address)
+        .map_err(|e| AppError::invalid_field("address", format!("Invalid wallet address: {}", e)))?
+        .checksummed();
+
+    let project = repository
+        .find_by_id(&project_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Project not found"))?;
+
+    let member = repository.find_member(&project_id, &requester).await?;
+    if !member.is_some_and(|m| m.can_review()) {
+        return Err(AppError::forbidden(
+            "Only reviewers can approve this project",
+        ));
+    }
+    if project.status != ProjectStatus::UnderReview {
+        return Err(AppError::conflict(format!(
+            "Only projects under review can be approved, this one is {}",
+            project.status
+        )));
+    }
+
+    let already_approved = repository
+        .list_approvals(&project_id)
+        .await?
+        .iter()
+        .any(|a| a.reviewer.checksummed() == requester);
+    if already_approved {
+        return Err(AppError::conflict("You already approved this project"));
+    }
+
+    repository
+        .add_approval(&ProjectApproval {
+            project_id,
+            reviewer: requester,
+            approved_at: Utc::now(),
+        })
+        .await?;
+
+    let approvals = current_approvals(repository.as_ref(), &project_id).await?;
+    Ok(ProjectApprovalsResponse {
+        approvals: approvals
+            .into_iter()
+            .map(ProjectApprovalResponse::from)
+            .collect(),
+        required: policy.required_approvals,
+    })
+}
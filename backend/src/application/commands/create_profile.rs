@@ -1,5 +1,6 @@
 use crate::application::dtos::profile_dtos::{CreateProfileRequest, ProfileResponse};
 use crate::domain::entities::profile::Profile;
+use crate::domain::errors::AppError;
 use crate::domain::repositories::profile_repository::ProfileRepository;
 use crate::domain::value_objects::wallet_address::WalletAddress;
 use regex;
@@ -9,17 +10,17 @@ pub async fn create_profile(
     profile_repository: Arc<dyn ProfileRepository + 'static>,
     address: String,
     request: CreateProfileRequest,
-) -> Result<ProfileResponse, String> {
-    let wallet_address = WalletAddress::new(address).map_err(|e| e.to_string())?;
+) -> Result<ProfileResponse, AppError> {
+    let wallet_address =
+        WalletAddress::new(address).map_err(|e| AppError::invalid_field("address", e))?;
 
     // Check if profile already exists
     if profile_repository
         .find_by_address(&wallet_address)
-        .await
-        .map_err(|e| e.to_string())?
+        .await?
         .is_some()
     {
-        return Err("Profile already exists for this user".to_string());
+        return Err(AppError::conflict("Profile already exists for this user"));
     }
 
     let mut profile = Profile::new(wallet_address.clone());
@@ -33,27 +34,26 @@ pub async fn create_profile(
         } else {
             let valid_format = regex::Regex::new(r"^[a-zA-Z0-9-]{3,100}$").unwrap();
             if !valid_format.is_match(trimmed) {
-                return Err("Invalid LinkedIn account format".to_string());
+                return Err(AppError::invalid_field(
+                    "linkedin_account",
+                    "Invalid LinkedIn account format",
+                ));
             }
 
             let normalized = trimmed.to_lowercase();
             if profile_repository
                 .find_by_linkedin_account(normalized.as_str())
-                .await
-                .map_err(|e| e.to_string())?
+                .await?
                 .is_some()
             {
-                return Err("LinkedIn account already taken".to_string());
+                return Err(AppError::conflict("LinkedIn account already taken"));
             }
 
             profile.linkedin_account = Some(trimmed.to_string());
         }
     }
 
-    profile_repository
-        .create(&profile)
-        .await
-        .map_err(|e| e.to_string())?;
+    profile_repository.create(&profile).await?;
 
     Ok(ProfileResponse {
         address: wallet_address,
@@ -3,7 +3,9 @@ use std::sync::Arc;
 use crate::{
     application::dtos::project_dtos::{CreateProjectRequest, ProjectResponse},
     domain::{
-        entities::projects::Project, repositories::project_repository::ProjectRepository,
+        entities::projects::{Project, ProjectStatus},
+        errors::AppError,
+        repositories::project_repository::ProjectRepository,
         value_objects::WalletAddress,
     },
 };
@@ -12,31 +14,36 @@ pub async fn create_project(
     repository: Arc<dyn ProjectRepository>,
     creator_address: String,
     request: CreateProjectRequest,
-) -> Result<ProjectResponse, String> {
+) -> Result<ProjectResponse, AppError> {
     // Validate and create WalletAddress
-    let creator = WalletAddress::new(creator_address)
-        .map_err(|e| format!("Invalid wallet address: {}", e))?;
+    let creator = WalletAddress::new(creator_address).map_err(|e| {
+        AppError::invalid_field("address", format!("Invalid wallet address: {}", e))
+    })?;
 
     // Verify creator has a profile
-    if !repository
-        .profile_exists(&creator)
-        .await
-        .map_err(|e| e.to_string())?
-    {
-        return Err("Only addresses with profiles can create projects".to_string());
+    if !repository.profile_exists(&creator).await? {
+        return Err(AppError::forbidden(
+            "Only addresses with profiles can create projects",
+        ));
+    }
+
+    // Every project enters the lifecycle as a proposal
+    let status = request.status.unwrap_or(ProjectStatus::Proposal);
+    if status != ProjectStatus::Proposal {
+        return Err(AppError::invalid_field(
+            "status",
+            "New projects start as proposals",
+        ));
     }
 
     // Create project entity
-    let project = Project::new(request.name, request.description, request.status, creator);
+    let project = Project::new(request.name, request.description, status, creator);
 
     // Validate project
     project.validate()?;
 
     // Save to repository
-    repository
-        .create(&project)
-        .await
-        .map_err(|e| e.to_string())?;
+    repository.create(&project).await?;
 
     // Return response
     Ok(ProjectResponse {
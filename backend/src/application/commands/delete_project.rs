@@ -2,39 +2,39 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::projects::ProjectId, repositories::project_repository::ProjectRepository,
-    value_objects::WalletAddress,
+    entities::projects::ProjectId, errors::AppError,
+    repositories::project_repository::ProjectRepository, value_objects::WalletAddress,
 };
 pub async fn delete_project(
     repository: Arc<dyn ProjectRepository>,
     requester_address: String,
     project_id: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     // Parse project ID
-    let id = Uuid::parse_str(&project_id).map_err(|_| "Invalid project ID".to_string())?;
+    let id = Uuid::parse_str(&project_id)
+        .map_err(|_| AppError::invalid_field("id", "Invalid project ID"))?;
     let project_id = ProjectId::from_uuid(id);
 
     // Validate requester address
-    let requester = WalletAddress::new(requester_address)
-        .map_err(|e| format!("Invalid wallet address: {}", e))?;
+    let requester = WalletAddress::new(requester_address).map_err(|e| {
+        AppError::invalid_field("address", format!("Invalid wallet address: {}", e))
+    })?;
 
-    // Get existing project
-    let project = repository
-        .find_by_id(&project_id)
-        .await
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| "Project not found".to_string())?;
+    // Check the project exists
+    if !repository.exists(&project_id).await? {
+        return Err(AppError::not_found("Project not found"));
+    }
 
-    // Verify requester is the creator
-    if requester != project.creator {
-        return Err("Only the creator can delete this project".to_string());
+    // Verify requester is a maintainer
+    let member = repository.find_member(&project_id, &requester).await?;
+    if !member.is_some_and(|m| m.can_manage()) {
+        return Err(AppError::forbidden(
+            "Only maintainers can delete this project",
+        ));
     }
 
     // Delete from repository
-    repository
-        .delete(&project_id)
-        .await
-        .map_err(|e| e.to_string())?;
+    repository.delete(&project_id).await?;
 
     Ok(())
 }
@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use crate::{
+    application::dtos::distribution_dtos::DistributionDetailResponse,
+    domain::{errors::AppError, repositories::distribution_repository::DistributionRepository},
+};
+
+pub async fn fail_distribution(
+    repository: Arc<dyn DistributionRepository>,
+    distribution_id: String,
+) -> Result<DistributionDetailResponse, AppError> {
+    let mut distribution = repository
+        .find_by_id(&distribution_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Distribution not found"))?;
+
+    let from = distribution.status;
+    distribution.fail()?;
+    repository.update_status(&distribution, from).await?;
+
+    Ok(distribution.into())
+}
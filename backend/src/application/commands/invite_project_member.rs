@@ -0,0 +1,62 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    application::dtos::project_dtos::{InviteMemberRequest, ProjectMemberResponse},
+    domain::{
+        entities::{project_member::ProjectMember, projects::ProjectId},
+        errors::AppError,
+        repositories::project_repository::ProjectRepository,
+        value_objects::WalletAddress,
+    },
+};
+
+pub async fn invite_project_member(
+    repository: Arc<dyn ProjectRepository>,
+    requester_address: String,
+    project_id: String,
+    request: InviteMemberRequest,
+) -> Result<ProjectMemberResponse, AppError> {
+    // Parse project ID
+    let id = Uuid::parse_str(&project_id)
+        .map_err(|_| AppError::invalid_field("id", "Invalid project ID"))?;
+    let project_id = ProjectId::from_uuid(id);
+
+    let requester = WalletAddress::new(requester_address).map_err(|e| {
+        AppError::invalid_field("address", format!("Invalid wallet address: {}", e))
+    })?;
+    let invitee = WalletAddress::new(request.address).map_err(|e| {
+        AppError::invalid_field("address", format!("Invalid wallet address: {}", e))
+    })?;
+
+    if !repository.exists(&project_id).await? {
+        return Err(AppError::not_found("Project not found"));
+    }
+
+    // Only maintainers bring people in
+    let inviter = repository.find_member(&project_id, &requester).await?;
+    if !inviter.is_some_and(|m| m.can_manage()) {
+        return Err(AppError::forbidden("Only maintainers can invite members"));
+    }
+
+    if !repository.profile_exists(&invitee).await? {
+        return Err(AppError::invalid_field(
+            "address",
+            "Only addresses with profiles can join projects",
+        ));
+    }
+    if repository
+        .find_member(&project_id, &invitee)
+        .await?
+        .is_some()
+    {
+        return Err(AppError::conflict(
+            "Address is already a member of or invited to this project",
+        ));
+    }
+
+    let member = ProjectMember::invite(project_id, invitee, request.role, requester);
+    repository.add_member(&member).await?;
+
+    Ok(member.into())
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::projects::ProjectId, errors::AppError,
+    repositories::project_repository::ProjectRepository, value_objects::WalletAddress,
+};
+
+/// Removes the requester from the project, or declines their pending invitation.
+/// The last active maintainer cannot leave, so every project keeps someone who
+/// can manage it.
+pub async fn leave_project(
+    repository: Arc<dyn ProjectRepository>,
+    requester_address: String,
+    project_id: String,
+) -> Result<(), AppError> {
+    // Parse project ID
+    let id = Uuid::parse_str(&project_id)
+        .map_err(|_| AppError::invalid_field("id", "Invalid project ID"))?;
+    let project_id = ProjectId::from_uuid(id);
+
+    let requester = WalletAddress::new(requester_address).map_err(|e| {
+        AppError::invalid_field("address", format!("Invalid wallet address: {}", e))
+    })?;
+
+    let member = repository
+        .find_member(&project_id, &requester)
+        .await?
+        .ok_or_else(|| AppError::not_found("Not a member of this project"))?;
+
+    if member.can_manage() {
+        let maintainers = repository
+            .list_members(&project_id)
+            .await?
+            .iter()
+            .filter(|m| m.can_manage())
+            .count();
+        if maintainers <= 1 {
+            return Err(AppError::conflict(
+                "The last maintainer cannot leave the project",
+            ));
+        }
+    }
+
+    repository.remove_member(&project_id, &requester).await?;
+    Ok(())
+}
@@ -1,6 +1,9 @@
+use crate::domain::errors::AppError;
 use crate::infrastructure::jwt::JwtManager;
 
-pub async fn login(address: String) -> Result<String, String> {
+pub async fn login(address: String) -> Result<String, AppError> {
     let jwt_manager = JwtManager::new();
-    jwt_manager.generate_token(&address)
+    jwt_manager
+        .generate_token(&address)
+        .map_err(AppError::internal)
 }
@@ -1,8 +1,18 @@
+pub mod accept_project_invitation;
+pub mod approve_project;
+pub mod confirm_distribution;
 pub mod create_profile;
 pub mod create_project;
 pub mod delete_project;
+pub mod fail_distribution;
+pub mod invite_project_member;
+pub mod leave_project;
 pub mod login;
+pub mod reconcile_distribution;
 pub mod register_distribution;
+pub mod set_project_repos;
+pub mod submit_distribution;
 pub mod sync_github_issues;
+pub mod transition_project;
 pub mod update_profile;
 pub mod update_project;
@@ -0,0 +1,180 @@
+use std::sync::Arc;
+
+use crate::{
+    application::dtos::distribution_dtos::{DistributionReconciliationReport, ReconciliationIssue},
+    domain::{
+        entities::distribution::{keccak256_hex, DistributionLine, DistributionStatus},
+        errors::AppError,
+        repositories::distribution_repository::DistributionRepository,
+        services::mint_event_service::{MintEvent, MintEventService},
+        value_objects::WalletAddress,
+    },
+};
+
+/// Compares a distribution with the mints of its transaction. A submitted
+/// distribution is confirmed when every line matches and nothing extra was
+/// minted, and failed when its transaction reverted; otherwise it is left as is.
+pub async fn reconcile_distribution(
+    repository: Arc<dyn DistributionRepository>,
+    mint_events: Arc<dyn MintEventService>,
+    distribution_id: String,
+) -> Result<DistributionReconciliationReport, AppError> {
+    let mut distribution = repository
+        .find_by_id(&distribution_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Distribution not found"))?;
+
+    let (Some(tx_hash), Some(chain_id)) = (distribution.tx_hash.clone(), distribution.chain_id)
+    else {
+        return Err(AppError::conflict(
+            "Distribution has no submitted transaction to reconcile",
+        ));
+    };
+    if distribution.lines.iter().any(|line| line.amount.is_none()) {
+        return Err(AppError::validation(
+            "Only contribution token distributions can be reconciled; every line needs an amount",
+        ));
+    }
+
+    let outcome = mint_events
+        .mints_in_transaction(chain_id, &tx_hash)
+        .await
+        .map_err(|e| AppError::internal(format!("Mint event lookup failed: {e}")))?;
+
+    let Some(outcome) = outcome else {
+        return Ok(DistributionReconciliationReport {
+            distribution_id: distribution.id,
+            tx_hash: Some(tx_hash),
+            status: distribution.status,
+            mined: false,
+            reverted: false,
+            matched: false,
+            matched_count: 0,
+            issues: Vec::new(),
+        });
+    };
+
+    let (matched_count, issues) = if outcome.reverted {
+        (0, Vec::new())
+    } else {
+        compare(&distribution.lines, &outcome.mints)
+    };
+    let matched = !outcome.reverted && issues.is_empty();
+
+    if distribution.status == DistributionStatus::Submitted {
+        if outcome.reverted {
+            distribution.fail()?;
+            repository
+                .update_status(&distribution, DistributionStatus::Submitted)
+                .await?;
+        } else if matched {
+            distribution.confirm()?;
+            repository
+                .update_status(&distribution, DistributionStatus::Submitted)
+                .await?;
+        }
+    }
+
+    Ok(DistributionReconciliationReport {
+        distribution_id: distribution.id,
+        tx_hash: Some(tx_hash),
+        status: distribution.status,
+        mined: true,
+        reverted: outcome.reverted,
+        matched,
+        matched_count,
+        issues,
+    })
+}
+
+/// Reconciles every submitted distribution, oldest first.
+pub async fn reconcile_submitted_distributions(
+    repository: Arc<dyn DistributionRepository>,
+    mint_events: Arc<dyn MintEventService>,
+) -> Result<Vec<DistributionReconciliationReport>, AppError> {
+    let ids = repository
+        .list_ids_by_status(DistributionStatus::Submitted)
+        .await?;
+
+    let mut reports = Vec::with_capacity(ids.len());
+    for id in ids {
+        reports.push(reconcile_distribution(repository.clone(), mint_events.clone(), id).await?);
+    }
+    Ok(reports)
+}
+
+/// Pairs lines with mints: exact matches first, then same-recipient mints as
+/// mismatches. Whatever is left over is missing (lines) or extra (mints).
+fn compare(lines: &[DistributionLine], mints: &[MintEvent]) -> (usize, Vec<ReconciliationIssue>) {
+    let recipient = |line: &DistributionLine| {
+        WalletAddress(line.address.clone())
+            .to_checksum()
+            .unwrap_or_else(|| line.address.clone())
+    };
+    let reason_hash =
+        |line: &DistributionLine| keccak256_hex(line.reason.as_deref().unwrap_or("").as_bytes());
+    let same_reason = |line: &DistributionLine, mint: &MintEvent| {
+        mint.reason_hash
+            .as_ref()
+            .is_none_or(|hash| hash.eq_ignore_ascii_case(&reason_hash(line)))
+    };
+    let expected = |line: &DistributionLine| line.amount.unwrap_or_default();
+
+    let mut used = vec![false; mints.len()];
+    let mut pending: Vec<&DistributionLine> = Vec::new();
+    let mut matched_count = 0;
+    for line in lines {
+        let exact = mints.iter().enumerate().position(|(i, mint)| {
+            !used[i]
+                && mint.recipient == recipient(line)
+                && mint.amount == expected(line)
+                && same_reason(line, mint)
+        });
+        match exact {
+            Some(i) => {
+                used[i] = true;
+                matched_count += 1;
+            }
+            None => pending.push(line),
+        }
+    }
+
+    let mut issues = Vec::new();
+    for line in pending {
+        let issue = |code: &str, minted: Option<&MintEvent>| ReconciliationIssue {
+            code: code.to_string(),
+            address: recipient(line),
+            badge_name: Some(line.badge_name.clone()),
+            expected_amount: line.amount,
+            minted_amount: minted.map(|mint| mint.amount),
+        };
+        let same_recipient = mints
+            .iter()
+            .enumerate()
+            .position(|(i, mint)| !used[i] && mint.recipient == recipient(line));
+        match same_recipient {
+            Some(i) => {
+                used[i] = true;
+                let code = if mints[i].amount == expected(line) {
+                    "reason_mismatch"
+                } else {
+                    "amount_mismatch"
+                };
+                issues.push(issue(code, Some(&mints[i])));
+            }
+            None => issues.push(issue("missing", None)),
+        }
+    }
+
+    for (mint, _) in mints.iter().zip(used).filter(|(_, used)| !used) {
+        issues.push(ReconciliationIssue {
+            code: "extra".to_string(),
+            address: mint.recipient.clone(),
+            badge_name: None,
+            expected_amount: None,
+            minted_amount: Some(mint.amount),
+        });
+    }
+
+    (matched_count, issues)
+}
@@ -1,30 +1,154 @@
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use crate::{
-    application::dtos::distribution_dtos::RegisterDistributionRequest,
-    domain::repositories::distribution_repository::{DistributionRecord, DistributionRepository},
+    application::dtos::distribution_dtos::{
+        RegisterDistributionItem, RegisterDistributionRequest, RegisterDistributionResponse,
+    },
+    application::queries::validate_distribution::validate_distribution,
+    domain::{
+        entities::distribution::{Distribution, DistributionLine},
+        errors::{AppError, FieldError},
+        repositories::{DistributionRepository, ProfileRepository},
+        services::badge_registry_service::BadgeRegistryService,
+        value_objects::WalletAddress,
+    },
 };
 
+/// A distribution built from part of a request, with the request indexes of its items.
+pub struct RequestedDistribution {
+    pub item_indexes: Vec<usize>,
+    pub distribution: Distribution,
+}
+
+/// Groups the request items by their optional `distributionId` and derives each
+/// group's id from its content. A supplied id must equal the derived one, and a
+/// group repeating a recipient/badge pair is rejected with the repeated items.
+pub fn build_distributions(
+    creator: &WalletAddress,
+    items: Vec<RegisterDistributionItem>,
+) -> Result<Vec<RequestedDistribution>, AppError> {
+    let mut grouped: BTreeMap<Option<String>, Vec<(usize, DistributionLine)>> = BTreeMap::new();
+    for (index, item) in items.into_iter().enumerate() {
+        let supplied = supplied_id(&item);
+        grouped.entry(supplied).or_default().push((
+            index,
+            DistributionLine {
+                distribution_id: String::new(),
+                address: item.address,
+                badge_name: item.badge_name,
+                amount: item.amount,
+                reason: item.reason,
+            },
+        ));
+    }
+
+    let mut distributions = Vec::with_capacity(grouped.len());
+    for (supplied, entries) in grouped {
+        let (item_indexes, lines): (Vec<usize>, Vec<DistributionLine>) =
+            entries.into_iter().unzip();
+        let repeated = Distribution::repeated_lines(&lines);
+        if !repeated.is_empty() {
+            let details = repeated
+                .into_iter()
+                .map(|(row, first)| FieldError {
+                    field: format!("distributions[{}]", item_indexes[row]),
+                    message: format!(
+                        "{} already receives {} in distributions[{}]",
+                        lines[row].address, lines[row].badge_name, item_indexes[first]
+                    ),
+                })
+                .collect();
+            return Err(AppError::Validation {
+                message: "A distribution cannot repeat a recipient/badge pair".into(),
+                details,
+            });
+        }
+        let distribution = Distribution::from_lines(creator.clone(), lines)?;
+        if Distribution::checked_total(&distribution.lines).is_none() {
+            return Err(AppError::invalid_field(
+                "amount",
+                "The total amount of a distribution must fit in a uint256",
+            ));
+        }
+
+        if let Some(supplied) = supplied {
+            if supplied != distribution.id {
+                return Err(AppError::invalid_field(
+                    "distributionId",
+                    format!(
+                        "distributionId {} does not match the content hash {}",
+                        supplied, distribution.id
+                    ),
+                ));
+            }
+        }
+
+        distributions.push(RequestedDistribution {
+            item_indexes,
+            distribution,
+        });
+    }
+    Ok(distributions)
+}
+
+/// The item's `distributionId`, ignoring blank values.
+pub fn supplied_id(item: &RegisterDistributionItem) -> Option<String> {
+    item.distribution_id
+        .clone()
+        .filter(|id| !id.trim().is_empty())
+}
+
+/// Registers the request's lines as draft distributions whose ids are derived
+/// from their content. Every line must pass the dry-run checks, otherwise the
+/// request is Unprocessable and nothing is stored. Registering the same lines
+/// again is a no-op.
 pub async fn register_distribution(
+    profile_repository: Arc<dyn ProfileRepository>,
     repository: Arc<dyn DistributionRepository>,
+    badge_registry: Arc<dyn BadgeRegistryService>,
+    creator_address: String,
     request: RegisterDistributionRequest,
-) -> Result<(), String> {
-    if request.distributions.is_empty() {
-        return Err("distributions must not be empty".to_string());
+) -> Result<RegisterDistributionResponse, AppError> {
+    let creator = WalletAddress::new(creator_address).map_err(|e| {
+        AppError::invalid_field("address", format!("Invalid wallet address: {}", e))
+    })?;
+
+    let report = validate_distribution(
+        profile_repository,
+        repository.clone(),
+        badge_registry,
+        creator.as_str().to_string(),
+        request.clone(),
+    )
+    .await?;
+    if !report.valid {
+        let details = report
+            .lines
+            .iter()
+            .flat_map(|line| {
+                line.issues.iter().map(move |issue| FieldError {
+                    field: format!("distributions[{}]", line.index),
+                    message: format!("{}: {}", issue.code, issue.message),
+                })
+            })
+            .collect();
+        return Err(AppError::unprocessable(
+            format!(
+                "{} of {} lines failed validation; nothing was registered",
+                report.invalid_count, report.line_count
+            ),
+            details,
+        ));
     }
 
-    let records: Vec<DistributionRecord> = request
-        .distributions
+    let distributions: Vec<Distribution> = build_distributions(&creator, request.distributions)?
         .into_iter()
-        .map(|item| DistributionRecord {
-            address: item.address,
-            badge_name: item.badge_name,
-            distribution_id: item.distribution_id,
-        })
+        .map(|requested| requested.distribution)
         .collect();
+    repository.register_all(&distributions).await?;
 
-    repository
-        .create_many(&records)
-        .await
-        .map_err(|e| e.to_string())
+    Ok(RegisterDistributionResponse {
+        distribution_ids: distributions.into_iter().map(|d| d.id).collect(),
+    })
 }
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    application::dtos::project_dtos::ProjectRepos,
+    domain::{
+        entities::projects::ProjectId, errors::AppError,
+        repositories::project_repository::ProjectRepository, value_objects::WalletAddress,
+    },
+};
+
+/// Replaces the GitHub repositories linked to a project (maintainers only).
+/// Names are the bare repository names synced by /admin/github/sync.
+pub async fn set_project_repos(
+    repository: Arc<dyn ProjectRepository>,
+    requester_address: String,
+    project_id: String,
+    request: ProjectRepos,
+) -> Result<ProjectRepos, AppError> {
+    // Parse project ID
+    let id = Uuid::parse_str(&project_id)
+        .map_err(|_| AppError::invalid_field("id", "Invalid project ID"))?;
+    let project_id = ProjectId::from_uuid(id);
+
+    let requester = WalletAddress::new(requester_address).map_err(|e| {
+        AppError::invalid_field("address", format!("Invalid wallet address: {}", e))
+    })?;
+
+    if !repository.exists(&project_id).await? {
+        return Err(AppError::not_found("Project not found"));
+    }
+
+    // Verify requester is a maintainer
+    let member = repository.find_member(&project_id, &requester).await?;
+    if !member.is_some_and(|m| m.can_manage()) {
+        return Err(AppError::forbidden(
+            "Only maintainers can link repositories to this project",
+        ));
+    }
+
+    let valid_format = regex::Regex::new(r"^[A-Za-z0-9._-]{1,100}$").unwrap();
+    let mut repos = Vec::new();
+    for (i, repo) in request.repos.into_iter().enumerate() {
+        let repo = repo.trim().to_string();
+        if !valid_format.is_match(&repo) {
+            return Err(AppError::invalid_field(
+                format!("repos[{i}]"),
+                "Repository names are 1-100 letters, digits, '.', '_' or '-'",
+            ));
+        }
+        if !repos.contains(&repo) {
+            repos.push(repo);
+        }
+    }
+
+    repository.set_repos(&project_id, &repos).await?;
+
+    Ok(ProjectRepos {
+        repos: repository.list_repos(&project_id).await?,
+    })
+}
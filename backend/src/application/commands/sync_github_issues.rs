@@ -5,6 +5,7 @@ use tracing::{debug, info};
 
 use crate::domain::{
     entities::github_issue::GithubIssue,
+    errors::AppError,
     repositories::github_issue_repository::GithubIssueRepository,
     services::github_service::{GitHubApiIssue, GithubService},
 };
@@ -29,7 +30,7 @@ pub fn transform_issue(
     repo: &str,
     repo_id: i64,
     api_issue: &GitHubApiIssue,
-) -> Result<GithubIssue, String> {
+) -> Result<GithubIssue, AppError> {
     let labels_normalized: Vec<serde_json::Value> = api_issue
         .labels
         .iter()
@@ -45,7 +46,7 @@ pub fn transform_issue(
     let points = derive_points(&api_issue.labels);
 
     let created_at = chrono::DateTime::parse_from_rfc3339(&api_issue.created_at)
-        .map_err(|e| format!("Invalid created_at: {e}"))?
+        .map_err(|e| AppError::internal(format!("Invalid created_at: {e}")))?
         .with_timezone(&chrono::Utc);
 
     let closed_at = api_issue
@@ -53,10 +54,10 @@ pub fn transform_issue(
         .as_ref()
         .map(|s| chrono::DateTime::parse_from_rfc3339(s).map(|dt| dt.with_timezone(&chrono::Utc)))
         .transpose()
-        .map_err(|e| format!("Invalid closed_at: {e}"))?;
+        .map_err(|e| AppError::internal(format!("Invalid closed_at: {e}")))?;
 
     let updated_at = chrono::DateTime::parse_from_rfc3339(&api_issue.updated_at)
-        .map_err(|e| format!("Invalid updated_at: {e}"))?
+        .map_err(|e| AppError::internal(format!("Invalid updated_at: {e}")))?
         .with_timezone(&chrono::Utc);
 
     Ok(GithubIssue {
@@ -84,7 +85,7 @@ pub async fn sync_github_issues(
     issue_repository: Arc<dyn GithubIssueRepository>,
     repos: Vec<String>,
     since: Option<String>,
-) -> Result<usize, String> {
+) -> Result<usize, AppError> {
     let mut total_synced: usize = 0;
 
     for repo in &repos {
@@ -92,7 +93,7 @@ pub async fn sync_github_issues(
         let (repo_id, api_issues) = github_service
             .fetch_issues(repo, since.as_deref())
             .await
-            .map_err(|e| format!("Failed to fetch issues for {repo}: {e}"))?;
+            .map_err(|e| AppError::internal(format!("Failed to fetch issues for {repo}: {e}")))?;
 
         info!(repo = %repo, count = api_issues.len(), "Fetched issues from GitHub API");
 
@@ -106,10 +107,9 @@ pub async fn sync_github_issues(
 
             let issue = transform_issue(repo, repo_id, api_issue)?;
 
-            issue_repository
-                .upsert(&issue)
-                .await
-                .map_err(|e| format!("Failed to upsert issue {}: {e}", api_issue.id))?;
+            issue_repository.upsert(&issue).await.map_err(|e| {
+                AppError::internal(format!("Failed to upsert issue {}: {e}", api_issue.id))
+            })?;
 
             info!(
                 repo = %repo,
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        dtos::project_dtos::{ProjectResponse, TransitionProjectRequest},
+        queries::get_project_approvals::current_approvals,
+    },
+    domain::{
+        entities::projects::{ProjectId, ProjectStatus, ProjectTransition, ReviewPolicy},
+        errors::AppError,
+        repositories::project_repository::ProjectRepository,
+        value_objects::WalletAddress,
+    },
+};
+
+/// Moves a project to the requested status and records it in the history.
+///
+/// Maintainers drive every transition; active reviewers may also reject a
+/// project under review. Approving needs `policy.required_approvals` reviewer
+/// approvals, and withdrawing a review back to proposal discards them.
+pub async fn transition_project(
+    repository: Arc<dyn ProjectRepository>,
+    policy: ReviewPolicy,
+    requester_address: String,
+    project_id: String,
+    request: TransitionProjectRequest,
+) -> Result<ProjectResponse, AppError> {
+    // Parse project ID
+    let id = Uuid::parse_str(&project_id)
+        .map_err(|_| AppError::invalid_field("id", "Invalid project ID"))?;
+    let project_id = ProjectId::from_uuid(id);
+
+    let requester = WalletAddress::new(requester_address).map_err(|e| {
+        AppError::invalid_field("address", format!("Invalid wallet address: {}", e))
+    })?;
+
+    let mut project = repository
+        .find_by_id(&project_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Project not found"))?;
+    let from = project.status;
+    let to = request.status;
+
+    let member = repository.find_member(&project_id, &requester).await?;
+    let reviewer_rejects = from == ProjectStatus::UnderReview && to == ProjectStatus::Rejected;
+    let allowed = member
+        .as_ref()
+        .is_some_and(|m| m.can_manage() || (reviewer_rejects && m.can_review()));
+    if !allowed {
+        return Err(AppError::forbidden(
+            "Only maintainers can change this project's status",
+        ));
+    }
+
+    if from.requires_approvals(to) {
+        let approvals = current_approvals(repository.as_ref(), &project_id)
+            .await?
+            .len();
+        if approvals < policy.required_approvals {
+            return Err(AppError::conflict(format!(
+                "Approving needs {} reviewer approvals, got {}",
+                policy.required_approvals, approvals
+            )));
+        }
+    }
+
+    project.transition_to(to)?;
+    let transition = ProjectTransition::new(project_id, from, to, requester, request.note);
+    repository.record_transition(&project, &transition).await?;
+
+    Ok(ProjectResponse {
+        id: project.id.value().to_string(),
+        name: project.name,
+        description: project.description,
+        status: project.status,
+        creator: project.creator.to_string(),
+        created_at: project.created_at,
+        updated_at: project.updated_at,
+    })
+}
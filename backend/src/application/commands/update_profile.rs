@@ -1,4 +1,5 @@
 use crate::application::dtos::profile_dtos::{ProfileResponse, UpdateProfileRequest};
+use crate::domain::errors::AppError;
 use crate::domain::repositories::profile_repository::ProfileRepository;
 use crate::domain::value_objects::wallet_address::WalletAddress;
 use regex;
@@ -8,14 +9,14 @@ pub async fn update_profile(
     profile_repository: Arc<dyn ProfileRepository + 'static>,
     address: String,
     request: UpdateProfileRequest,
-) -> Result<ProfileResponse, String> {
-    let wallet_address = WalletAddress::new(address).map_err(|e| e.to_string())?;
+) -> Result<ProfileResponse, AppError> {
+    let wallet_address =
+        WalletAddress::new(address).map_err(|e| AppError::invalid_field("address", e))?;
 
     let mut profile = profile_repository
         .find_by_address(&wallet_address)
-        .await
-        .map_err(|e| e.to_string())?
-        .ok_or("Profile not found")?;
+        .await?
+        .ok_or_else(|| AppError::not_found("Profile not found"))?;
 
     profile.update_info(request.name, request.description, request.avatar_url);
     if let Some(ref handle) = request.github_login {
@@ -28,16 +29,17 @@ pub async fn update_profile(
             // Validate format for non-empty handles
             let valid_format = regex::Regex::new(r"^[a-zA-Z0-9-]{1,39}$").unwrap();
             if !valid_format.is_match(trimmed) {
-                return Err("Invalid GitHub handle format".to_string());
+                return Err(AppError::invalid_field(
+                    "github_login",
+                    "Invalid GitHub handle format",
+                ));
             }
-            if let Some(conflicting_profile) = profile_repository
-                .find_by_github_login(trimmed)
-                .await
-                .map_err(|e| e.to_string())?
+            if let Some(conflicting_profile) =
+                profile_repository.find_by_github_login(trimmed).await?
             {
                 // Only conflict if it's not the current user's profile
                 if conflicting_profile.address != wallet_address {
-                    return Err("GitHub handle already taken".to_string());
+                    return Err(AppError::conflict("GitHub handle already taken"));
                 }
             }
             profile.github_login = Some(trimmed.to_string());
@@ -53,16 +55,17 @@ pub async fn update_profile(
             // Validate format for non-empty handles (Twitter/X handle: 1-15 alphanumeric + underscores)
             let valid_format = regex::Regex::new(r"^[a-zA-Z0-9_]{1,15}$").unwrap();
             if !valid_format.is_match(trimmed) {
-                return Err("Invalid Twitter handle format".to_string());
+                return Err(AppError::invalid_field(
+                    "twitter_handle",
+                    "Invalid Twitter handle format",
+                ));
             }
-            if let Some(conflicting_profile) = profile_repository
-                .find_by_twitter_handle(trimmed)
-                .await
-                .map_err(|e| e.to_string())?
+            if let Some(conflicting_profile) =
+                profile_repository.find_by_twitter_handle(trimmed).await?
             {
                 // Only conflict if it's not the current user's profile
                 if conflicting_profile.address != wallet_address {
-                    return Err("Twitter handle already taken".to_string());
+                    return Err(AppError::conflict("Twitter handle already taken"));
                 }
             }
             profile.twitter_handle = Some(trimmed.to_string());
@@ -78,27 +81,26 @@ pub async fn update_profile(
             // Validate format for non-empty LinkedIn accounts
             let valid_format = regex::Regex::new(r"^[a-zA-Z0-9-]{3,100}$").unwrap();
             if !valid_format.is_match(trimmed) {
-                return Err("Invalid LinkedIn account format".to_string());
+                return Err(AppError::invalid_field(
+                    "linkedin_account",
+                    "Invalid LinkedIn account format",
+                ));
             }
 
             let normalized = trimmed.to_lowercase();
             if let Some(conflicting_profile) = profile_repository
                 .find_by_linkedin_account(normalized.as_str())
-                .await
-                .map_err(|e| e.to_string())?
+                .await?
             {
                 if conflicting_profile.address != wallet_address {
-                    return Err("LinkedIn account already taken".to_string());
+                    return Err(AppError::conflict("LinkedIn account already taken"));
                 }
             }
 
             profile.linkedin_account = Some(trimmed.to_string());
         }
     }
-    profile_repository
-        .update(&profile)
-        .await
-        .map_err(|e| e.to_string())?;
+    profile_repository.update(&profile).await?;
 
     Ok(ProfileResponse {
         address: wallet_address,
@@ -4,8 +4,8 @@ use uuid::Uuid;
 use crate::{
     application::dtos::project_dtos::{ProjectResponse, UpdateProjectRequest},
     domain::{
-        entities::projects::ProjectId, repositories::project_repository::ProjectRepository,
-        value_objects::WalletAddress,
+        entities::projects::ProjectId, errors::AppError,
+        repositories::project_repository::ProjectRepository, value_objects::WalletAddress,
     },
 };
 
@@ -14,38 +14,50 @@ pub async fn update_project(
     requester_address: String,
     project_id: String,
     request: UpdateProjectRequest,
-) -> Result<ProjectResponse, String> {
+) -> Result<ProjectResponse, AppError> {
     // Parse project ID
-    let id = Uuid::parse_str(&project_id).map_err(|_| "Invalid project ID".to_string())?;
+    let id = Uuid::parse_str(&project_id)
+        .map_err(|_| AppError::invalid_field("id", "Invalid project ID"))?;
     let project_id = ProjectId::from_uuid(id);
 
     // Validate requester address
-    let requester = WalletAddress::new(requester_address)
-        .map_err(|e| format!("Invalid wallet address: {}", e))?;
+    let requester = WalletAddress::new(requester_address).map_err(|e| {
+        AppError::invalid_field("address", format!("Invalid wallet address: {}", e))
+    })?;
 
     // Get existing project
     let mut project = repository
         .find_by_id(&project_id)
-        .await
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| "Project not found".to_string())?;
+        .await?
+        .ok_or_else(|| AppError::not_found("Project not found"))?;
 
-    // Verify requester is the creator
-    if requester != project.creator {
-        return Err("Only the creator can update this project".to_string());
+    // Verify requester is a maintainer
+    let member = repository.find_member(&project_id, &requester).await?;
+    if !member.is_some_and(|m| m.can_manage()) {
+        return Err(AppError::forbidden(
+            "Only maintainers can update this project",
+        ));
+    }
+
+    // Status only changes through the lifecycle transitions
+    if request
+        .status
+        .is_some_and(|status| status != project.status)
+    {
+        return Err(AppError::invalid_field(
+            "status",
+            "Status changes go through POST /projects/{id}/transitions",
+        ));
     }
 
     // Update project
-    project.update_info(request.name, request.description, request.status);
+    project.update_info(request.name, request.description);
 
     // Validate updated project
     project.validate()?;
 
     // Save to repository
-    repository
-        .update(&project)
-        .await
-        .map_err(|e| e.to_string())?;
+    repository.update(&project).await?;
 
     // Return response
     Ok(ProjectResponse {
@@ -1,24 +1,164 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+use crate::domain::entities::distribution::{Distribution, DistributionLine, DistributionStatus};
+use crate::domain::value_objects::TokenAmount;
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
 pub struct RegisterDistributionItem {
     pub address: String,
     #[serde(rename = "badgeName")]
     pub badge_name: String,
-    #[serde(rename = "distributionId")]
-    pub distribution_id: String,
+    /// Optional; when given it must equal the id derived from the lines
+    #[serde(rename = "distributionId", default)]
+    pub distribution_id: Option<String>,
+    /// Token amount in base units, for contribution token distributions
+    #[serde(default)]
+    pub amount: Option<TokenAmount>,
+    /// Attestation justification or mint reason
+    #[serde(default)]
+    pub reason: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
 pub struct RegisterDistributionRequest {
     pub distributions: Vec<RegisterDistributionItem>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterDistributionResponse {
+    /// Content-derived ids, one per distribution in the request
+    pub distribution_ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
 pub struct DistributionResponse {
     pub address: String,
     #[serde(rename = "badgeName")]
     pub badge_name: String,
     #[serde(rename = "distributionId")]
     pub distribution_id: String,
+    pub amount: Option<TokenAmount>,
+    pub reason: Option<String>,
+}
+
+impl From<DistributionLine> for DistributionResponse {
+    fn from(line: DistributionLine) -> Self {
+        Self {
+            address: line.address,
+            badge_name: line.badge_name,
+            distribution_id: line.distribution_id,
+            amount: line.amount,
+            reason: line.reason,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitDistributionRequest {
+    pub tx_hash: String,
+    pub chain_id: i64,
+}
+
+/// Distribution header with totals and lines.
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DistributionDetailResponse {
+    pub id: String,
+    pub creator: String,
+    pub status: DistributionStatus,
+    pub tx_hash: Option<String>,
+    pub chain_id: Option<i64>,
+    /// keccak256 of the canonical CSV of the lines; equals `id` unless the lines were altered
+    pub content_hash: String,
+    pub line_count: usize,
+    pub total_amount: TokenAmount,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub lines: Vec<DistributionResponse>,
+}
+
+impl From<Distribution> for DistributionDetailResponse {
+    fn from(distribution: Distribution) -> Self {
+        Self {
+            content_hash: Distribution::content_id(&distribution.lines),
+            line_count: distribution.line_count(),
+            total_amount: distribution.total_amount(),
+            id: distribution.id,
+            creator: distribution.creator.to_string(),
+            status: distribution.status,
+            tx_hash: distribution.tx_hash,
+            chain_id: distribution.chain_id,
+            created_at: distribution.created_at,
+            updated_at: distribution.updated_at,
+            lines: distribution.lines.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A problem found with one line during a dry run.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct DistributionLineIssue {
+    /// One of invalid_address, invalid_checksum, unknown_profile, invalid_badge_name,
+    /// unknown_badge, duplicate_in_request, already_distributed
+    #[schema(example = "unknown_badge")]
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DistributionLineReport {
+    /// Position of the item in the request
+    pub index: usize,
+    pub address: String,
+    pub badge_name: String,
+    pub distribution_id: String,
+    pub valid: bool,
+    pub issues: Vec<DistributionLineIssue>,
+}
+
+/// Result of validating a distribution without persisting it.
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DistributionDryRunReport {
+    pub valid: bool,
+    pub line_count: usize,
+    pub invalid_count: usize,
+    pub lines: Vec<DistributionLineReport>,
+}
+
+/// A difference between the distribution lines and the mints of its transaction.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconciliationIssue {
+    /// One of missing, extra, amount_mismatch, reason_mismatch
+    #[schema(example = "amount_mismatch")]
+    pub code: String,
+    pub address: String,
+    /// Not set for extra mints
+    pub badge_name: Option<String>,
+    pub expected_amount: Option<TokenAmount>,
+    /// Amount minted on chain
+    pub minted_amount: Option<TokenAmount>,
+}
+
+/// Result of comparing a submitted distribution with the mints of its transaction.
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DistributionReconciliationReport {
+    pub distribution_id: String,
+    pub tx_hash: Option<String>,
+    /// Status after reconciliation
+    pub status: DistributionStatus,
+    /// False while the transaction has no receipt yet
+    pub mined: bool,
+    pub reverted: bool,
+    /// Every line was minted exactly once, with nothing extra
+    pub matched: bool,
+    pub matched_count: usize,
+    pub issues: Vec<ReconciliationIssue>,
 }
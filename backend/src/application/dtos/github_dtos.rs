@@ -1,21 +1,22 @@
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
 /// Request DTO for POST /admin/github/sync
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct GithubSyncRequest {
     pub repos: Vec<String>,
     pub since: Option<String>,
 }
 
 /// Response DTO for POST /admin/github/sync
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct GithubSyncResponse {
     pub synced: usize,
     pub repos: Vec<String>,
 }
 
 /// Query parameters for GET /github/issues
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
 pub struct GithubIssuesQuery {
     pub repo: String,
     pub state: Option<String>,
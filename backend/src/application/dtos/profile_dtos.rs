@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
 use crate::domain::value_objects::WalletAddress;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CreateProfileRequest {
     pub name: String,
     pub description: Option<String>,
@@ -10,7 +11,7 @@ pub struct CreateProfileRequest {
     pub linkedin_account: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UpdateProfileRequest {
     pub name: Option<String>,
     pub description: Option<String>,
@@ -20,7 +21,7 @@ pub struct UpdateProfileRequest {
     pub linkedin_account: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ProfileResponse {
     pub address: WalletAddress,
     pub name: String,
@@ -32,3 +33,30 @@ pub struct ProfileResponse {
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
+
+/// Query parameters for GET /profiles
+#[derive(Debug, Default, Clone, Deserialize, IntoParams)]
+pub struct ListProfilesQuery {
+    /// Words matched as prefixes against name, description and GitHub login
+    pub q: Option<String>,
+    pub has_github: Option<bool>,
+    /// Received a badge in a confirmed distribution; based on the distributions
+    /// registered here, not on on-chain attestations
+    pub has_badges: Option<bool>,
+    /// newest (default), oldest or name
+    pub sort: Option<String>,
+    /// Page size, 1 to 100 (default 20)
+    pub limit: Option<i64>,
+    /// `next_cursor` of the previous page
+    pub cursor: Option<String>,
+}
+
+/// One page of profiles
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProfilePageResponse {
+    pub items: Vec<ProfileResponse>,
+    /// Profiles matching the filters, across all pages
+    pub total: i64,
+    /// Pass as `cursor` to get the next page; absent on the last page
+    pub next_cursor: Option<String>,
+}
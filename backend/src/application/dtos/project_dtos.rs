@@ -1,26 +1,33 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
-use crate::domain::entities::projects::ProjectStatus;
+use crate::domain::entities::{
+    project_member::{MembershipStatus, ProjectMember, ProjectRole},
+    projects::{ProjectApproval, ProjectStatus, ProjectTransition},
+};
 
 /// Request DTO for creating a project
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct CreateProjectRequest {
     pub name: String,
     pub description: String,
-    pub status: ProjectStatus,
+    /// Optional; new projects always start as `proposal`
+    #[serde(default)]
+    pub status: Option<ProjectStatus>,
 }
 
 /// Request DTO for updating a project
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
 pub struct UpdateProjectRequest {
     pub name: Option<String>,
     pub description: Option<String>,
+    /// Must equal the current status when given; use the transitions endpoint to change it
     pub status: Option<ProjectStatus>,
 }
 
 /// Response DTO for a project
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct ProjectResponse {
     pub id: String,
     pub name: String,
@@ -30,3 +37,152 @@ pub struct ProjectResponse {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+/// Query parameters for GET /projects
+#[derive(Debug, Default, Clone, Deserialize, IntoParams)]
+pub struct ListProjectsQuery {
+    /// Words matched as prefixes against name and description
+    pub q: Option<String>,
+    pub status: Option<String>,
+    pub creator: Option<String>,
+    /// RFC 3339 timestamp; only projects created at or after it
+    pub created_after: Option<String>,
+    /// RFC 3339 timestamp; only projects created before it
+    pub created_before: Option<String>,
+    /// newest (default), oldest, name or updated
+    pub sort: Option<String>,
+    /// Page size, 1 to 100 (default 20)
+    pub limit: Option<i64>,
+    /// `next_cursor` of the previous page
+    pub cursor: Option<String>,
+}
+
+/// One page of projects
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectPageResponse {
+    pub items: Vec<ProjectResponse>,
+    /// Projects matching the filters, across all pages
+    pub total: i64,
+    /// Pass as `cursor` to get the next page; absent on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Request DTO for inviting a wallet to a project
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct InviteMemberRequest {
+    pub address: String,
+    pub role: ProjectRole,
+}
+
+/// Response DTO for a project member or pending invitation
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectMemberResponse {
+    pub address: String,
+    pub role: ProjectRole,
+    pub status: MembershipStatus,
+    /// Absent for the project creator
+    pub invited_by: Option<String>,
+    pub invited_at: DateTime<Utc>,
+    pub joined_at: Option<DateTime<Utc>>,
+}
+
+impl From<ProjectMember> for ProjectMemberResponse {
+    fn from(member: ProjectMember) -> Self {
+        Self {
+            address: member.address.to_string(),
+            role: member.role,
+            status: member.status,
+            invited_by: member.invited_by.map(|a| a.to_string()),
+            invited_at: member.invited_at,
+            joined_at: member.joined_at,
+        }
+    }
+}
+
+/// Request DTO for moving a project through its lifecycle
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct TransitionProjectRequest {
+    pub status: ProjectStatus,
+    /// Reason recorded in the project history
+    pub note: Option<String>,
+}
+
+/// Response DTO for one entry of a project's history
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectTransitionResponse {
+    /// Absent for the entry recorded at creation
+    pub from: Option<ProjectStatus>,
+    pub to: ProjectStatus,
+    pub actor: String,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ProjectTransition> for ProjectTransitionResponse {
+    fn from(transition: ProjectTransition) -> Self {
+        Self {
+            from: transition.from,
+            to: transition.to,
+            actor: transition.actor.to_string(),
+            note: transition.note,
+            created_at: transition.created_at,
+        }
+    }
+}
+
+/// Response DTO for a project's review round
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectApprovalsResponse {
+    /// Reviewers who approved, oldest first; only active reviewers are listed
+    pub approvals: Vec<ProjectApprovalResponse>,
+    /// Approvals needed before the project can be approved
+    pub required: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectApprovalResponse {
+    pub reviewer: String,
+    pub approved_at: DateTime<Utc>,
+}
+
+impl From<ProjectApproval> for ProjectApprovalResponse {
+    fn from(approval: ProjectApproval) -> Self {
+        Self {
+            reviewer: approval.reviewer.to_string(),
+            approved_at: approval.approved_at,
+        }
+    }
+}
+
+/// GitHub repositories linked to a project, by bare name (as passed to /admin/github/sync)
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ProjectRepos {
+    pub repos: Vec<String>,
+}
+
+/// Query parameters for GET /projects/:id/issues
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct ProjectIssuesQuery {
+    /// open or closed
+    pub state: Option<String>,
+}
+
+/// Issues closed and points earned by one GitHub user on a project
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ContributorStats {
+    pub login: String,
+    pub closed_issues: usize,
+    pub points: i64,
+}
+
+/// Aggregates over the synced issues of a project's repositories
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProjectStatsResponse {
+    pub repos: Vec<String>,
+    pub open_issues: usize,
+    pub closed_issues: usize,
+    /// Sum of the points of closed issues
+    pub points_delivered: i64,
+    /// Assignees of closed issues by points, then closed issues; at most 10
+    pub top_contributors: Vec<ContributorStats>,
+}
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::{
+    entities::distribution::{csv_field, keccak256_hex, DistributionLine},
+    errors::AppError,
+    repositories::distribution_repository::DistributionRepository,
+    value_objects::{TokenAmount, WalletAddress},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// `attestations.json` consumed by EmitAttestationsJson.s.sol
+    Attestations,
+    /// `contribution-tokens-latest.json` consumed by MintTGCFromJson.s.sol
+    Mints,
+    Csv,
+}
+
+/// A rendered distribution file and the keccak256 of its exact bytes.
+#[derive(Debug, Clone)]
+pub struct DistributionExport {
+    pub filename: String,
+    pub content_type: &'static str,
+    pub body: Vec<u8>,
+    pub content_hash: String,
+}
+
+#[derive(Serialize)]
+struct AttestationsFile {
+    attestations: Vec<AttestationEntry>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AttestationEntry {
+    recipient: String,
+    badge_name: String,
+    justification: String,
+}
+
+#[derive(Serialize)]
+struct MintsFile {
+    mints: Vec<MintEntry>,
+}
+
+#[derive(Serialize)]
+struct MintEntry {
+    recipient: String,
+    amount: TokenAmount,
+    reason: String,
+}
+
+pub async fn export_distribution(
+    repository: Arc<dyn DistributionRepository>,
+    distribution_id: String,
+    format: ExportFormat,
+) -> Result<DistributionExport, AppError> {
+    let distribution = repository
+        .find_by_id(&distribution_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Distribution not found"))?;
+
+    let mut lines = distribution.lines;
+    lines.sort_by(|a, b| (&a.address, &a.badge_name).cmp(&(&b.address, &b.badge_name)));
+
+    let (filename, content_type, body) = match format {
+        ExportFormat::Attestations => (
+            "attestations.json",
+            "application/json",
+            render_attestations(&lines)?,
+        ),
+        ExportFormat::Mints => (
+            "contribution-tokens-latest.json",
+            "application/json",
+            render_mints(&lines)?,
+        ),
+        ExportFormat::Csv => ("distribution.csv", "text/csv", render_csv(&lines)),
+    };
+
+    Ok(DistributionExport {
+        filename: format!("{}-{}", distribution.id, filename),
+        content_type,
+        content_hash: keccak256_hex(&body),
+        body,
+    })
+}
+
+/// Checksummed when the stored address is a valid one, so equal inputs export identically.
+fn recipient(line: &DistributionLine) -> String {
+    WalletAddress(line.address.clone())
+        .to_checksum()
+        .unwrap_or_else(|| line.address.clone())
+}
+
+fn render_attestations(lines: &[DistributionLine]) -> Result<Vec<u8>, AppError> {
+    let file = AttestationsFile {
+        attestations: lines
+            .iter()
+            .map(|line| AttestationEntry {
+                recipient: recipient(line),
+                badge_name: line.badge_name.clone(),
+                justification: line.reason.clone().unwrap_or_default(),
+            })
+            .collect(),
+    };
+    to_json(&file)
+}
+
+fn render_mints(lines: &[DistributionLine]) -> Result<Vec<u8>, AppError> {
+    let mut mints = Vec::with_capacity(lines.len());
+    for line in lines {
+        let amount = line
+            .amount
+            .filter(|amount| !amount.is_zero())
+            .ok_or_else(|| {
+                AppError::validation(format!(
+                    "Line for {} ({}) has no positive amount; it cannot be minted",
+                    line.address, line.badge_name
+                ))
+            })?;
+        mints.push(MintEntry {
+            recipient: recipient(line),
+            amount,
+            reason: line.reason.clone().unwrap_or_default(),
+        });
+    }
+    to_json(&MintsFile { mints })
+}
+
+fn render_csv(lines: &[DistributionLine]) -> Vec<u8> {
+    let mut csv = String::from("recipient,badge_name,amount,reason\n");
+    for line in lines {
+        let amount = line.amount.map(|a| a.to_string()).unwrap_or_default();
+        let reason = line.reason.clone().unwrap_or_default();
+        csv.push_str(&format!(
+            "{},{},{},{}\n",
+            csv_field(&recipient(line)),
+            csv_field(&line.badge_name),
+            amount,
+            csv_field(&reason)
+        ));
+    }
+    csv.into_bytes()
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<Vec<u8>, AppError> {
+    let mut body = serde_json::to_vec_pretty(value)
+        .map_err(|e| AppError::internal(format!("Failed to render export: {e}")))?;
+    body.push(b'\n');
+    Ok(body)
+}
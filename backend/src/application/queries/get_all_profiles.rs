@@ -1,27 +1,99 @@
-use crate::application::dtos::profile_dtos::ProfileResponse;
-use crate::domain::repositories::profile_repository::ProfileRepository;
+use crate::application::dtos::profile_dtos::{
+    ListProfilesQuery, ProfilePageResponse, ProfileResponse,
+};
+use crate::domain::errors::AppError;
+use crate::domain::repositories::profile_repository::{
+    ProfileRepository, ProfileSearch, ProfileSort,
+};
+use crate::domain::value_objects::{PageCursor, SearchTerms};
 use std::sync::Arc;
 
+const DEFAULT_PAGE_SIZE: i64 = 20;
+const MAX_PAGE_SIZE: i64 = 100;
+
 pub async fn get_all_profiles(
     profile_repository: Arc<dyn ProfileRepository + 'static>,
-) -> Result<Vec<ProfileResponse>, String> {
-    let profiles = profile_repository
-        .find_all()
-        .await
-        .map_err(|e| e.to_string())?;
+    params: ListProfilesQuery,
+) -> Result<ProfilePageResponse, AppError> {
+    let sort: ProfileSort = match params.sort.as_deref() {
+        Some(sort) => sort
+            .parse()
+            .map_err(|e: String| AppError::invalid_field("sort", e))?,
+        None => ProfileSort::default(),
+    };
+
+    let limit = params.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+    if !(1..=MAX_PAGE_SIZE).contains(&limit) {
+        return Err(AppError::invalid_field(
+            "limit",
+            format!("limit must be between 1 and {MAX_PAGE_SIZE}"),
+        ));
+    }
+
+    let after = match params.cursor.as_deref() {
+        Some(cursor) => {
+            let cursor =
+                PageCursor::decode(cursor).map_err(|e| AppError::invalid_field("cursor", e))?;
+            if cursor.sort != sort.as_str() {
+                return Err(AppError::invalid_field(
+                    "cursor",
+                    "cursor was issued for a different sort",
+                ));
+            }
+            Some(cursor)
+        }
+        None => None,
+    };
+
+    let search = ProfileSearch {
+        terms: SearchTerms::parse(params.q.as_deref().unwrap_or_default()),
+        has_github: params.has_github,
+        has_badges: params.has_badges,
+        sort,
+        limit,
+        after,
+    };
+    let page = profile_repository.search(&search).await?;
 
-    Ok(profiles
-        .into_iter()
-        .map(|profile| ProfileResponse {
-            address: profile.address,
-            name: profile.name.unwrap_or_default(),
-            description: profile.description,
-            avatar_url: profile.avatar_url,
-            github_login: profile.github_login,
-            twitter_handle: profile.twitter_handle,
-            linkedin_account: profile.linkedin_account,
-            created_at: profile.created_at,
-            updated_at: profile.updated_at,
-        })
-        .collect())
+    Ok(ProfilePageResponse {
+        items: page
+            .profiles
+            .into_iter()
+            .map(|profile| ProfileResponse {
+                address: profile.address,
+                name: profile.name.unwrap_or_default(),
+                description: profile.description,
+                avatar_url: profile.avatar_url,
+                github_login: profile.github_login,
+                twitter_handle: profile.twitter_handle,
+                linkedin_account: profile.linkedin_account,
+                created_at: profile.created_at,
+                updated_at: profile.updated_at,
+            })
+            .collect(),
+        total: page.total,
+        next_cursor: page.next_cursor.map(|cursor| cursor.encode()),
+    })
+}
+
+/// Every matching profile, following the cursors page by page. Serves the
+/// deprecated root `GET /profiles`, which returned a bare array of all profiles.
+pub async fn get_all_profiles_unpaged(
+    profile_repository: Arc<dyn ProfileRepository + 'static>,
+    params: ListProfilesQuery,
+) -> Result<Vec<ProfileResponse>, AppError> {
+    let mut params = ListProfilesQuery {
+        limit: Some(MAX_PAGE_SIZE),
+        cursor: None,
+        ..params
+    };
+    let mut profiles = Vec::new();
+    loop {
+        let page = get_all_profiles(profile_repository.clone(), params.clone()).await?;
+        profiles.extend(page.items);
+        match page.next_cursor {
+            Some(cursor) => params.cursor = Some(cursor),
+            None => return Ok(profiles),
+        }
+    }
 }
@@ -1,62 +1,138 @@
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
+
 use crate::{
-    application::dtos::project_dtos::ProjectResponse,
+    application::dtos::project_dtos::{ListProjectsQuery, ProjectPageResponse, ProjectResponse},
     domain::{
-        entities::projects::ProjectStatus, repositories::project_repository::ProjectRepository,
-        value_objects::WalletAddress,
+        entities::projects::ProjectStatus,
+        errors::AppError,
+        repositories::project_repository::{ProjectRepository, ProjectSearch, ProjectSort},
+        value_objects::{PageCursor, SearchTerms, WalletAddress},
     },
 };
 
+const DEFAULT_PAGE_SIZE: i64 = 20;
+const MAX_PAGE_SIZE: i64 = 100;
+
+fn parse_timestamp(field: &str, value: Option<String>) -> Result<Option<DateTime<Utc>>, AppError> {
+    value
+        .map(|value| {
+            DateTime::parse_from_rfc3339(&value)
+                .map(|at| at.with_timezone(&Utc))
+                .map_err(|_| {
+                    AppError::invalid_field(field, format!("{field} must be an RFC 3339 timestamp"))
+                })
+        })
+        .transpose()
+}
+
 pub async fn get_all_projects(
     repository: Arc<dyn ProjectRepository>,
-    status: Option<String>,
-    creator: Option<String>,
-    limit: Option<i64>,
-    offset: Option<i64>,
-) -> Result<Vec<ProjectResponse>, String> {
+    params: ListProjectsQuery,
+) -> Result<ProjectPageResponse, AppError> {
     // Parse status if provided
-    let status_filter = if let Some(status_str) = status {
+    let status = if let Some(status_str) = params.status {
         Some(
             status_str
                 .parse::<ProjectStatus>()
-                .map_err(|e| format!("Invalid status: {}", e))?,
+                .map_err(|e| AppError::invalid_field("status", format!("Invalid status: {}", e)))?,
         )
     } else {
         None
     };
 
     // Parse creator if provided
-    let creator_filter = if let Some(creator_str) = creator {
-        Some(
-            WalletAddress::new(creator_str)
-                .map_err(|e| format!("Invalid creator address: {}", e))?,
-        )
+    let creator = if let Some(creator_str) = params.creator {
+        Some(WalletAddress::new(creator_str).map_err(|e| {
+            AppError::invalid_field("creator", format!("Invalid creator address: {}", e))
+        })?)
     } else {
         None
     };
 
-    // Validate and limit pagination
-    let limit = limit.map(|l| l.clamp(1, 100));
-    let offset = offset.map(|o| o.max(0));
-
-    // Get projects
-    let projects = repository
-        .find_all(status_filter, creator_filter.as_ref(), limit, offset)
-        .await
-        .map_err(|e| e.to_string())?;
-
-    // Convert to responses
-    Ok(projects
-        .into_iter()
-        .map(|project| ProjectResponse {
-            id: project.id.value().to_string(),
-            name: project.name,
-            description: project.description,
-            status: project.status,
-            creator: project.creator.to_string(),
-            created_at: project.created_at,
-            updated_at: project.updated_at,
-        })
-        .collect())
+    let created_after = parse_timestamp("created_after", params.created_after)?;
+    let created_before = parse_timestamp("created_before", params.created_before)?;
+
+    let sort: ProjectSort = match params.sort.as_deref() {
+        Some(sort) => sort
+            .parse()
+            .map_err(|e: String| AppError::invalid_field("sort", e))?,
+        None => ProjectSort::default(),
+    };
+
+    let limit = params.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+    if !(1..=MAX_PAGE_SIZE).contains(&limit) {
+        return Err(AppError::invalid_field(
+            "limit",
+            format!("limit must be between 1 and {MAX_PAGE_SIZE}"),
+        ));
+    }
+
+    let after = match params.cursor.as_deref() {
+        Some(cursor) => {
+            let cursor =
+                PageCursor::decode(cursor).map_err(|e| AppError::invalid_field("cursor", e))?;
+            if cursor.sort != sort.as_str() {
+                return Err(AppError::invalid_field(
+                    "cursor",
+                    "cursor was issued for a different sort",
+                ));
+            }
+            Some(cursor)
+        }
+        None => None,
+    };
+
+    let search = ProjectSearch {
+        terms: SearchTerms::parse(params.q.as_deref().unwrap_or_default()),
+        status,
+        creator,
+        created_after,
+        created_before,
+        sort,
+        limit,
+        after,
+    };
+    let page = repository.search(&search).await?;
+
+    Ok(ProjectPageResponse {
+        items: page
+            .projects
+            .into_iter()
+            .map(|project| ProjectResponse {
+                id: project.id.value().to_string(),
+                name: project.name,
+                description: project.description,
+                status: project.status,
+                creator: project.creator.to_string(),
+                created_at: project.created_at,
+                updated_at: project.updated_at,
+            })
+            .collect(),
+        total: page.total,
+        next_cursor: page.next_cursor.map(|cursor| cursor.encode()),
+    })
+}
+
+/// Every matching project, following the cursors page by page. Serves the
+/// deprecated root `GET /projects`, which returned a bare array.
+pub async fn get_all_projects_unpaged(
+    repository: Arc<dyn ProjectRepository>,
+    params: ListProjectsQuery,
+) -> Result<Vec<ProjectResponse>, AppError> {
+    let mut params = ListProjectsQuery {
+        limit: Some(MAX_PAGE_SIZE),
+        cursor: None,
+        ..params
+    };
+    let mut projects = Vec::new();
+    loop {
+        let page = get_all_projects(repository.clone(), params.clone()).await?;
+        projects.extend(page.items);
+        match page.next_cursor {
+            Some(cursor) => params.cursor = Some(cursor),
+            None => return Ok(projects),
+        }
+    }
 }
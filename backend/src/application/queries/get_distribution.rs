@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use crate::{
+    application::dtos::distribution_dtos::DistributionDetailResponse,
+    domain::{errors::AppError, repositories::distribution_repository::DistributionRepository},
+};
+
+pub async fn get_distribution(
+    repository: Arc<dyn DistributionRepository>,
+    distribution_id: String,
+) -> Result<DistributionDetailResponse, AppError> {
+    repository
+        .find_by_id(&distribution_id)
+        .await?
+        .map(Into::into)
+        .ok_or_else(|| AppError::not_found("Distribution not found"))
+}
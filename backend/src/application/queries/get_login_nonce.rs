@@ -1,11 +1,13 @@
 use std::sync::Arc;
 
-use crate::domain::{repositories::ProfileRepository, value_objects::WalletAddress};
+use crate::domain::{
+    errors::AppError, repositories::ProfileRepository, value_objects::WalletAddress,
+};
 
 pub async fn get_login_nonce(
     profile_repository: Arc<dyn ProfileRepository>,
     address: String,
-) -> Result<i64, String> {
+) -> Result<i64, AppError> {
     let wallet_address = WalletAddress(address);
 
     match profile_repository
@@ -14,6 +16,6 @@ pub async fn get_login_nonce(
     {
         Ok(Some(nonce)) => Ok(nonce),
         Ok(None) => Ok(1), // Return default nonce for new addresses
-        Err(e) => Err(format!("Error fetching nonce: {}", e)),
+        Err(e) => Err(AppError::internal(format!("Error fetching nonce: {}", e))),
     }
 }
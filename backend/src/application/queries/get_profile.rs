@@ -1,4 +1,5 @@
 use crate::application::dtos::profile_dtos::ProfileResponse;
+use crate::domain::errors::AppError;
 use crate::domain::repositories::profile_repository::ProfileRepository;
 use crate::domain::value_objects::wallet_address::WalletAddress;
 use std::sync::Arc;
@@ -6,14 +7,14 @@ use std::sync::Arc;
 pub async fn get_profile(
     profile_repository: Arc<dyn ProfileRepository + 'static>,
     address: String,
-) -> Result<ProfileResponse, String> {
-    let wallet_address = WalletAddress::new(address).map_err(|e| e.to_string())?;
+) -> Result<ProfileResponse, AppError> {
+    let wallet_address =
+        WalletAddress::new(address).map_err(|e| AppError::invalid_field("address", e))?;
 
     let profile = profile_repository
         .find_by_address(&wallet_address)
-        .await
-        .map_err(|e| e.to_string())?
-        .ok_or("Profile not found")?;
+        .await?
+        .ok_or_else(|| AppError::not_found("Profile not found"))?;
 
     Ok(ProfileResponse {
         address: wallet_address,
@@ -3,23 +3,26 @@ use uuid::Uuid;
 
 use crate::{
     application::dtos::project_dtos::ProjectResponse,
-    domain::{entities::projects::ProjectId, repositories::project_repository::ProjectRepository},
+    domain::{
+        entities::projects::ProjectId, errors::AppError,
+        repositories::project_repository::ProjectRepository,
+    },
 };
 
 pub async fn get_project(
     repository: Arc<dyn ProjectRepository>,
     project_id: String,
-) -> Result<ProjectResponse, String> {
+) -> Result<ProjectResponse, AppError> {
     // Parse project ID
-    let id = Uuid::parse_str(&project_id).map_err(|_| "Invalid project ID".to_string())?;
+    let id = Uuid::parse_str(&project_id)
+        .map_err(|_| AppError::invalid_field("id", "Invalid project ID"))?;
     let project_id = ProjectId::from_uuid(id);
 
     // Get project
     let project = repository
         .find_by_id(&project_id)
-        .await
-        .map_err(|e| e.to_string())?
-        .ok_or_else(|| "Project not found".to_string())?;
+        .await?
+        .ok_or_else(|| AppError::not_found("Project not found"))?;
 
     // Return response
     Ok(ProjectResponse {
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    application::dtos::project_dtos::{ProjectApprovalResponse, ProjectApprovalsResponse},
+    domain::{
+        entities::projects::{ProjectApproval, ProjectId, ReviewPolicy},
+        errors::AppError,
+        repositories::project_repository::ProjectRepository,
+    },
+};
+
+/// Approvals of the current review round that still count: reviewers who left
+/// the project or changed role since approving are ignored.
+pub async fn current_approvals(
+    repository: &dyn ProjectRepository,
+    project_id: &ProjectId,
+) -> Result<Vec<ProjectApproval>, AppError> {
+    let members = repository.list_members(project_id).await?;
+    let approvals = repository.list_approvals(project_id).await?;
+    Ok(approvals
+        .into_iter()
+        .filter(|approval| {
+            let reviewer = approval.reviewer.checksummed();
+            members
+                .iter()
+                .any(|m| m.address.checksummed() == reviewer && m.can_review())
+        })
+        .collect())
+}
+
+pub async fn get_project_approvals(
+    repository: Arc<dyn ProjectRepository>,
+    policy: ReviewPolicy,
+    project_id: String,
+) -> Result<ProjectApprovalsResponse, AppError> {
+    // Parse project ID
+    let id = Uuid::parse_str(&project_id)
+        .map_err(|_| AppError::invalid_field("id", "Invalid project ID"))?;
+    let project_id = ProjectId::from_uuid(id);
+
+    if !repository.exists(&project_id).await? {
+        return Err(AppError::not_found("Project not found"));
+    }
+
+    let approvals = current_approvals(repository.as_ref(), &project_id).await?;
+    Ok(ProjectApprovalsResponse {
+        approvals: approvals
+            .into_iter()
+            .map(ProjectApprovalResponse::from)
+            .collect(),
+        required: policy.required_approvals,
+    })
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    application::dtos::project_dtos::ProjectIssuesQuery,
+    domain::{
+        entities::{github_issue::GithubIssue, projects::ProjectId},
+        errors::AppError,
+        repositories::{
+            github_issue_repository::GithubIssueRepository, project_repository::ProjectRepository,
+        },
+    },
+};
+
+/// Synced issues of every repository linked to the project, newest first.
+pub async fn project_issues(
+    projects: &dyn ProjectRepository,
+    issues: &dyn GithubIssueRepository,
+    project_id: &ProjectId,
+    state: Option<&str>,
+) -> Result<Vec<GithubIssue>, AppError> {
+    let mut all = Vec::new();
+    for repo in projects.list_repos(project_id).await? {
+        all.extend(issues.list_by_repo(&repo, state).await?);
+    }
+    all.sort_by(|a, b| {
+        (b.created_at, b.repo_id, b.github_issue_id).cmp(&(
+            a.created_at,
+            a.repo_id,
+            a.github_issue_id,
+        ))
+    });
+    Ok(all)
+}
+
+pub async fn get_project_issues(
+    projects: Arc<dyn ProjectRepository>,
+    issues: Arc<dyn GithubIssueRepository>,
+    project_id: String,
+    params: ProjectIssuesQuery,
+) -> Result<Vec<GithubIssue>, AppError> {
+    // Parse project ID
+    let id = Uuid::parse_str(&project_id)
+        .map_err(|_| AppError::invalid_field("id", "Invalid project ID"))?;
+    let project_id = ProjectId::from_uuid(id);
+
+    if let Some(state) = params.state.as_deref() {
+        if state != "open" && state != "closed" {
+            return Err(AppError::invalid_field(
+                "state",
+                "state must be open or closed",
+            ));
+        }
+    }
+
+    if !projects.exists(&project_id).await? {
+        return Err(AppError::not_found("Project not found"));
+    }
+
+    project_issues(
+        projects.as_ref(),
+        issues.as_ref(),
+        &project_id,
+        params.state.as_deref(),
+    )
+    .await
+}
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    application::dtos::project_dtos::ProjectMemberResponse,
+    domain::{
+        entities::projects::ProjectId, errors::AppError,
+        repositories::project_repository::ProjectRepository,
+    },
+};
+
+pub async fn get_project_members(
+    repository: Arc<dyn ProjectRepository>,
+    project_id: String,
+) -> Result<Vec<ProjectMemberResponse>, AppError> {
+    // Parse project ID
+    let id = Uuid::parse_str(&project_id)
+        .map_err(|_| AppError::invalid_field("id", "Invalid project ID"))?;
+    let project_id = ProjectId::from_uuid(id);
+
+    if !repository.exists(&project_id).await? {
+        return Err(AppError::not_found("Project not found"));
+    }
+
+    let members = repository.list_members(&project_id).await?;
+    Ok(members
+        .into_iter()
+        .map(ProjectMemberResponse::from)
+        .collect())
+}
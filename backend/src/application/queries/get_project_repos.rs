@@ -0,0 +1,28 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    application::dtos::project_dtos::ProjectRepos,
+    domain::{
+        entities::projects::ProjectId, errors::AppError,
+        repositories::project_repository::ProjectRepository,
+    },
+};
+
+pub async fn get_project_repos(
+    repository: Arc<dyn ProjectRepository>,
+    project_id: String,
+) -> Result<ProjectRepos, AppError> {
+    // Parse project ID
+    let id = Uuid::parse_str(&project_id)
+        .map_err(|_| AppError::invalid_field("id", "Invalid project ID"))?;
+    let project_id = ProjectId::from_uuid(id);
+
+    if !repository.exists(&project_id).await? {
+        return Err(AppError::not_found("Project not found"));
+    }
+
+    Ok(ProjectRepos {
+        repos: repository.list_repos(&project_id).await?,
+    })
+}
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        dtos::project_dtos::{ContributorStats, ProjectStatsResponse},
+        queries::get_project_issues::project_issues,
+    },
+    domain::{
+        entities::projects::ProjectId,
+        errors::AppError,
+        repositories::{
+            github_issue_repository::GithubIssueRepository, project_repository::ProjectRepository,
+        },
+    },
+};
+
+const TOP_CONTRIBUTORS: usize = 10;
+
+/// Issue counts, delivered points and top contributors across the project's
+/// repositories. Every assignee of a closed issue is credited with its points.
+pub async fn get_project_stats(
+    projects: Arc<dyn ProjectRepository>,
+    issues: Arc<dyn GithubIssueRepository>,
+    project_id: String,
+) -> Result<ProjectStatsResponse, AppError> {
+    // Parse project ID
+    let id = Uuid::parse_str(&project_id)
+        .map_err(|_| AppError::invalid_field("id", "Invalid project ID"))?;
+    let project_id = ProjectId::from_uuid(id);
+
+    if !projects.exists(&project_id).await? {
+        return Err(AppError::not_found("Project not found"));
+    }
+
+    let repos = projects.list_repos(&project_id).await?;
+    let all = project_issues(projects.as_ref(), issues.as_ref(), &project_id, None).await?;
+
+    let mut open_issues = 0;
+    let mut closed_issues = 0;
+    let mut points_delivered = 0i64;
+    let mut contributors: HashMap<String, ContributorStats> = HashMap::new();
+    for issue in &all {
+        if issue.state != "closed" {
+            open_issues += 1;
+            continue;
+        }
+        closed_issues += 1;
+        points_delivered += i64::from(issue.points);
+
+        let logins = issue.assignee_logins.as_array().into_iter().flatten();
+        for login in logins.filter_map(|l| l.as_str()) {
+            let stats = contributors
+                .entry(login.to_string())
+                .or_insert_with(|| ContributorStats {
+                    login: login.to_string(),
+                    closed_issues: 0,
+                    points: 0,
+                });
+            stats.closed_issues += 1;
+            stats.points += i64::from(issue.points);
+        }
+    }
+
+    let mut top_contributors: Vec<ContributorStats> = contributors.into_values().collect();
+    top_contributors.sort_by(|a, b| {
+        b.points
+            .cmp(&a.points)
+            .then(b.closed_issues.cmp(&a.closed_issues))
+            .then(a.login.cmp(&b.login))
+    });
+    top_contributors.truncate(TOP_CONTRIBUTORS);
+
+    Ok(ProjectStatsResponse {
+        repos,
+        open_issues,
+        closed_issues,
+        points_delivered,
+        top_contributors,
+    })
+}
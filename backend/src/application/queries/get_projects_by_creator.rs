@@ -2,22 +2,23 @@ use std::sync::Arc;
 
 use crate::{
     application::dtos::project_dtos::ProjectResponse,
-    domain::{repositories::project_repository::ProjectRepository, value_objects::WalletAddress},
+    domain::{
+        errors::AppError, repositories::project_repository::ProjectRepository,
+        value_objects::WalletAddress,
+    },
 };
 
 pub async fn get_projects_by_creator(
     repository: Arc<dyn ProjectRepository>,
     creator_address: String,
-) -> Result<Vec<ProjectResponse>, String> {
+) -> Result<Vec<ProjectResponse>, AppError> {
     // Validate creator address
-    let creator = WalletAddress::new(creator_address)
-        .map_err(|e| format!("Invalid wallet address: {}", e))?;
+    let creator = WalletAddress::new(creator_address).map_err(|e| {
+        AppError::invalid_field("address", format!("Invalid wallet address: {}", e))
+    })?;
 
     // Get projects
-    let projects = repository
-        .find_by_creator(&creator)
-        .await
-        .map_err(|e| e.to_string())?;
+    let projects = repository.find_by_creator(&creator).await?;
 
     // Convert to responses
     Ok(projects
@@ -2,25 +2,15 @@ use std::sync::Arc;
 
 use crate::{
     application::dtos::distribution_dtos::DistributionResponse,
-    domain::repositories::distribution_repository::DistributionRepository,
+    domain::{errors::AppError, repositories::distribution_repository::DistributionRepository},
 };
 
 pub async fn list_distributions(
     repository: Arc<dyn DistributionRepository>,
     distribution_id: Option<String>,
-) -> Result<Vec<DistributionResponse>, String> {
+) -> Result<Vec<DistributionResponse>, AppError> {
     repository
-        .list(distribution_id.as_deref())
+        .list_lines(distribution_id.as_deref())
         .await
-        .map(|records| {
-            records
-                .into_iter()
-                .map(|record| DistributionResponse {
-                    address: record.address,
-                    badge_name: record.badge_name,
-                    distribution_id: record.distribution_id,
-                })
-                .collect()
-        })
-        .map_err(|e| e.to_string())
+        .map(|lines| lines.into_iter().map(Into::into).collect())
 }
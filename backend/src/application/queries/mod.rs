@@ -1,8 +1,17 @@
+pub mod export_distribution;
 pub mod get_all_profiles;
 pub mod get_all_projects;
+pub mod get_distribution;
 pub mod get_login_nonce;
 pub mod get_profile;
 pub mod get_projects_by_creator;
 pub mod list_distributions;
+pub mod validate_distribution;
 
 pub mod get_project;
+pub mod get_project_approvals;
+pub mod get_project_history;
+pub mod get_project_issues;
+pub mod get_project_members;
+pub mod get_project_repos;
+pub mod get_project_stats;
@@ -0,0 +1,170 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::{
+    application::commands::register_distribution::{build_distributions, supplied_id},
+    application::dtos::distribution_dtos::{
+        DistributionDryRunReport, DistributionLineIssue, DistributionLineReport,
+        RegisterDistributionItem, RegisterDistributionRequest,
+    },
+    domain::{
+        errors::AppError,
+        repositories::{DistributionRepository, ProfileRepository},
+        services::badge_registry_service::BadgeRegistryService,
+        value_objects::WalletAddress,
+    },
+};
+
+fn issue(code: &str, message: impl Into<String>) -> DistributionLineIssue {
+    DistributionLineIssue {
+        code: code.to_string(),
+        message: message.into(),
+    }
+}
+
+/// Checks every item of a register request without persisting anything:
+/// EIP-55 address, existing profile, badge name fitting a bytes32 and present in
+/// the indexed registry, and no duplicate recipient/badge pair in the request or
+/// in an earlier distribution. Registration runs the same checks.
+pub async fn validate_distribution(
+    profile_repository: Arc<dyn ProfileRepository>,
+    distribution_repository: Arc<dyn DistributionRepository>,
+    badge_registry: Arc<dyn BadgeRegistryService>,
+    creator_address: String,
+    request: RegisterDistributionRequest,
+) -> Result<DistributionDryRunReport, AppError> {
+    if request.distributions.is_empty() {
+        return Err(AppError::invalid_field(
+            "distributions",
+            "distributions must not be empty",
+        ));
+    }
+
+    // Repeated pairs are reported per line below; ids are derived from the first
+    // occurrence of each pair so that every line still reports one.
+    let mut first_pairs = HashSet::new();
+    let firsts: Vec<RegisterDistributionItem> = request
+        .distributions
+        .iter()
+        .filter(|item| {
+            first_pairs.insert((
+                supplied_id(item),
+                item.address.to_lowercase(),
+                item.badge_name.clone(),
+            ))
+        })
+        .cloned()
+        .collect();
+    let first_groups: Vec<Option<String>> = firsts.iter().map(supplied_id).collect();
+    let mut group_ids: HashMap<Option<String>, String> = HashMap::new();
+    for requested in build_distributions(&WalletAddress(creator_address), firsts)? {
+        for index in requested.item_indexes {
+            group_ids.insert(
+                first_groups[index].clone(),
+                requested.distribution.id.clone(),
+            );
+        }
+    }
+    let mut distribution_ids: Vec<String> = request
+        .distributions
+        .iter()
+        .map(|item| group_ids[&supplied_id(item)].clone())
+        .collect();
+
+    let mut known_badges: HashMap<String, bool> = HashMap::new();
+    let mut seen: HashSet<(String, String)> = HashSet::new();
+    let mut lines = Vec::with_capacity(request.distributions.len());
+
+    for (index, item) in request.distributions.into_iter().enumerate() {
+        let distribution_id = std::mem::take(&mut distribution_ids[index]);
+        let mut issues = Vec::new();
+        let wallet = WalletAddress(item.address.clone());
+
+        match wallet.to_checksum() {
+            None => issues.push(issue(
+                "invalid_address",
+                "Address must be 0x followed by 40 hex characters",
+            )),
+            Some(checksummed) if checksummed != item.address => issues.push(issue(
+                "invalid_checksum",
+                format!("Address is not EIP-55 checksummed; expected {checksummed}"),
+            )),
+            Some(_) => {
+                if profile_repository.find_by_address(&wallet).await?.is_none() {
+                    issues.push(issue(
+                        "unknown_profile",
+                        "No profile exists for this address",
+                    ));
+                }
+            }
+        }
+
+        if item.badge_name.trim().is_empty() || item.badge_name.len() > 32 {
+            issues.push(issue(
+                "invalid_badge_name",
+                "Badge name must be 1 to 32 bytes to fit in a bytes32",
+            ));
+        }
+
+        let badge_exists = match known_badges.get(&item.badge_name) {
+            Some(exists) => *exists,
+            None => {
+                let exists = badge_registry
+                    .badge_exists(&item.badge_name)
+                    .await
+                    .map_err(|e| {
+                        AppError::internal(format!("Badge registry lookup failed: {e}"))
+                    })?;
+                known_badges.insert(item.badge_name.clone(), exists);
+                exists
+            }
+        };
+        if !badge_exists {
+            issues.push(issue(
+                "unknown_badge",
+                format!("Badge {} is not in the badge registry", item.badge_name),
+            ));
+        }
+
+        let pair = (item.address.to_lowercase(), item.badge_name.clone());
+        if !seen.insert(pair) {
+            issues.push(issue(
+                "duplicate_in_request",
+                "Recipient already receives this badge earlier in the request",
+            ));
+        }
+
+        let earlier: Vec<String> = distribution_repository
+            .find_distribution_ids_with_line(&item.address, &item.badge_name)
+            .await?
+            .into_iter()
+            .filter(|id| *id != distribution_id)
+            .collect();
+        if !earlier.is_empty() {
+            issues.push(issue(
+                "already_distributed",
+                format!(
+                    "Recipient already has this badge in distribution {}",
+                    earlier.join(", ")
+                ),
+            ));
+        }
+
+        lines.push(DistributionLineReport {
+            index,
+            address: item.address,
+            badge_name: item.badge_name,
+            distribution_id,
+            valid: issues.is_empty(),
+            issues,
+        });
+    }
+
+    let invalid_count = lines.iter().filter(|line| !line.valid).count();
+    Ok(DistributionDryRunReport {
+        valid: invalid_count == 0,
+        line_count: lines.len(),
+        invalid_count,
+        lines,
+    })
+}
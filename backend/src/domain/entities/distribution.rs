@@ -0,0 +1,288 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+use crate::domain::{
+    errors::{AppError, FieldError},
+    value_objects::{TokenAmount, WalletAddress},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DistributionStatus {
+    /// Registered, lines may still be added.
+    Draft,
+    /// Broadcast on chain, waiting for the transaction to be confirmed.
+    Submitted,
+    Confirmed,
+    Failed,
+}
+
+impl DistributionStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DistributionStatus::Draft => "draft",
+            DistributionStatus::Submitted => "submitted",
+            DistributionStatus::Confirmed => "confirmed",
+            DistributionStatus::Failed => "failed",
+        }
+    }
+
+    /// Allowed moves: draft → submitted → confirmed | failed.
+    /// A failed distribution can be submitted again with a new transaction.
+    pub fn can_transition_to(&self, next: DistributionStatus) -> bool {
+        use DistributionStatus::*;
+        matches!(
+            (self, next),
+            (Draft, Submitted) | (Submitted, Confirmed) | (Submitted, Failed) | (Failed, Submitted)
+        )
+    }
+}
+
+impl std::fmt::Display for DistributionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for DistributionStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "draft" => Ok(DistributionStatus::Draft),
+            "submitted" => Ok(DistributionStatus::Submitted),
+            "confirmed" => Ok(DistributionStatus::Confirmed),
+            "failed" => Ok(DistributionStatus::Failed),
+            _ => Err(format!("Invalid distribution status: {}", s)),
+        }
+    }
+}
+
+/// One recipient/badge pair of a distribution. `amount` is only set for
+/// contribution token distributions; `reason` is the attestation justification
+/// or the mint reason.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DistributionLine {
+    pub distribution_id: String,
+    pub address: String,
+    pub badge_name: String,
+    pub amount: Option<TokenAmount>,
+    pub reason: Option<String>,
+}
+
+/// Distribution header together with its lines.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Distribution {
+    pub id: String,
+    pub creator: WalletAddress,
+    pub status: DistributionStatus,
+    pub tx_hash: Option<String>,
+    pub chain_id: Option<i64>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub lines: Vec<DistributionLine>,
+}
+
+impl Distribution {
+    pub fn new(id: String, creator: WalletAddress, lines: Vec<DistributionLine>) -> Self {
+        let now = Utc::now();
+        Self {
+            id,
+            creator,
+            status: DistributionStatus::Draft,
+            tx_hash: None,
+            chain_id: None,
+            created_at: now,
+            updated_at: now,
+            lines,
+        }
+    }
+
+    /// Builds a draft whose id is derived from its content (see [`Distribution::content_id`]).
+    /// Addresses are stored in EIP-55 form; a recipient/badge pair given twice is a
+    /// Validation error listing the repeated rows, whatever their amount or reason.
+    pub fn from_lines(
+        creator: WalletAddress,
+        lines: Vec<DistributionLine>,
+    ) -> Result<Self, AppError> {
+        let mut normalized: Vec<DistributionLine> = lines
+            .into_iter()
+            .map(|line| {
+                let address = WalletAddress(line.address.clone())
+                    .to_checksum()
+                    .unwrap_or(line.address);
+                DistributionLine { address, ..line }
+            })
+            .collect();
+
+        let repeated = Self::repeated_lines(&normalized);
+        if !repeated.is_empty() {
+            let details = repeated
+                .into_iter()
+                .map(|(row, first)| FieldError {
+                    field: format!("lines[{row}]"),
+                    message: format!(
+                        "{} already receives {} in lines[{first}]",
+                        normalized[row].address, normalized[row].badge_name
+                    ),
+                })
+                .collect();
+            return Err(AppError::Validation {
+                message: "A distribution cannot repeat a recipient/badge pair".into(),
+                details,
+            });
+        }
+
+        let id = Self::content_id(&normalized);
+        for line in &mut normalized {
+            line.distribution_id = id.clone();
+        }
+        Ok(Self::new(id, creator, normalized))
+    }
+
+    /// `(row, first_row)` for every line repeating the recipient/badge pair of an
+    /// earlier one. Addresses are compared case-insensitively.
+    pub fn repeated_lines(lines: &[DistributionLine]) -> Vec<(usize, usize)> {
+        let mut first_rows: HashMap<(String, &str), usize> = HashMap::new();
+        let mut repeated = Vec::new();
+        for (row, line) in lines.iter().enumerate() {
+            let pair = (line.address.to_lowercase(), line.badge_name.as_str());
+            match first_rows.get(&pair) {
+                Some(first) => repeated.push((row, *first)),
+                None => {
+                    first_rows.insert(pair, row);
+                }
+            }
+        }
+        repeated
+    }
+
+    /// Canonical CSV of the lines: an `address,badge_name,amount,reason` header, then one row per
+    /// line sorted by address and badge, with EIP-55 addresses, empty amount and reason when unset,
+    /// and badge names and reasons quoted as in RFC 4180 when they hold `,`, `"` or line breaks.
+    pub fn canonical_csv(lines: &[DistributionLine]) -> String {
+        let mut rows: Vec<String> = lines
+            .iter()
+            .map(|line| {
+                let address = WalletAddress(line.address.clone())
+                    .to_checksum()
+                    .unwrap_or_else(|| line.address.clone());
+                let amount = line.amount.map(|a| a.to_string()).unwrap_or_default();
+                format!(
+                    "{},{},{},{}",
+                    address,
+                    csv_field(&line.badge_name),
+                    amount,
+                    csv_field(line.reason.as_deref().unwrap_or_default())
+                )
+            })
+            .collect();
+        rows.sort();
+
+        let mut csv = String::from("address,badge_name,amount,reason\n");
+        for row in rows {
+            csv.push_str(&row);
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// keccak256 of the canonical CSV, 0x-prefixed: the same lines always yield the same id.
+    pub fn content_id(lines: &[DistributionLine]) -> String {
+        keccak256_hex(Self::canonical_csv(lines).as_bytes())
+    }
+
+    /// False when the id is a content hash that no longer matches the lines.
+    /// Distributions registered before ids were derived from content always pass.
+    pub fn content_matches_id(&self) -> bool {
+        !is_bytes32_hex(&self.id) || Self::content_id(&self.lines) == self.id
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Sum of the line amounts. Registration rejects distributions whose total
+    /// does not fit in a uint256, so this never saturates for stored ones.
+    pub fn total_amount(&self) -> TokenAmount {
+        Self::checked_total(&self.lines).unwrap_or(TokenAmount(ethers::types::U256::MAX))
+    }
+
+    /// Sum of the line amounts, None when it overflows a uint256.
+    pub fn checked_total(lines: &[DistributionLine]) -> Option<TokenAmount> {
+        lines
+            .iter()
+            .filter_map(|line| line.amount)
+            .try_fold(TokenAmount::default(), TokenAmount::checked_add)
+    }
+
+    /// Record the broadcast transaction.
+    pub fn submit(&mut self, tx_hash: String, chain_id: i64) -> Result<(), AppError> {
+        if !is_bytes32_hex(&tx_hash) {
+            return Err(AppError::invalid_field(
+                "txHash",
+                "txHash must be a 0x-prefixed 32-byte hex string",
+            ));
+        }
+        if chain_id <= 0 {
+            return Err(AppError::invalid_field(
+                "chainId",
+                "chainId must be a positive integer",
+            ));
+        }
+
+        if !self.content_matches_id() {
+            return Err(AppError::conflict(
+                "Distribution lines no longer match its id; refusing to submit",
+            ));
+        }
+
+        self.transition_to(DistributionStatus::Submitted)?;
+        self.tx_hash = Some(tx_hash);
+        self.chain_id = Some(chain_id);
+        Ok(())
+    }
+
+    pub fn confirm(&mut self) -> Result<(), AppError> {
+        self.transition_to(DistributionStatus::Confirmed)
+    }
+
+    pub fn fail(&mut self) -> Result<(), AppError> {
+        self.transition_to(DistributionStatus::Failed)
+    }
+
+    fn transition_to(&mut self, next: DistributionStatus) -> Result<(), AppError> {
+        if !self.status.can_transition_to(next) {
+            return Err(AppError::conflict(format!(
+                "Cannot move distribution from {} to {}",
+                self.status, next
+            )));
+        }
+        self.status = next;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+/// 0x-prefixed hex keccak256 digest.
+pub fn keccak256_hex(data: &[u8]) -> String {
+    let digest = Keccak256::digest(data);
+    let hex: String = digest.iter().map(|b| format!("{b:02x}")).collect();
+    format!("0x{hex}")
+}
+
+/// CSV field quoted as in RFC 4180 when it holds a separator, quote or line break.
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn is_bytes32_hex(id: &str) -> bool {
+    id.len() == 66 && id.starts_with("0x") && id[2..].chars().all(|c| c.is_ascii_hexdigit())
+}
@@ -1,7 +1,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct GithubIssue {
     pub repo_id: i64,
     pub github_issue_id: i64,
@@ -9,8 +10,10 @@ pub struct GithubIssue {
     pub issue_number: i32,
     pub title: String,
     pub state: String,
+    #[schema(value_type = Vec<String>)]
     pub labels: serde_json::Value,
     pub points: i32,
+    #[schema(value_type = Vec<String>)]
     pub assignee_logins: serde_json::Value,
     pub url: String,
     pub created_at: DateTime<Utc>,
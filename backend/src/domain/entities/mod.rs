@@ -1,6 +1,12 @@
+pub mod distribution;
 pub mod github_issue;
 pub mod profile;
+pub mod project_member;
 pub mod projects;
 
+pub use distribution::{Distribution, DistributionLine, DistributionStatus};
 pub use profile::Profile;
-pub use projects::{Project, ProjectId, ProjectStatus};
+pub use project_member::{MembershipStatus, ProjectMember, ProjectRole};
+pub use projects::{
+    Project, ProjectApproval, ProjectId, ProjectStatus, ProjectTransition, ReviewPolicy,
+};
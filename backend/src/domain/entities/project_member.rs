@@ -0,0 +1,147 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::{
+    entities::projects::{Project, ProjectId},
+    errors::AppError,
+    value_objects::WalletAddress,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectRole {
+    /// Edits and deletes the project and invites members.
+    Maintainer,
+    Contributor,
+    Reviewer,
+}
+
+impl ProjectRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProjectRole::Maintainer => "maintainer",
+            ProjectRole::Contributor => "contributor",
+            ProjectRole::Reviewer => "reviewer",
+        }
+    }
+}
+
+impl std::fmt::Display for ProjectRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for ProjectRole {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "maintainer" => Ok(ProjectRole::Maintainer),
+            "contributor" => Ok(ProjectRole::Contributor),
+            "reviewer" => Ok(ProjectRole::Reviewer),
+            _ => Err(format!("Invalid project role: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum MembershipStatus {
+    /// Invited by a maintainer, waiting for the invitee to accept.
+    Invited,
+    Active,
+}
+
+impl MembershipStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MembershipStatus::Invited => "invited",
+            MembershipStatus::Active => "active",
+        }
+    }
+}
+
+impl std::str::FromStr for MembershipStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "invited" => Ok(MembershipStatus::Invited),
+            "active" => Ok(MembershipStatus::Active),
+            _ => Err(format!("Invalid membership status: {}", s)),
+        }
+    }
+}
+
+/// A wallet attached to a project. The creator becomes an active maintainer
+/// when the project is created; everyone else joins by accepting an invitation.
+/// Addresses are kept in EIP-55 checksum form so lookups do not depend on
+/// the casing a client sends.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectMember {
+    pub project_id: ProjectId,
+    pub address: WalletAddress,
+    pub role: ProjectRole,
+    pub status: MembershipStatus,
+    /// None for the creator
+    pub invited_by: Option<WalletAddress>,
+    pub invited_at: DateTime<Utc>,
+    pub joined_at: Option<DateTime<Utc>>,
+}
+
+impl ProjectMember {
+    /// The creator's membership, active from the project's creation.
+    pub fn founder(project: &Project) -> Self {
+        Self {
+            project_id: project.id,
+            address: project.creator.checksummed(),
+            role: ProjectRole::Maintainer,
+            status: MembershipStatus::Active,
+            invited_by: None,
+            invited_at: project.created_at,
+            joined_at: Some(project.created_at),
+        }
+    }
+
+    pub fn invite(
+        project_id: ProjectId,
+        address: WalletAddress,
+        role: ProjectRole,
+        invited_by: WalletAddress,
+    ) -> Self {
+        Self {
+            project_id,
+            address: address.checksummed(),
+            role,
+            status: MembershipStatus::Invited,
+            invited_by: Some(invited_by.checksummed()),
+            invited_at: Utc::now(),
+            joined_at: None,
+        }
+    }
+
+    pub fn accept(&mut self) -> Result<(), AppError> {
+        if self.status == MembershipStatus::Active {
+            return Err(AppError::conflict("Invitation was already accepted"));
+        }
+        self.status = MembershipStatus::Active;
+        self.joined_at = Some(Utc::now());
+        Ok(())
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status == MembershipStatus::Active
+    }
+
+    /// Whether this member may edit, delete and invite others to the project.
+    pub fn can_manage(&self) -> bool {
+        self.is_active() && self.role == ProjectRole::Maintainer
+    }
+
+    /// Whether this member's approval counts towards the review threshold.
+    pub fn can_review(&self) -> bool {
+        self.is_active() && self.role == ProjectRole::Reviewer
+    }
+}
@@ -1,8 +1,12 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
-use crate::domain::value_objects::WalletAddress;
+use crate::domain::{
+    errors::{AppError, FieldError},
+    value_objects::WalletAddress,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ProjectId(pub Uuid);
@@ -33,14 +37,16 @@ impl std::fmt::Display for ProjectId {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
 pub enum ProjectStatus {
-    #[serde(rename = "proposal")]
     Proposal,
-    #[serde(rename = "ongoing")]
+    /// Collecting reviewer approvals.
+    UnderReview,
+    Approved,
     Ongoing,
-    #[serde(rename = "rejected")]
+    Completed,
+    Archived,
     Rejected,
 }
 
@@ -48,10 +54,39 @@ impl ProjectStatus {
     pub fn as_str(&self) -> &'static str {
         match self {
             ProjectStatus::Proposal => "proposal",
+            ProjectStatus::UnderReview => "under_review",
+            ProjectStatus::Approved => "approved",
             ProjectStatus::Ongoing => "ongoing",
+            ProjectStatus::Completed => "completed",
+            ProjectStatus::Archived => "archived",
             ProjectStatus::Rejected => "rejected",
         }
     }
+
+    /// Allowed moves: proposal → under_review → approved | rejected, then
+    /// approved → ongoing → completed → archived. A review can be withdrawn back
+    /// to proposal, and ongoing or rejected projects can be archived directly.
+    pub fn can_transition_to(&self, next: ProjectStatus) -> bool {
+        use ProjectStatus::*;
+        matches!(
+            (self, next),
+            (Proposal, UnderReview)
+                | (UnderReview, Proposal)
+                | (UnderReview, Approved)
+                | (UnderReview, Rejected)
+                | (Approved, Ongoing)
+                | (Ongoing, Completed)
+                | (Ongoing, Archived)
+                | (Completed, Archived)
+                | (Rejected, Archived)
+        )
+    }
+
+    /// Whether moving to `next` takes the proposal out of review with a
+    /// positive outcome, which needs enough reviewer approvals.
+    pub fn requires_approvals(&self, next: ProjectStatus) -> bool {
+        *self == ProjectStatus::UnderReview && next == ProjectStatus::Approved
+    }
 }
 
 impl std::fmt::Display for ProjectStatus {
@@ -66,7 +101,11 @@ impl std::str::FromStr for ProjectStatus {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
             "proposal" => Ok(ProjectStatus::Proposal),
+            "under_review" => Ok(ProjectStatus::UnderReview),
+            "approved" => Ok(ProjectStatus::Approved),
             "ongoing" => Ok(ProjectStatus::Ongoing),
+            "completed" => Ok(ProjectStatus::Completed),
+            "archived" => Ok(ProjectStatus::Archived),
             "rejected" => Ok(ProjectStatus::Rejected),
             _ => Err(format!("Invalid project status: {}", s)),
         }
@@ -103,42 +142,136 @@ impl Project {
         }
     }
 
-    pub fn validate(&self) -> Result<(), String> {
-        if self.name.trim().is_empty() {
-            return Err("Project name cannot be empty".to_string());
-        }
+    pub fn validate(&self) -> Result<(), AppError> {
+        let mut details = Vec::new();
 
-        if self.name.len() > 255 {
-            return Err("Project name cannot exceed 255 characters".to_string());
+        if self.name.trim().is_empty() {
+            details.push(FieldError {
+                field: "name".to_string(),
+                message: "Project name cannot be empty".to_string(),
+            });
+        } else if self.name.len() > 255 {
+            details.push(FieldError {
+                field: "name".to_string(),
+                message: "Project name cannot exceed 255 characters".to_string(),
+            });
         }
 
         if self.description.trim().is_empty() {
-            return Err("Project description cannot be empty".to_string());
+            details.push(FieldError {
+                field: "description".to_string(),
+                message: "Project description cannot be empty".to_string(),
+            });
         }
 
-        Ok(())
+        match details.first() {
+            None => Ok(()),
+            Some(first) => Err(AppError::Validation {
+                message: first.message.clone(),
+                details,
+            }),
+        }
     }
 
-    pub fn update_info(
-        &mut self,
-        name: Option<String>,
-        description: Option<String>,
-        status: Option<ProjectStatus>,
-    ) {
+    pub fn update_info(&mut self, name: Option<String>, description: Option<String>) {
         if let Some(n) = name {
             self.name = n;
         }
         if let Some(d) = description {
             self.description = d;
         }
-        if let Some(s) = status {
-            self.status = s;
-        }
         self.updated_at = Utc::now();
     }
 
-    pub fn change_status(&mut self, new_status: ProjectStatus) {
-        self.status = new_status;
+    pub fn transition_to(&mut self, next: ProjectStatus) -> Result<(), AppError> {
+        if !self.status.can_transition_to(next) {
+            return Err(AppError::conflict(format!(
+                "Cannot move project from {} to {}",
+                self.status, next
+            )));
+        }
+        self.status = next;
         self.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+/// How many active reviewers must approve a project under review before it
+/// can be approved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReviewPolicy {
+    pub required_approvals: usize,
+}
+
+impl Default for ReviewPolicy {
+    fn default() -> Self {
+        Self {
+            required_approvals: 2,
+        }
     }
 }
+
+impl ReviewPolicy {
+    /// PROJECT_REQUIRED_APPROVALS, falling back to the default when unset or not a positive integer.
+    pub fn from_env() -> Self {
+        std::env::var("PROJECT_REQUIRED_APPROVALS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .filter(|required| *required > 0)
+            .map(|required_approvals| Self { required_approvals })
+            .unwrap_or_default()
+    }
+}
+
+/// One status change of a project. `from` is None for the entry recorded when
+/// the project is created.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectTransition {
+    pub id: Uuid,
+    pub project_id: ProjectId,
+    pub from: Option<ProjectStatus>,
+    pub to: ProjectStatus,
+    pub actor: WalletAddress,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ProjectTransition {
+    pub fn created(project: &Project) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            project_id: project.id,
+            from: None,
+            to: project.status,
+            actor: project.creator.clone(),
+            note: None,
+            created_at: project.created_at,
+        }
+    }
+
+    pub fn new(
+        project_id: ProjectId,
+        from: ProjectStatus,
+        to: ProjectStatus,
+        actor: WalletAddress,
+        note: Option<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            project_id,
+            from: Some(from),
+            to,
+            actor,
+            note,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// A reviewer's approval of a project in its current review round.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectApproval {
+    pub project_id: ProjectId,
+    pub reviewer: WalletAddress,
+    pub approved_at: DateTime<Utc>,
+}
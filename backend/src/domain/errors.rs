@@ -0,0 +1,135 @@
+use serde::Serialize;
+use std::fmt;
+use utoipa::ToSchema;
+
+/// A single invalid field reported alongside a validation error.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+/// Error type shared by repositories, commands and queries.
+///
+/// Each variant maps to one HTTP status and one machine-readable code, so
+/// handlers never have to inspect error messages to pick a response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppError {
+    NotFound(String),
+    Conflict(String),
+    Validation {
+        message: String,
+        details: Vec<FieldError>,
+    },
+    /// Well-formed request whose content breaks a business rule, with one
+    /// entry per offending item.
+    Unprocessable {
+        message: String,
+        details: Vec<FieldError>,
+    },
+    Forbidden(String),
+    Internal(String),
+}
+
+impl AppError {
+    pub fn not_found(message: impl Into<String>) -> Self {
+        AppError::NotFound(message.into())
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        AppError::Conflict(message.into())
+    }
+
+    pub fn validation(message: impl Into<String>) -> Self {
+        AppError::Validation {
+            message: message.into(),
+            details: Vec::new(),
+        }
+    }
+
+    /// Validation error tied to a single request field.
+    pub fn invalid_field(field: impl Into<String>, message: impl Into<String>) -> Self {
+        let message = message.into();
+        AppError::Validation {
+            details: vec![FieldError {
+                field: field.into(),
+                message: message.clone(),
+            }],
+            message,
+        }
+    }
+
+    pub fn unprocessable(message: impl Into<String>, details: Vec<FieldError>) -> Self {
+        AppError::Unprocessable {
+            message: message.into(),
+            details,
+        }
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        AppError::Forbidden(message.into())
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        AppError::Internal(message.into())
+    }
+
+    /// Machine-readable code returned in the JSON error body.
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::NotFound(_) => "not_found",
+            AppError::Conflict(_) => "conflict",
+            AppError::Validation { .. } => "validation_error",
+            AppError::Unprocessable { .. } => "unprocessable",
+            AppError::Forbidden(_) => "forbidden",
+            AppError::Internal(_) => "internal_error",
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            AppError::NotFound(m)
+            | AppError::Conflict(m)
+            | AppError::Forbidden(m)
+            | AppError::Internal(m) => m,
+            AppError::Validation { message, .. } | AppError::Unprocessable { message, .. } => {
+                message
+            }
+        }
+    }
+
+    pub fn details(&self) -> &[FieldError] {
+        match self {
+            AppError::Validation { details, .. } | AppError::Unprocessable { details, .. } => {
+                details
+            }
+            _ => &[],
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<sqlx::Error> for AppError {
+    fn from(err: sqlx::Error) -> Self {
+        match &err {
+            sqlx::Error::RowNotFound => AppError::not_found("Record not found"),
+            // The constraint name and values stay in the logs, not the response.
+            sqlx::Error::Database(db) if db.is_unique_violation() => {
+                tracing::warn!(
+                    constraint = db.constraint().unwrap_or_default(),
+                    error = %db.message(),
+                    "Unique constraint violated"
+                );
+                AppError::conflict("Resource already exists")
+            }
+            _ => AppError::internal(format!("Database error: {err}")),
+        }
+    }
+}
@@ -1,21 +1,49 @@
 use async_trait::async_trait;
 
-#[derive(Clone, Debug)]
-pub struct DistributionRecord {
-    pub address: String,
-    pub badge_name: String,
-    pub distribution_id: String,
-}
+use crate::domain::{
+    entities::distribution::{Distribution, DistributionLine, DistributionStatus},
+    errors::AppError,
+};
 
 #[async_trait]
 pub trait DistributionRepository: Send + Sync {
-    async fn create_many(
+    /// Insert the header if it does not exist yet, plus every line not already
+    /// stored for it. Registering the same lines twice is a no-op; adding lines
+    /// to a distribution that is no longer a draft is a Conflict.
+    async fn register(&self, distribution: &Distribution) -> Result<(), AppError> {
+        self.register_all(std::slice::from_ref(distribution)).await
+    }
+
+    /// [`DistributionRepository::register`] for several distributions at once:
+    /// either all of them are stored or, on any error, none is.
+    async fn register_all(&self, distributions: &[Distribution]) -> Result<(), AppError>;
+
+    /// Find a distribution with its lines
+    async fn find_by_id(&self, id: &str) -> Result<Option<Distribution>, AppError>;
+
+    /// Persist the status, tx hash and chain id of an existing distribution,
+    /// provided it is still in status `from`; Conflict otherwise.
+    async fn update_status(
+        &self,
+        distribution: &Distribution,
+        from: DistributionStatus,
+    ) -> Result<(), AppError>;
+
+    /// Ids of distributions in this status, oldest first
+    async fn list_ids_by_status(&self, status: DistributionStatus)
+        -> Result<Vec<String>, AppError>;
+
+    /// Ids of distributions that are not failed and already contain this
+    /// recipient/badge pair (address compared case-insensitively)
+    async fn find_distribution_ids_with_line(
         &self,
-        records: &[DistributionRecord],
-    ) -> Result<(), Box<dyn std::error::Error>>;
+        address: &str,
+        badge_name: &str,
+    ) -> Result<Vec<String>, AppError>;
 
-    async fn list(
+    /// List lines, optionally restricted to one distribution
+    async fn list_lines(
         &self,
         distribution_id: Option<&str>,
-    ) -> Result<Vec<DistributionRecord>, Box<dyn std::error::Error>>;
+    ) -> Result<Vec<DistributionLine>, AppError>;
 }
@@ -1,23 +1,23 @@
 use async_trait::async_trait;
 
-use crate::domain::entities::github_issue::GithubIssue;
+use crate::domain::{entities::github_issue::GithubIssue, errors::AppError};
 
 #[async_trait]
 pub trait GithubIssueRepository: Send + Sync {
     /// Upsert a GitHub issue (insert or update based on composite key repo_id + github_issue_id)
-    async fn upsert(&self, issue: &GithubIssue) -> Result<(), Box<dyn std::error::Error>>;
+    async fn upsert(&self, issue: &GithubIssue) -> Result<(), AppError>;
 
     /// Find an issue by its composite key
     async fn find_by_key(
         &self,
         repo_id: i64,
         github_issue_id: i64,
-    ) -> Result<Option<GithubIssue>, Box<dyn std::error::Error>>;
+    ) -> Result<Option<GithubIssue>, AppError>;
 
     /// List issues filtered by repo name and optional state
     async fn list_by_repo(
         &self,
         repo: &str,
         state: Option<&str>,
-    ) -> Result<Vec<GithubIssue>, Box<dyn std::error::Error>>;
+    ) -> Result<Vec<GithubIssue>, AppError>;
 }
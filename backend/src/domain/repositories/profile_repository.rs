@@ -1,35 +1,100 @@
 use async_trait::async_trait;
+use chrono::SecondsFormat;
 
-use crate::domain::{entities::profile::Profile, value_objects::WalletAddress};
+use crate::domain::{
+    entities::profile::Profile,
+    errors::AppError,
+    value_objects::{PageCursor, SearchTerms, WalletAddress},
+};
+
+/// Orderings for profile listings. Ties are broken by address so pages are stable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProfileSort {
+    #[default]
+    Newest,
+    Oldest,
+    /// Case-insensitive name, unnamed profiles first
+    Name,
+}
+
+impl ProfileSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProfileSort::Newest => "newest",
+            ProfileSort::Oldest => "oldest",
+            ProfileSort::Name => "name",
+        }
+    }
+
+    /// Cursor pointing just after this profile.
+    pub fn cursor_for(&self, profile: &Profile) -> PageCursor {
+        let key = match self {
+            ProfileSort::Newest | ProfileSort::Oldest => profile
+                .created_at
+                .to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            ProfileSort::Name => profile.name.clone().unwrap_or_default().to_lowercase(),
+        };
+        PageCursor::new(self.as_str(), key, profile.address.as_str())
+    }
+}
+
+impl std::str::FromStr for ProfileSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "newest" => Ok(ProfileSort::Newest),
+            "oldest" => Ok(ProfileSort::Oldest),
+            "name" => Ok(ProfileSort::Name),
+            _ => Err(format!(
+                "Invalid sort: {s}; expected newest, oldest or name"
+            )),
+        }
+    }
+}
+
+/// Filters, ordering and keyset position for [`ProfileRepository::search`].
+#[derive(Debug, Clone, Default)]
+pub struct ProfileSearch {
+    /// Matched against name, description and GitHub login
+    pub terms: SearchTerms,
+    pub has_github: Option<bool>,
+    /// Whether the address received a line of a confirmed distribution
+    pub has_badges: Option<bool>,
+    pub sort: ProfileSort,
+    pub limit: i64,
+    /// Only profiles after this cursor; must have been issued for the same sort
+    pub after: Option<PageCursor>,
+}
+
+/// One page of profiles plus the number of profiles matching the filters.
+#[derive(Debug, Clone)]
+pub struct ProfilePage {
+    pub profiles: Vec<Profile>,
+    pub total: i64,
+    pub next_cursor: Option<PageCursor>,
+}
 
 #[async_trait]
 pub trait ProfileRepository: Send + Sync {
-    async fn find_by_address(
-        &self,
-        address: &WalletAddress,
-    ) -> Result<Option<Profile>, Box<dyn std::error::Error>>;
-    async fn find_all(&self) -> Result<Vec<Profile>, Box<dyn std::error::Error>>;
-    async fn create(&self, profile: &Profile) -> Result<(), Box<dyn std::error::Error>>;
-    async fn update(&self, profile: &Profile) -> Result<(), Box<dyn std::error::Error>>;
-    async fn delete(&self, address: &WalletAddress) -> Result<(), Box<dyn std::error::Error>>;
-    async fn find_by_github_login(
-        &self,
-        github_login: &str,
-    ) -> Result<Option<Profile>, Box<dyn std::error::Error + Send + Sync>>;
+    async fn find_by_address(&self, address: &WalletAddress) -> Result<Option<Profile>, AppError>;
+    async fn find_all(&self) -> Result<Vec<Profile>, AppError>;
+    async fn search(&self, search: &ProfileSearch) -> Result<ProfilePage, AppError>;
+    async fn create(&self, profile: &Profile) -> Result<(), AppError>;
+    async fn update(&self, profile: &Profile) -> Result<(), AppError>;
+    async fn delete(&self, address: &WalletAddress) -> Result<(), AppError>;
+    async fn find_by_github_login(&self, github_login: &str) -> Result<Option<Profile>, AppError>;
     async fn find_by_twitter_handle(
         &self,
         twitter_handle: &str,
-    ) -> Result<Option<Profile>, Box<dyn std::error::Error + Send + Sync>>;
+    ) -> Result<Option<Profile>, AppError>;
     async fn find_by_linkedin_account(
         &self,
         linkedin_account: &str,
-    ) -> Result<Option<Profile>, Box<dyn std::error::Error + Send + Sync>>;
+    ) -> Result<Option<Profile>, AppError>;
     async fn get_login_nonce_by_wallet_address(
         &self,
         address: &WalletAddress,
-    ) -> Result<Option<i64>, Box<dyn std::error::Error>>;
-    async fn increment_login_nonce(
-        &self,
-        address: &WalletAddress,
-    ) -> Result<(), Box<dyn std::error::Error>>;
+    ) -> Result<Option<i64>, AppError>;
+    async fn increment_login_nonce(&self, address: &WalletAddress) -> Result<(), AppError>;
 }
@@ -1,48 +1,158 @@
 use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
 
 use crate::domain::{
-    entities::projects::{Project, ProjectId, ProjectStatus},
-    value_objects::WalletAddress,
+    entities::{
+        project_member::ProjectMember,
+        projects::{Project, ProjectApproval, ProjectId, ProjectStatus, ProjectTransition},
+    },
+    errors::AppError,
+    value_objects::{PageCursor, SearchTerms, WalletAddress},
 };
 
+/// Orderings for project listings. Ties are broken by id so pages are stable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProjectSort {
+    #[default]
+    Newest,
+    Oldest,
+    /// Case-insensitive name
+    Name,
+    /// Most recently updated first
+    Updated,
+}
+
+impl ProjectSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProjectSort::Newest => "newest",
+            ProjectSort::Oldest => "oldest",
+            ProjectSort::Name => "name",
+            ProjectSort::Updated => "updated",
+        }
+    }
+
+    /// Cursor pointing just after this project.
+    pub fn cursor_for(&self, project: &Project) -> PageCursor {
+        let timestamp = |at: DateTime<Utc>| at.to_rfc3339_opts(SecondsFormat::AutoSi, true);
+        let key = match self {
+            ProjectSort::Newest | ProjectSort::Oldest => timestamp(project.created_at),
+            ProjectSort::Updated => timestamp(project.updated_at),
+            ProjectSort::Name => project.name.to_lowercase(),
+        };
+        PageCursor::new(self.as_str(), key, project.id.value().to_string())
+    }
+}
+
+impl std::str::FromStr for ProjectSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "newest" => Ok(ProjectSort::Newest),
+            "oldest" => Ok(ProjectSort::Oldest),
+            "name" => Ok(ProjectSort::Name),
+            "updated" => Ok(ProjectSort::Updated),
+            _ => Err(format!(
+                "Invalid sort: {s}; expected newest, oldest, name or updated"
+            )),
+        }
+    }
+}
+
+/// Filters, ordering and keyset position for [`ProjectRepository::search`].
+#[derive(Debug, Clone, Default)]
+pub struct ProjectSearch {
+    /// Matched against name and description
+    pub terms: SearchTerms,
+    pub status: Option<ProjectStatus>,
+    pub creator: Option<WalletAddress>,
+    /// Inclusive lower bound on created_at
+    pub created_after: Option<DateTime<Utc>>,
+    /// Exclusive upper bound on created_at
+    pub created_before: Option<DateTime<Utc>>,
+    pub sort: ProjectSort,
+    pub limit: i64,
+    /// Only projects after this cursor; must have been issued for the same sort
+    pub after: Option<PageCursor>,
+}
+
+/// One page of projects plus the number of projects matching the filters.
+#[derive(Debug, Clone)]
+pub struct ProjectPage {
+    pub projects: Vec<Project>,
+    pub total: i64,
+    pub next_cursor: Option<PageCursor>,
+}
+
 #[async_trait]
 pub trait ProjectRepository: Send + Sync {
-    /// Create a new project
-    async fn create(&self, project: &Project) -> Result<(), Box<dyn std::error::Error>>;
+    /// Create a new project, with its creator as an active maintainer and the
+    /// creation recorded in its history
+    async fn create(&self, project: &Project) -> Result<(), AppError>;
 
     /// Find a project by ID
-    async fn find_by_id(
-        &self,
-        id: &ProjectId,
-    ) -> Result<Option<Project>, Box<dyn std::error::Error>>;
+    async fn find_by_id(&self, id: &ProjectId) -> Result<Option<Project>, AppError>;
 
-    /// Find all projects with optional filters
-    async fn find_all(
-        &self,
-        status: Option<ProjectStatus>,
-        creator: Option<&WalletAddress>,
-        limit: Option<i64>,
-        offset: Option<i64>,
-    ) -> Result<Vec<Project>, Box<dyn std::error::Error>>;
+    /// One page of projects matching the search, in keyset order
+    async fn search(&self, search: &ProjectSearch) -> Result<ProjectPage, AppError>;
 
     /// Find projects by creator
-    async fn find_by_creator(
-        &self,
-        creator: &WalletAddress,
-    ) -> Result<Vec<Project>, Box<dyn std::error::Error>>;
+    async fn find_by_creator(&self, creator: &WalletAddress) -> Result<Vec<Project>, AppError>;
 
-    /// Update a project
-    async fn update(&self, project: &Project) -> Result<(), Box<dyn std::error::Error>>;
+    /// Update a project's name and description. The status is left alone:
+    /// it only changes through `record_transition`.
+    async fn update(&self, project: &Project) -> Result<(), AppError>;
 
     /// Delete a project
-    async fn delete(&self, id: &ProjectId) -> Result<(), Box<dyn std::error::Error>>;
+    async fn delete(&self, id: &ProjectId) -> Result<(), AppError>;
 
     /// Check if a project exists
-    async fn exists(&self, id: &ProjectId) -> Result<bool, Box<dyn std::error::Error>>;
+    async fn exists(&self, id: &ProjectId) -> Result<bool, AppError>;
 
     /// Check if a profile exists (for creator validation)
-    async fn profile_exists(
+    async fn profile_exists(&self, address: &WalletAddress) -> Result<bool, AppError>;
+
+    /// Members and pending invitations of a project, oldest invitation first
+    async fn list_members(&self, id: &ProjectId) -> Result<Vec<ProjectMember>, AppError>;
+
+    /// Find the membership or invitation of an address
+    async fn find_member(
         &self,
+        id: &ProjectId,
         address: &WalletAddress,
-    ) -> Result<bool, Box<dyn std::error::Error>>;
+    ) -> Result<Option<ProjectMember>, AppError>;
+
+    /// Insert a membership; Conflict if the address is already attached to the project
+    async fn add_member(&self, member: &ProjectMember) -> Result<(), AppError>;
+
+    /// Persist the status, role and joined_at of an existing membership
+    async fn update_member(&self, member: &ProjectMember) -> Result<(), AppError>;
+
+    /// Remove a membership or invitation
+    async fn remove_member(&self, id: &ProjectId, address: &WalletAddress) -> Result<(), AppError>;
+
+    /// Persist a status change together with its history entry. Conflicts when
+    /// the project is no longer in the transition's `from` status; moving back
+    /// to proposal also discards the review round's approvals, atomically.
+    async fn record_transition(
+        &self,
+        project: &Project,
+        transition: &ProjectTransition,
+    ) -> Result<(), AppError>;
+
+    /// Status changes of a project, oldest first
+    async fn history(&self, id: &ProjectId) -> Result<Vec<ProjectTransition>, AppError>;
+
+    /// Record an approval; Conflict if the reviewer already approved
+    async fn add_approval(&self, approval: &ProjectApproval) -> Result<(), AppError>;
+
+    /// Approvals of the current review round, oldest first
+    async fn list_approvals(&self, id: &ProjectId) -> Result<Vec<ProjectApproval>, AppError>;
+
+    /// GitHub repositories linked to a project, by name
+    async fn list_repos(&self, id: &ProjectId) -> Result<Vec<String>, AppError>;
+
+    /// Replace the GitHub repositories linked to a project
+    async fn set_repos(&self, id: &ProjectId, repos: &[String]) -> Result<(), AppError>;
 }
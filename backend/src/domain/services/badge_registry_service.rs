@@ -0,0 +1,8 @@
+use async_trait::async_trait;
+
+/// Read access to the on-chain badge registry, as indexed by the indexer.
+#[async_trait]
+pub trait BadgeRegistryService: Send + Sync {
+    /// Whether a badge with this name has been created in TheGuildBadgeRegistry.
+    async fn badge_exists(&self, name: &str) -> Result<bool, Box<dyn std::error::Error>>;
+}
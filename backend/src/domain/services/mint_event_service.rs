@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+
+use crate::domain::value_objects::TokenAmount;
+
+/// A contribution token mint observed on chain. `reason_hash` is the indexed
+/// keccak256 of the reason bytes, present when the mint emitted `MintedWithReason`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MintEvent {
+    pub recipient: String,
+    pub amount: TokenAmount,
+    pub reason_hash: Option<String>,
+}
+
+/// Outcome of a mined distribution transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionMints {
+    pub reverted: bool,
+    pub mints: Vec<MintEvent>,
+}
+
+/// Read access to the mints emitted by TheGuildContributionToken.
+#[async_trait]
+pub trait MintEventService: Send + Sync {
+    /// Mints (`Transfer` from the zero address, paired with `MintedWithReason`)
+    /// emitted in a transaction, or `None` while it is not mined yet.
+    async fn mints_in_transaction(
+        &self,
+        chain_id: i64,
+        tx_hash: &str,
+    ) -> Result<Option<TransactionMints>, Box<dyn std::error::Error>>;
+}
@@ -1,2 +1,4 @@
 pub mod auth_service;
+pub mod badge_registry_service;
 pub mod github_service;
+pub mod mint_event_service;
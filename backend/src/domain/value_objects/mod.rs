@@ -1,5 +1,11 @@
 pub mod nonce;
+pub mod page_cursor;
+pub mod search_terms;
+pub mod token_amount;
 pub mod wallet_address;
 
 pub use nonce::Nonce;
+pub use page_cursor::PageCursor;
+pub use search_terms::SearchTerms;
+pub use token_amount::TokenAmount;
 pub use wallet_address::WalletAddress;
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// Keyset position after the last row of a page: the sort it was produced
+/// with, that row's sort key and its unique id as a tie-breaker.
+/// Clients only see it as an opaque hex string.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageCursor {
+    pub sort: String,
+    pub key: String,
+    pub id: String,
+}
+
+impl PageCursor {
+    pub fn new(sort: &str, key: impl Into<String>, id: impl Into<String>) -> Self {
+        Self {
+            sort: sort.to_string(),
+            key: key.into(),
+            id: id.into(),
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        json.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    pub fn decode(value: &str) -> Result<Self, String> {
+        if !value.len().is_multiple_of(2) || !value.is_ascii() {
+            return Err("Malformed cursor".to_string());
+        }
+        let bytes = (0..value.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&value[i..i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| "Malformed cursor".to_string())?;
+        serde_json::from_slice(&bytes).map_err(|_| "Malformed cursor".to_string())
+    }
+}
@@ -0,0 +1,36 @@
+/// Words of a free-text search, lowercased and stripped of punctuation.
+/// Every term must match the start of a word in the searched text.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SearchTerms(Vec<String>);
+
+impl SearchTerms {
+    pub fn parse(query: &str) -> Self {
+        Self(split_words(query))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The terms joined by spaces, to be parsed again by the store's own
+    /// full-text parser.
+    pub fn as_text(&self) -> String {
+        self.0.join(" ")
+    }
+
+    /// Prefix match of every term, for stores without full-text search.
+    pub fn matches(&self, text: &str) -> bool {
+        let words = split_words(text);
+        self.0
+            .iter()
+            .all(|term| words.iter().any(|word| word.starts_with(term.as_str())))
+    }
+}
+
+fn split_words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
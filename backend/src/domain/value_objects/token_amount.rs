@@ -0,0 +1,82 @@
+use ethers::types::U256;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use utoipa::ToSchema;
+
+/// A uint256 token amount in base units; TGC has 18 decimals, so most amounts
+/// overflow an i64. Serialized as a decimal string, and accepted as a decimal
+/// string or a non-negative JSON integer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, ToSchema)]
+#[schema(value_type = String, example = "1000000000000000000")]
+pub struct TokenAmount(pub U256);
+
+impl TokenAmount {
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn checked_add(self, other: TokenAmount) -> Option<TokenAmount> {
+        self.0.checked_add(other.0).map(TokenAmount)
+    }
+}
+
+impl From<u64> for TokenAmount {
+    fn from(amount: u64) -> Self {
+        Self(U256::from(amount))
+    }
+}
+
+impl fmt::Display for TokenAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for TokenAmount {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("Invalid token amount: {}", s));
+        }
+        U256::from_dec_str(s)
+            .map(Self)
+            .map_err(|_| format!("Token amount does not fit in a uint256: {}", s))
+    }
+}
+
+impl Serialize for TokenAmount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TokenAmount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AmountVisitor;
+
+        impl de::Visitor<'_> for AmountVisitor {
+            type Value = TokenAmount;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a non-negative integer or a decimal string")
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<TokenAmount, E> {
+                Ok(TokenAmount::from(v))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<TokenAmount, E> {
+                u64::try_from(v)
+                    .map(TokenAmount::from)
+                    .map_err(|_| E::custom("token amount must not be negative"))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<TokenAmount, E> {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(AmountVisitor)
+    }
+}
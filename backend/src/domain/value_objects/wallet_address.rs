@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
 use std::fmt;
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[schema(value_type = String, example = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e")]
 pub struct WalletAddress(pub String);
 
 impl WalletAddress {
@@ -21,6 +24,40 @@ impl WalletAddress {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// EIP-55 mixed-case form of this address, or None if it is not 20 hex bytes.
+    pub fn to_checksum(&self) -> Option<String> {
+        let hex = self.0.strip_prefix("0x")?;
+        if hex.len() != 40 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let lower = hex.to_ascii_lowercase();
+        let hash = Keccak256::digest(lower.as_bytes());
+        let checksummed: String = lower
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+                if c.is_ascii_alphabetic() && nibble >= 8 {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }
+            })
+            .collect();
+        Some(format!("0x{checksummed}"))
+    }
+
+    /// This address in checksum form, or unchanged if it is not 20 hex bytes.
+    pub fn checksummed(&self) -> Self {
+        self.to_checksum().map(Self).unwrap_or_else(|| self.clone())
+    }
+
+    /// True when the address is written exactly in its EIP-55 checksum form.
+    pub fn is_checksummed(&self) -> bool {
+        self.to_checksum().as_deref() == Some(self.0.as_str())
+    }
 }
 
 impl fmt::Display for WalletAddress {
@@ -0,0 +1,167 @@
+use async_trait::async_trait;
+
+use crate::domain::entities::distribution::{Distribution, DistributionLine, DistributionStatus};
+use crate::domain::errors::AppError;
+use crate::domain::repositories::distribution_repository::DistributionRepository;
+
+use super::in_memory_store::InMemoryStore;
+
+#[derive(Clone, Default)]
+pub struct InMemoryDistributionRepository {
+    store: InMemoryStore,
+}
+
+impl InMemoryDistributionRepository {
+    pub fn new(store: InMemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+fn same_line(a: &DistributionLine, b: &DistributionLine) -> bool {
+    a.address == b.address && a.badge_name == b.badge_name
+}
+
+fn sort_lines(lines: &mut [DistributionLine]) {
+    lines.sort_by(|a, b| (&a.address, &a.badge_name).cmp(&(&b.address, &b.badge_name)));
+}
+
+#[async_trait]
+impl DistributionRepository for InMemoryDistributionRepository {
+    async fn register_all(&self, distributions: &[Distribution]) -> Result<(), AppError> {
+        let mut tables = self.store.write();
+
+        // Check every distribution before touching the store, so a conflict
+        // leaves nothing half-registered.
+        let mut additions = Vec::with_capacity(distributions.len());
+        for distribution in distributions {
+            let stored = tables.distributions.get(&distribution.id);
+            let new_lines: Vec<DistributionLine> = distribution
+                .lines
+                .iter()
+                .filter(|line| {
+                    !stored.is_some_and(|stored| {
+                        stored
+                            .lines
+                            .iter()
+                            .any(|existing| same_line(existing, line))
+                    })
+                })
+                .map(|line| DistributionLine {
+                    distribution_id: distribution.id.clone(),
+                    ..line.clone()
+                })
+                .collect();
+
+            if let Some(stored) = stored {
+                if !new_lines.is_empty() && stored.status != DistributionStatus::Draft {
+                    return Err(AppError::conflict(format!(
+                        "Distribution {} is {}; lines can only be added to drafts",
+                        distribution.id, stored.status
+                    )));
+                }
+            }
+            additions.push((distribution, new_lines));
+        }
+
+        for (distribution, new_lines) in additions {
+            tables
+                .distributions
+                .entry(distribution.id.clone())
+                .or_insert_with(|| Distribution {
+                    lines: Vec::new(),
+                    ..distribution.clone()
+                })
+                .lines
+                .extend(new_lines);
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &str) -> Result<Option<Distribution>, AppError> {
+        Ok(self
+            .store
+            .read()
+            .distributions
+            .get(id)
+            .cloned()
+            .map(|mut distribution| {
+                sort_lines(&mut distribution.lines);
+                distribution
+            }))
+    }
+
+    async fn update_status(
+        &self,
+        distribution: &Distribution,
+        from: DistributionStatus,
+    ) -> Result<(), AppError> {
+        let mut tables = self.store.write();
+        let stored = tables
+            .distributions
+            .get_mut(&distribution.id)
+            .filter(|stored| stored.status == from)
+            .ok_or_else(|| {
+                AppError::conflict(format!(
+                    "Distribution {} is no longer {}",
+                    distribution.id, from
+                ))
+            })?;
+        stored.status = distribution.status;
+        stored.tx_hash = distribution.tx_hash.clone();
+        stored.chain_id = distribution.chain_id;
+        stored.updated_at = distribution.updated_at;
+        Ok(())
+    }
+
+    async fn list_ids_by_status(
+        &self,
+        status: DistributionStatus,
+    ) -> Result<Vec<String>, AppError> {
+        let tables = self.store.read();
+        let mut matching: Vec<&Distribution> = tables
+            .distributions
+            .values()
+            .filter(|d| d.status == status)
+            .collect();
+        matching.sort_by_key(|d| (d.created_at, d.id.clone()));
+        Ok(matching.into_iter().map(|d| d.id.clone()).collect())
+    }
+
+    async fn find_distribution_ids_with_line(
+        &self,
+        address: &str,
+        badge_name: &str,
+    ) -> Result<Vec<String>, AppError> {
+        Ok(self
+            .store
+            .read()
+            .distributions
+            .values()
+            .filter(|d| d.status != DistributionStatus::Failed)
+            .filter(|d| {
+                d.lines.iter().any(|line| {
+                    line.address.eq_ignore_ascii_case(address) && line.badge_name == badge_name
+                })
+            })
+            .map(|d| d.id.clone())
+            .collect())
+    }
+
+    async fn list_lines(
+        &self,
+        distribution_id: Option<&str>,
+    ) -> Result<Vec<DistributionLine>, AppError> {
+        let tables = self.store.read();
+        // Newest ids first, like the Postgres ordering
+        let mut lines: Vec<DistributionLine> = Vec::new();
+        for distribution in tables.distributions.values().rev() {
+            if distribution_id.is_some_and(|id| id != distribution.id) {
+                continue;
+            }
+            let mut own = distribution.lines.clone();
+            sort_lines(&mut own);
+            lines.extend(own);
+        }
+        Ok(lines)
+    }
+}
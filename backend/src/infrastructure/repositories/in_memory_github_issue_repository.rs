@@ -0,0 +1,75 @@
+use std::cmp::Reverse;
+
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::github_issue::GithubIssue, errors::AppError,
+    repositories::github_issue_repository::GithubIssueRepository,
+};
+
+use super::in_memory_store::InMemoryStore;
+
+#[derive(Clone, Default)]
+pub struct InMemoryGithubIssueRepository {
+    store: InMemoryStore,
+}
+
+impl InMemoryGithubIssueRepository {
+    pub fn new(store: InMemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl GithubIssueRepository for InMemoryGithubIssueRepository {
+    async fn upsert(&self, issue: &GithubIssue) -> Result<(), AppError> {
+        let mut tables = self.store.write();
+        let key = (issue.repo_id, issue.github_issue_id);
+
+        match tables.github_issues.get_mut(&key) {
+            // Reward bookkeeping is owned by the distribution flow, not by syncs
+            Some(existing) => {
+                *existing = GithubIssue {
+                    rewarded_sepolia: existing.rewarded_sepolia,
+                    distribution_id: existing.distribution_id.take(),
+                    ..issue.clone()
+                };
+            }
+            None => {
+                tables.github_issues.insert(key, issue.clone());
+            }
+        }
+        Ok(())
+    }
+
+    async fn find_by_key(
+        &self,
+        repo_id: i64,
+        github_issue_id: i64,
+    ) -> Result<Option<GithubIssue>, AppError> {
+        Ok(self
+            .store
+            .read()
+            .github_issues
+            .get(&(repo_id, github_issue_id))
+            .cloned())
+    }
+
+    async fn list_by_repo(
+        &self,
+        repo: &str,
+        state: Option<&str>,
+    ) -> Result<Vec<GithubIssue>, AppError> {
+        let mut issues: Vec<GithubIssue> = self
+            .store
+            .read()
+            .github_issues
+            .values()
+            .filter(|i| i.repo == repo)
+            .filter(|i| state.is_none_or(|s| i.state == s))
+            .cloned()
+            .collect();
+        issues.sort_by_key(|i| Reverse(i.created_at));
+        Ok(issues)
+    }
+}
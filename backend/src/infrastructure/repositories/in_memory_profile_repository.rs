@@ -0,0 +1,298 @@
+use std::cmp::Ordering;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entities::distribution::DistributionStatus;
+use crate::domain::entities::profile::Profile;
+use crate::domain::errors::AppError;
+use crate::domain::repositories::profile_repository::{
+    ProfilePage, ProfileRepository, ProfileSearch, ProfileSort,
+};
+use crate::domain::value_objects::{PageCursor, WalletAddress};
+
+use super::in_memory_store::{unique_violation, InMemoryStore, Tables};
+
+#[derive(Clone, Default)]
+pub struct InMemoryProfileRepository {
+    store: InMemoryStore,
+}
+
+impl InMemoryProfileRepository {
+    pub fn new(store: InMemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+fn eq_ignore_case(a: &Option<String>, b: &str) -> bool {
+    a.as_deref()
+        .is_some_and(|a| a.to_lowercase() == b.to_lowercase())
+}
+
+/// Mirrors the `LOWER(...)` unique indexes on the profiles table.
+fn check_unique_handles(tables: &Tables, profile: &Profile) -> Result<(), AppError> {
+    let others = tables
+        .profiles
+        .values()
+        .filter(|p| p.address != profile.address);
+
+    for other in others {
+        let handles = [
+            (&profile.github_login, &other.github_login),
+            (&profile.twitter_handle, &other.twitter_handle),
+            (&profile.linkedin_account, &other.linkedin_account),
+        ];
+        for (mine, theirs) in handles {
+            if let Some(mine) = mine {
+                if eq_ignore_case(theirs, mine) {
+                    return Err(unique_violation());
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Regular profile queries do not select the nonce.
+fn without_nonce(profile: &Profile) -> Profile {
+    Profile {
+        login_nonce: 0,
+        ..profile.clone()
+    }
+}
+
+/// Mirrors the badge subquery: a line of a confirmed distribution.
+fn has_badges(tables: &Tables, address: &str) -> bool {
+    tables
+        .distributions
+        .values()
+        .filter(|d| d.status == DistributionStatus::Confirmed)
+        .flat_map(|d| d.lines.iter())
+        .any(|line| line.address.eq_ignore_ascii_case(address))
+}
+
+fn matches_filters(tables: &Tables, profile: &Profile, search: &ProfileSearch) -> bool {
+    let text = [&profile.name, &profile.description, &profile.github_login]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let has_github = profile
+        .github_login
+        .as_deref()
+        .is_some_and(|g| !g.is_empty());
+
+    search.terms.matches(&text)
+        && search.has_github.is_none_or(|wanted| wanted == has_github)
+        && search
+            .has_badges
+            .is_none_or(|wanted| wanted == has_badges(tables, profile.address.as_str()))
+}
+
+/// Same ordering as the Postgres query (byte-wise, like `COLLATE "C"`).
+fn compare(sort: ProfileSort, a: &Profile, b: &Profile) -> Ordering {
+    match sort {
+        ProfileSort::Newest => {
+            (b.created_at, b.address.as_str()).cmp(&(a.created_at, a.address.as_str()))
+        }
+        ProfileSort::Oldest => {
+            (a.created_at, a.address.as_str()).cmp(&(b.created_at, b.address.as_str()))
+        }
+        ProfileSort::Name => {
+            let name = |p: &Profile| p.name.clone().unwrap_or_default().to_lowercase();
+            (name(a), a.address.as_str()).cmp(&(name(b), b.address.as_str()))
+        }
+    }
+}
+
+fn is_after(sort: ProfileSort, profile: &Profile, cursor: &PageCursor) -> Result<bool, AppError> {
+    let id = cursor.id.as_str();
+    Ok(match sort {
+        ProfileSort::Newest | ProfileSort::Oldest => {
+            let at = DateTime::parse_from_rfc3339(&cursor.key)
+                .map_err(|_| AppError::invalid_field("cursor", "Malformed cursor"))?
+                .with_timezone(&Utc);
+            let key = (profile.created_at, profile.address.as_str());
+            if sort == ProfileSort::Newest {
+                key < (at, id)
+            } else {
+                key > (at, id)
+            }
+        }
+        ProfileSort::Name => {
+            let name = profile.name.clone().unwrap_or_default().to_lowercase();
+            (name.as_str(), profile.address.as_str()) > (cursor.key.as_str(), id)
+        }
+    })
+}
+
+#[async_trait]
+impl ProfileRepository for InMemoryProfileRepository {
+    async fn find_by_address(&self, address: &WalletAddress) -> Result<Option<Profile>, AppError> {
+        Ok(self
+            .store
+            .read()
+            .profiles
+            .get(address.as_str())
+            .map(without_nonce))
+    }
+
+    async fn find_all(&self) -> Result<Vec<Profile>, AppError> {
+        Ok(self
+            .store
+            .read()
+            .profiles
+            .values()
+            .map(without_nonce)
+            .collect())
+    }
+
+    async fn search(&self, search: &ProfileSearch) -> Result<ProfilePage, AppError> {
+        let tables = self.store.read();
+        let mut matching: Vec<Profile> = tables
+            .profiles
+            .values()
+            .filter(|p| matches_filters(&tables, p, search))
+            .map(without_nonce)
+            .collect();
+        matching.sort_by(|a, b| compare(search.sort, a, b));
+        let total = matching.len() as i64;
+
+        let mut profiles = Vec::new();
+        for profile in matching {
+            if let Some(cursor) = &search.after {
+                if !is_after(search.sort, &profile, cursor)? {
+                    continue;
+                }
+            }
+            profiles.push(profile);
+        }
+
+        let limit = search.limit.max(0) as usize;
+        let next_cursor = if limit > 0 && profiles.len() > limit {
+            Some(search.sort.cursor_for(&profiles[limit - 1]))
+        } else {
+            None
+        };
+        profiles.truncate(limit);
+
+        Ok(ProfilePage {
+            profiles,
+            total,
+            next_cursor,
+        })
+    }
+
+    async fn create(&self, profile: &Profile) -> Result<(), AppError> {
+        let mut tables = self.store.write();
+        if tables.profiles.contains_key(profile.address.as_str()) {
+            return Err(unique_violation());
+        }
+        check_unique_handles(&tables, profile)?;
+
+        tables
+            .profiles
+            .insert(profile.address.0.clone(), profile.clone());
+        Ok(())
+    }
+
+    async fn update(&self, profile: &Profile) -> Result<(), AppError> {
+        let mut tables = self.store.write();
+        check_unique_handles(&tables, profile)?;
+
+        if let Some(existing) = tables.profiles.get_mut(profile.address.as_str()) {
+            existing.name = profile.name.clone();
+            existing.description = profile.description.clone();
+            existing.avatar_url = profile.avatar_url.clone();
+            existing.github_login = profile.github_login.clone();
+            existing.twitter_handle = profile.twitter_handle.clone();
+            existing.linkedin_account = profile.linkedin_account.clone();
+            existing.updated_at = profile.updated_at;
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, address: &WalletAddress) -> Result<(), AppError> {
+        let mut tables = self.store.write();
+        if tables.profiles.remove(address.as_str()).is_some() {
+            // ON DELETE CASCADE from projects.creator, plus the trigger that
+            // drops memberships matching the address in any case
+            tables
+                .projects
+                .retain(|_, project| project.creator != *address);
+            let Tables {
+                projects,
+                project_members,
+                project_history,
+                project_approvals,
+                project_repos,
+                ..
+            } = &mut *tables;
+            project_members.retain(|(project_id, member), _| {
+                !member.eq_ignore_ascii_case(address.as_str()) && projects.contains_key(project_id)
+            });
+            project_history.retain(|entry| projects.contains_key(&entry.project_id.value()));
+            project_approvals.retain(|(project_id, _), _| projects.contains_key(project_id));
+            project_repos.retain(|(project_id, _)| projects.contains_key(project_id));
+        }
+        Ok(())
+    }
+
+    async fn find_by_github_login(&self, github_login: &str) -> Result<Option<Profile>, AppError> {
+        Ok(self
+            .store
+            .read()
+            .profiles
+            .values()
+            .find(|p| eq_ignore_case(&p.github_login, github_login))
+            .map(without_nonce))
+    }
+
+    async fn find_by_twitter_handle(
+        &self,
+        twitter_handle: &str,
+    ) -> Result<Option<Profile>, AppError> {
+        Ok(self
+            .store
+            .read()
+            .profiles
+            .values()
+            .find(|p| eq_ignore_case(&p.twitter_handle, twitter_handle))
+            .map(without_nonce))
+    }
+
+    async fn find_by_linkedin_account(
+        &self,
+        linkedin_account: &str,
+    ) -> Result<Option<Profile>, AppError> {
+        Ok(self
+            .store
+            .read()
+            .profiles
+            .values()
+            .find(|p| eq_ignore_case(&p.linkedin_account, linkedin_account))
+            .map(without_nonce))
+    }
+
+    async fn get_login_nonce_by_wallet_address(
+        &self,
+        address: &WalletAddress,
+    ) -> Result<Option<i64>, AppError> {
+        Ok(self
+            .store
+            .read()
+            .profiles
+            .get(address.as_str())
+            .map(|p| p.login_nonce))
+    }
+
+    async fn increment_login_nonce(&self, address: &WalletAddress) -> Result<(), AppError> {
+        if let Some(profile) = self.store.write().profiles.get_mut(address.as_str()) {
+            profile.login_nonce += 1;
+            profile.updated_at = Utc::now();
+        }
+        Ok(())
+    }
+}
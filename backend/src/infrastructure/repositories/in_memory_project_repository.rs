@@ -0,0 +1,361 @@
+use std::cmp::{Ordering, Reverse};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    entities::{
+        project_member::ProjectMember,
+        projects::{Project, ProjectApproval, ProjectId, ProjectStatus, ProjectTransition},
+    },
+    errors::AppError,
+    repositories::project_repository::{
+        ProjectPage, ProjectRepository, ProjectSearch, ProjectSort,
+    },
+    value_objects::{PageCursor, WalletAddress},
+};
+
+use super::in_memory_store::{unique_violation, InMemoryStore};
+
+#[derive(Clone, Default)]
+pub struct InMemoryProjectRepository {
+    store: InMemoryStore,
+}
+
+impl InMemoryProjectRepository {
+    pub fn new(store: InMemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+/// Members are keyed like the case-insensitive Postgres index on address.
+fn member_key(id: &ProjectId, address: &WalletAddress) -> (uuid::Uuid, String) {
+    (id.value(), address.checksummed().0)
+}
+
+fn newest_first(projects: &mut [Project]) {
+    projects.sort_by_key(|p| Reverse(p.created_at));
+}
+
+fn matches_filters(project: &Project, search: &ProjectSearch) -> bool {
+    search
+        .terms
+        .matches(&format!("{} {}", project.name, project.description))
+        && search.status.is_none_or(|s| project.status == s)
+        && search
+            .creator
+            .as_ref()
+            .is_none_or(|c| project.creator == *c)
+        && search
+            .created_after
+            .is_none_or(|at| project.created_at >= at)
+        && search
+            .created_before
+            .is_none_or(|at| project.created_at < at)
+}
+
+/// Same ordering as the Postgres query: sort key, then id.
+fn compare(sort: ProjectSort, a: &Project, b: &Project) -> Ordering {
+    let (a_id, b_id) = (a.id.value(), b.id.value());
+    match sort {
+        ProjectSort::Newest => (b.created_at, b_id).cmp(&(a.created_at, a_id)),
+        ProjectSort::Oldest => (a.created_at, a_id).cmp(&(b.created_at, b_id)),
+        ProjectSort::Updated => (b.updated_at, b_id).cmp(&(a.updated_at, a_id)),
+        ProjectSort::Name => (a.name.to_lowercase(), a_id).cmp(&(b.name.to_lowercase(), b_id)),
+    }
+}
+
+fn is_after(sort: ProjectSort, project: &Project, cursor: &PageCursor) -> Result<bool, AppError> {
+    let malformed = || AppError::invalid_field("cursor", "Malformed cursor");
+    let id = cursor.id.parse::<uuid::Uuid>().map_err(|_| malformed())?;
+    let at = || {
+        DateTime::parse_from_rfc3339(&cursor.key)
+            .map(|at| at.with_timezone(&Utc))
+            .map_err(|_| malformed())
+    };
+    let own_id = project.id.value();
+    Ok(match sort {
+        ProjectSort::Newest => (project.created_at, own_id) < (at()?, id),
+        ProjectSort::Oldest => (project.created_at, own_id) > (at()?, id),
+        ProjectSort::Updated => (project.updated_at, own_id) < (at()?, id),
+        ProjectSort::Name => (project.name.to_lowercase(), own_id) > (cursor.key.clone(), id),
+    })
+}
+
+#[async_trait]
+impl ProjectRepository for InMemoryProjectRepository {
+    async fn create(&self, project: &Project) -> Result<(), AppError> {
+        let mut tables = self.store.write();
+        if !tables.profiles.contains_key(project.creator.as_str()) {
+            return Err(AppError::internal(
+                "Database error: insert or update on table \"projects\" violates foreign key constraint \"fk_creator\"",
+            ));
+        }
+        if tables.projects.contains_key(&project.id.value()) {
+            return Err(unique_violation());
+        }
+
+        tables.projects.insert(project.id.value(), project.clone());
+        tables.project_members.insert(
+            member_key(&project.id, &project.creator),
+            ProjectMember::founder(project),
+        );
+        tables
+            .project_history
+            .push(ProjectTransition::created(project));
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &ProjectId) -> Result<Option<Project>, AppError> {
+        Ok(self.store.read().projects.get(&id.value()).cloned())
+    }
+
+    async fn search(&self, search: &ProjectSearch) -> Result<ProjectPage, AppError> {
+        let mut matching: Vec<Project> = self
+            .store
+            .read()
+            .projects
+            .values()
+            .filter(|p| matches_filters(p, search))
+            .cloned()
+            .collect();
+        matching.sort_by(|a, b| compare(search.sort, a, b));
+        let total = matching.len() as i64;
+
+        let mut projects = Vec::new();
+        for project in matching {
+            if let Some(cursor) = &search.after {
+                if !is_after(search.sort, &project, cursor)? {
+                    continue;
+                }
+            }
+            projects.push(project);
+        }
+
+        let limit = search.limit.max(0) as usize;
+        let next_cursor = if limit > 0 && projects.len() > limit {
+            Some(search.sort.cursor_for(&projects[limit - 1]))
+        } else {
+            None
+        };
+        projects.truncate(limit);
+
+        Ok(ProjectPage {
+            projects,
+            total,
+            next_cursor,
+        })
+    }
+
+    async fn find_by_creator(&self, creator: &WalletAddress) -> Result<Vec<Project>, AppError> {
+        let mut projects: Vec<Project> = self
+            .store
+            .read()
+            .projects
+            .values()
+            .filter(|p| p.creator == *creator)
+            .cloned()
+            .collect();
+        newest_first(&mut projects);
+        Ok(projects)
+    }
+
+    async fn update(&self, project: &Project) -> Result<(), AppError> {
+        if let Some(existing) = self.store.write().projects.get_mut(&project.id.value()) {
+            existing.name = project.name.clone();
+            existing.description = project.description.clone();
+            // The Postgres trigger always stamps updated_at on UPDATE
+            existing.updated_at = Utc::now();
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: &ProjectId) -> Result<(), AppError> {
+        let mut tables = self.store.write();
+        tables.projects.remove(&id.value());
+        // ON DELETE CASCADE from project_members.project_id
+        tables
+            .project_members
+            .retain(|(project_id, _), _| *project_id != id.value());
+        tables
+            .project_history
+            .retain(|entry| entry.project_id != *id);
+        tables
+            .project_approvals
+            .retain(|(project_id, _), _| *project_id != id.value());
+        tables
+            .project_repos
+            .retain(|(project_id, _)| *project_id != id.value());
+        Ok(())
+    }
+
+    async fn exists(&self, id: &ProjectId) -> Result<bool, AppError> {
+        Ok(self.store.read().projects.contains_key(&id.value()))
+    }
+
+    async fn profile_exists(&self, address: &WalletAddress) -> Result<bool, AppError> {
+        Ok(self.store.read().profiles.contains_key(address.as_str()))
+    }
+
+    async fn list_members(&self, id: &ProjectId) -> Result<Vec<ProjectMember>, AppError> {
+        let mut members: Vec<ProjectMember> = self
+            .store
+            .read()
+            .project_members
+            .values()
+            .filter(|m| m.project_id == *id)
+            .cloned()
+            .collect();
+        members.sort_by(|a, b| (a.invited_at, &a.address.0).cmp(&(b.invited_at, &b.address.0)));
+        Ok(members)
+    }
+
+    async fn find_member(
+        &self,
+        id: &ProjectId,
+        address: &WalletAddress,
+    ) -> Result<Option<ProjectMember>, AppError> {
+        Ok(self
+            .store
+            .read()
+            .project_members
+            .get(&member_key(id, address))
+            .cloned())
+    }
+
+    async fn add_member(&self, member: &ProjectMember) -> Result<(), AppError> {
+        let mut tables = self.store.write();
+        if !tables.projects.contains_key(&member.project_id.value()) {
+            return Err(AppError::internal(
+                "Database error: insert or update on table \"project_members\" violates foreign key constraint",
+            ));
+        }
+        let key = member_key(&member.project_id, &member.address);
+        if tables.project_members.contains_key(&key) {
+            return Err(unique_violation());
+        }
+        tables.project_members.insert(key, member.clone());
+        Ok(())
+    }
+
+    async fn update_member(&self, member: &ProjectMember) -> Result<(), AppError> {
+        let key = member_key(&member.project_id, &member.address);
+        if let Some(existing) = self.store.write().project_members.get_mut(&key) {
+            existing.role = member.role;
+            existing.status = member.status;
+            existing.joined_at = member.joined_at;
+        }
+        Ok(())
+    }
+
+    async fn remove_member(&self, id: &ProjectId, address: &WalletAddress) -> Result<(), AppError> {
+        self.store
+            .write()
+            .project_members
+            .remove(&member_key(id, address));
+        Ok(())
+    }
+
+    async fn record_transition(
+        &self,
+        project: &Project,
+        transition: &ProjectTransition,
+    ) -> Result<(), AppError> {
+        let from = transition
+            .from
+            .ok_or_else(|| AppError::internal("A status change needs the status it leaves"))?;
+        let mut tables = self.store.write();
+        let Some(existing) = tables.projects.get_mut(&project.id.value()) else {
+            return Err(AppError::not_found("Project not found"));
+        };
+        if existing.status != from {
+            return Err(AppError::conflict(format!(
+                "Project {} is no longer {}",
+                project.id.value(),
+                from
+            )));
+        }
+        existing.status = project.status;
+        existing.updated_at = Utc::now();
+        tables.project_history.push(transition.clone());
+        if transition.to == ProjectStatus::Proposal {
+            tables
+                .project_approvals
+                .retain(|(project_id, _), _| *project_id != project.id.value());
+        }
+        Ok(())
+    }
+
+    async fn history(&self, id: &ProjectId) -> Result<Vec<ProjectTransition>, AppError> {
+        let mut history: Vec<ProjectTransition> = self
+            .store
+            .read()
+            .project_history
+            .iter()
+            .filter(|entry| entry.project_id == *id)
+            .cloned()
+            .collect();
+        // Stable, so entries sharing a timestamp keep their insertion order
+        history.sort_by_key(|entry| entry.created_at);
+        Ok(history)
+    }
+
+    async fn add_approval(&self, approval: &ProjectApproval) -> Result<(), AppError> {
+        let mut tables = self.store.write();
+        if !tables.projects.contains_key(&approval.project_id.value()) {
+            return Err(AppError::internal(
+                "Database error: insert or update on table \"project_approvals\" violates foreign key constraint",
+            ));
+        }
+        let key = (
+            approval.project_id.value(),
+            approval.reviewer.as_str().to_string(),
+        );
+        if tables.project_approvals.contains_key(&key) {
+            return Err(unique_violation());
+        }
+        tables.project_approvals.insert(key, approval.clone());
+        Ok(())
+    }
+
+    async fn list_approvals(&self, id: &ProjectId) -> Result<Vec<ProjectApproval>, AppError> {
+        let mut approvals: Vec<ProjectApproval> = self
+            .store
+            .read()
+            .project_approvals
+            .values()
+            .filter(|a| a.project_id == *id)
+            .cloned()
+            .collect();
+        approvals
+            .sort_by(|a, b| (a.approved_at, &a.reviewer.0).cmp(&(b.approved_at, &b.reviewer.0)));
+        Ok(approvals)
+    }
+
+    async fn list_repos(&self, id: &ProjectId) -> Result<Vec<String>, AppError> {
+        Ok(self
+            .store
+            .read()
+            .project_repos
+            .iter()
+            .filter(|(project_id, _)| *project_id == id.value())
+            .map(|(_, repo)| repo.clone())
+            .collect())
+    }
+
+    async fn set_repos(&self, id: &ProjectId, repos: &[String]) -> Result<(), AppError> {
+        let mut tables = self.store.write();
+        if !tables.projects.contains_key(&id.value()) {
+            return Err(AppError::internal(
+                "Database error: insert or update on table \"project_repos\" violates foreign key constraint",
+            ));
+        }
+        tables
+            .project_repos
+            .retain(|(project_id, _)| *project_id != id.value());
+        tables
+            .project_repos
+            .extend(repos.iter().map(|repo| (id.value(), repo.clone())));
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        distribution::Distribution,
+        github_issue::GithubIssue,
+        profile::Profile,
+        project_member::ProjectMember,
+        projects::{Project, ProjectApproval, ProjectTransition},
+    },
+    errors::AppError,
+};
+
+/// Rows held by the in-memory repositories, one collection per table.
+#[derive(Default)]
+pub(crate) struct Tables {
+    pub profiles: BTreeMap<String, Profile>,
+    pub projects: HashMap<Uuid, Project>,
+    /// Keyed by (project id, checksummed member address)
+    pub project_members: BTreeMap<(Uuid, String), ProjectMember>,
+    /// In insertion order
+    pub project_history: Vec<ProjectTransition>,
+    /// Keyed by (project id, reviewer address)
+    pub project_approvals: BTreeMap<(Uuid, String), ProjectApproval>,
+    /// (project id, repository name)
+    pub project_repos: BTreeSet<(Uuid, String)>,
+    pub github_issues: HashMap<(i64, i64), GithubIssue>,
+    pub distributions: BTreeMap<String, Distribution>,
+}
+
+/// The error `From<sqlx::Error>` returns for a unique violation, so both
+/// backends answer a duplicate the same way.
+pub(crate) fn unique_violation() -> AppError {
+    AppError::conflict("Resource already exists")
+}
+
+/// Shared backing store for the in-memory repositories.
+///
+/// Repositories built from clones of the same store see each other's writes,
+/// which is what lets profile deletion cascade to projects the way the
+/// `fk_creator` foreign key does in Postgres.
+#[derive(Clone, Default)]
+pub struct InMemoryStore {
+    tables: Arc<RwLock<Tables>>,
+}
+
+impl InMemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn read(&self) -> RwLockReadGuard<'_, Tables> {
+        self.tables.read().expect("in-memory store lock poisoned")
+    }
+
+    pub(crate) fn write(&self) -> RwLockWriteGuard<'_, Tables> {
+        self.tables.write().expect("in-memory store lock poisoned")
+    }
+}
@@ -1,8 +1,29 @@
+#[cfg(any(test, feature = "test-support"))]
+pub mod in_memory_distribution_repository;
+#[cfg(any(test, feature = "test-support"))]
+pub mod in_memory_github_issue_repository;
+#[cfg(any(test, feature = "test-support"))]
+pub mod in_memory_profile_repository;
+#[cfg(any(test, feature = "test-support"))]
+pub mod in_memory_project_repository;
+#[cfg(any(test, feature = "test-support"))]
+pub mod in_memory_store;
 pub mod postgres_distribution_repository;
 pub mod postgres_github_issue_repository;
 pub mod postgres_profile_repository;
 pub mod postgres_project_repository;
+mod postgres_search;
 
+#[cfg(any(test, feature = "test-support"))]
+pub use in_memory_distribution_repository::InMemoryDistributionRepository;
+#[cfg(any(test, feature = "test-support"))]
+pub use in_memory_github_issue_repository::InMemoryGithubIssueRepository;
+#[cfg(any(test, feature = "test-support"))]
+pub use in_memory_profile_repository::InMemoryProfileRepository;
+#[cfg(any(test, feature = "test-support"))]
+pub use in_memory_project_repository::InMemoryProjectRepository;
+#[cfg(any(test, feature = "test-support"))]
+pub use in_memory_store::InMemoryStore;
 pub use postgres_distribution_repository::PostgresDistributionRepository;
 pub use postgres_github_issue_repository::PostgresGithubIssueRepository;
 pub use postgres_profile_repository::PostgresProfileRepository;
@@ -1,9 +1,11 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, Transaction};
 
-use crate::domain::repositories::distribution_repository::{
-    DistributionRecord, DistributionRepository,
-};
+use crate::domain::entities::distribution::{Distribution, DistributionLine, DistributionStatus};
+use crate::domain::errors::AppError;
+use crate::domain::repositories::distribution_repository::DistributionRepository;
+use crate::domain::value_objects::WalletAddress;
 
 #[derive(Clone)]
 pub struct PostgresDistributionRepository {
@@ -18,67 +20,229 @@ impl PostgresDistributionRepository {
 
 #[async_trait]
 impl DistributionRepository for PostgresDistributionRepository {
-    async fn create_many(
-        &self,
-        records: &[DistributionRecord],
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    async fn register_all(&self, distributions: &[Distribution]) -> Result<(), AppError> {
         let mut tx = self.pool.begin().await?;
-
-        for record in records {
-            sqlx::query(
-                r#"
-                INSERT INTO distributions (address, badge_name, distribution_id)
-                VALUES ($1, $2, $3)
-                "#,
-            )
-            .bind(&record.address)
-            .bind(&record.badge_name)
-            .bind(&record.distribution_id)
-            .execute(&mut *tx)
-            .await?;
+        for distribution in distributions {
+            register_in(&mut tx, distribution).await?;
         }
-
         tx.commit().await?;
         Ok(())
     }
 
-    async fn list(
+    async fn find_by_id(&self, id: &str) -> Result<Option<Distribution>, AppError> {
+        let row: Option<(
+            String,
+            String,
+            String,
+            Option<String>,
+            Option<i64>,
+            DateTime<Utc>,
+            DateTime<Utc>,
+        )> = sqlx::query_as(
+            r#"
+            SELECT id, creator, status, tx_hash, chain_id, created_at, updated_at
+            FROM distributions
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some((id, creator, status, tx_hash, chain_id, created_at, updated_at)) = row else {
+            return Ok(None);
+        };
+        let lines = self.list_lines(Some(&id)).await?;
+
+        Ok(Some(Distribution {
+            id,
+            creator: WalletAddress(creator),
+            status: parse_status(&status)?,
+            tx_hash,
+            chain_id,
+            created_at,
+            updated_at,
+            lines,
+        }))
+    }
+
+    async fn update_status(
+        &self,
+        distribution: &Distribution,
+        from: DistributionStatus,
+    ) -> Result<(), AppError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE distributions
+            SET status = $2, tx_hash = $3, chain_id = $4, updated_at = $5
+            WHERE id = $1 AND status = $6
+            "#,
+        )
+        .bind(&distribution.id)
+        .bind(distribution.status.as_str())
+        .bind(&distribution.tx_hash)
+        .bind(distribution.chain_id)
+        .bind(distribution.updated_at)
+        .bind(from.as_str())
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::conflict(format!(
+                "Distribution {} is no longer {}",
+                distribution.id, from
+            )));
+        }
+        Ok(())
+    }
+
+    async fn list_ids_by_status(
+        &self,
+        status: DistributionStatus,
+    ) -> Result<Vec<String>, AppError> {
+        let ids: Vec<(String,)> = sqlx::query_as(
+            r#"
+            SELECT id
+            FROM distributions
+            WHERE status = $1
+            ORDER BY created_at ASC, id ASC
+            "#,
+        )
+        .bind(status.as_str())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(ids.into_iter().map(|(id,)| id).collect())
+    }
+
+    async fn find_distribution_ids_with_line(
+        &self,
+        address: &str,
+        badge_name: &str,
+    ) -> Result<Vec<String>, AppError> {
+        let ids: Vec<(String,)> = sqlx::query_as(
+            r#"
+            SELECT DISTINCT l.distribution_id
+            FROM distribution_lines l
+            JOIN distributions d ON d.id = l.distribution_id
+            WHERE LOWER(l.address) = LOWER($1) AND l.badge_name = $2 AND d.status <> 'failed'
+            ORDER BY l.distribution_id
+            "#,
+        )
+        .bind(address)
+        .bind(badge_name)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(ids.into_iter().map(|(id,)| id).collect())
+    }
+
+    async fn list_lines(
         &self,
         distribution_id: Option<&str>,
-    ) -> Result<Vec<DistributionRecord>, Box<dyn std::error::Error>> {
-        let rows: Vec<(String, String, String)> = if let Some(id) = distribution_id {
-            sqlx::query_as(
-                r#"
-                SELECT address, badge_name, distribution_id
-                FROM distributions
+    ) -> Result<Vec<DistributionLine>, AppError> {
+        let rows: Vec<(String, String, String, Option<String>, Option<String>)> =
+            if let Some(id) = distribution_id {
+                sqlx::query_as(
+                    r#"
+                SELECT distribution_id, address, badge_name, amount::text, reason
+                FROM distribution_lines
                 WHERE distribution_id = $1
-                ORDER BY address ASC
+                ORDER BY address ASC, badge_name ASC
                 "#,
-            )
-            .bind(id)
-            .fetch_all(&self.pool)
-            .await?
-        } else {
-            sqlx::query_as(
-                r#"
-                SELECT address, badge_name, distribution_id
-                FROM distributions
-                ORDER BY distribution_id DESC, address ASC
+                )
+                .bind(id)
+                .fetch_all(&self.pool)
+                .await?
+            } else {
+                sqlx::query_as(
+                    r#"
+                SELECT distribution_id, address, badge_name, amount::text, reason
+                FROM distribution_lines
+                ORDER BY distribution_id DESC, address ASC, badge_name ASC
                 "#,
-            )
-            .fetch_all(&self.pool)
-            .await?
-        };
+                )
+                .fetch_all(&self.pool)
+                .await?
+            };
 
-        Ok(rows
-            .into_iter()
-            .map(
-                |(address, badge_name, distribution_id)| DistributionRecord {
+        rows.into_iter()
+            .map(|(distribution_id, address, badge_name, amount, reason)| {
+                Ok(DistributionLine {
+                    distribution_id,
                     address,
                     badge_name,
-                    distribution_id,
-                },
-            )
-            .collect())
+                    amount: amount
+                        .map(|amount| amount.parse().map_err(AppError::internal))
+                        .transpose()?,
+                    reason,
+                })
+            })
+            .collect()
     }
 }
+
+/// Header and new lines of one distribution, inside the caller's transaction.
+async fn register_in(
+    tx: &mut Transaction<'_, Postgres>,
+    distribution: &Distribution,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        INSERT INTO distributions (id, creator, status, tx_hash, chain_id, created_at, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        ON CONFLICT (id) DO NOTHING
+        "#,
+    )
+    .bind(&distribution.id)
+    .bind(distribution.creator.as_str())
+    .bind(distribution.status.as_str())
+    .bind(&distribution.tx_hash)
+    .bind(distribution.chain_id)
+    .bind(distribution.created_at)
+    .bind(distribution.updated_at)
+    .execute(&mut **tx)
+    .await?;
+
+    // Locks the header so a concurrent submit cannot slip in between the
+    // status check and the new lines.
+    let (status,): (String,) =
+        sqlx::query_as("SELECT status FROM distributions WHERE id = $1 FOR UPDATE")
+            .bind(&distribution.id)
+            .fetch_one(&mut **tx)
+            .await?;
+    let status = parse_status(&status)?;
+
+    let mut added = 0;
+    for line in &distribution.lines {
+        added += sqlx::query(
+            r#"
+            INSERT INTO distribution_lines (distribution_id, address, badge_name, amount, reason)
+            VALUES ($1, $2, $3, $4::numeric, $5)
+            ON CONFLICT (distribution_id, address, badge_name) DO NOTHING
+            "#,
+        )
+        .bind(&distribution.id)
+        .bind(&line.address)
+        .bind(&line.badge_name)
+        .bind(line.amount.map(|amount| amount.to_string()))
+        .bind(&line.reason)
+        .execute(&mut **tx)
+        .await?
+        .rows_affected();
+    }
+
+    if added > 0 && status != DistributionStatus::Draft {
+        return Err(AppError::conflict(format!(
+            "Distribution {} is {}; lines can only be added to drafts",
+            distribution.id, status
+        )));
+    }
+
+    Ok(())
+}
+
+/// A status outside the CHECK constraint means the row is corrupt, not a draft.
+fn parse_status(status: &str) -> Result<DistributionStatus, AppError> {
+    status.parse().map_err(AppError::internal)
+}
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use sqlx::PgPool;
 
 use crate::domain::{
-    entities::github_issue::GithubIssue,
+    entities::github_issue::GithubIssue, errors::AppError,
     repositories::github_issue_repository::GithubIssueRepository,
 };
 
@@ -19,7 +19,7 @@ impl PostgresGithubIssueRepository {
 
 #[async_trait]
 impl GithubIssueRepository for PostgresGithubIssueRepository {
-    async fn upsert(&self, issue: &GithubIssue) -> Result<(), Box<dyn std::error::Error>> {
+    async fn upsert(&self, issue: &GithubIssue) -> Result<(), AppError> {
         sqlx::query(
             r#"
             INSERT INTO github_issues (
@@ -58,8 +58,7 @@ impl GithubIssueRepository for PostgresGithubIssueRepository {
         .bind(&issue.distribution_id)
         .bind(issue.updated_at)
         .execute(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .await?;
 
         Ok(())
     }
@@ -68,7 +67,7 @@ impl GithubIssueRepository for PostgresGithubIssueRepository {
         &self,
         repo_id: i64,
         github_issue_id: i64,
-    ) -> Result<Option<GithubIssue>, Box<dyn std::error::Error>> {
+    ) -> Result<Option<GithubIssue>, AppError> {
         let row = sqlx::query_as::<
             _,
             (
@@ -100,8 +99,7 @@ impl GithubIssueRepository for PostgresGithubIssueRepository {
         .bind(repo_id)
         .bind(github_issue_id)
         .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .await?;
 
         Ok(row.map(|r| GithubIssue {
             repo_id: r.0,
@@ -126,7 +124,7 @@ impl GithubIssueRepository for PostgresGithubIssueRepository {
         &self,
         repo: &str,
         state: Option<&str>,
-    ) -> Result<Vec<GithubIssue>, Box<dyn std::error::Error>> {
+    ) -> Result<Vec<GithubIssue>, AppError> {
         let rows: Vec<(
             i64,
             i64,
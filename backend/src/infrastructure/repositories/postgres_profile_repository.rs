@@ -1,10 +1,57 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, QueryBuilder};
 
 use crate::domain::entities::profile::Profile;
-use crate::domain::repositories::profile_repository::ProfileRepository;
+use crate::domain::errors::AppError;
+use crate::domain::repositories::profile_repository::{
+    ProfilePage, ProfileRepository, ProfileSearch, ProfileSort,
+};
 use crate::domain::value_objects::WalletAddress;
 
+use super::postgres_search::push_prefix_match;
+
+type ProfileRow = (
+    String,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<DateTime<Utc>>,
+    Option<DateTime<Utc>>,
+);
+
+/// Must match the expression of the idx_profiles_search GIN index.
+const SEARCH_DOCUMENT: &str = "to_tsvector('simple', COALESCE(name, '') || ' ' || COALESCE(description, '') || ' ' || COALESCE(github_login, ''))";
+
+const NAME_KEY: &str = r#"LOWER(COALESCE(name, '')) COLLATE "C""#;
+
+fn push_filters(query: &mut QueryBuilder<'_, Postgres>, search: &ProfileSearch) {
+    push_prefix_match(query, SEARCH_DOCUMENT, &search.terms);
+    match search.has_github {
+        Some(true) => {
+            query.push(" AND COALESCE(github_login, '') <> ''");
+        }
+        Some(false) => {
+            query.push(" AND COALESCE(github_login, '') = ''");
+        }
+        None => {}
+    }
+    if let Some(has_badges) = search.has_badges {
+        query
+            .push(if has_badges { " AND " } else { " AND NOT " })
+            .push(
+                r#"EXISTS (
+                SELECT 1 FROM distribution_lines l
+                JOIN distributions d ON d.id = l.distribution_id
+                WHERE d.status = 'confirmed' AND LOWER(l.address) = LOWER(profiles.address)
+            )"#,
+            );
+    }
+}
+
 #[derive(Clone)]
 pub struct PostgresProfileRepository {
     pool: PgPool,
@@ -18,10 +65,7 @@ impl PostgresProfileRepository {
 
 #[async_trait]
 impl ProfileRepository for PostgresProfileRepository {
-    async fn find_by_address(
-        &self,
-        address: &WalletAddress,
-    ) -> Result<Option<Profile>, Box<dyn std::error::Error>> {
+    async fn find_by_address(&self, address: &WalletAddress) -> Result<Option<Profile>, AppError> {
         let row = sqlx::query!(
             r#"
             SELECT address, name, description, avatar_url, github_login, twitter_handle, linkedin_account, created_at, updated_at
@@ -31,8 +75,7 @@ impl ProfileRepository for PostgresProfileRepository {
             address.as_str()
         )
         .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .await?;
 
         Ok(row.map(|r| Profile {
             address: WalletAddress(r.address),
@@ -48,7 +91,7 @@ impl ProfileRepository for PostgresProfileRepository {
         }))
     }
 
-    async fn find_all(&self) -> Result<Vec<Profile>, Box<dyn std::error::Error>> {
+    async fn find_all(&self) -> Result<Vec<Profile>, AppError> {
         let rows = sqlx::query!(
             r#"
             SELECT address, name, description, avatar_url, github_login, twitter_handle, linkedin_account, created_at, updated_at
@@ -56,8 +99,7 @@ impl ProfileRepository for PostgresProfileRepository {
             "#,
         )
         .fetch_all(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .await?;
 
         Ok(rows
             .into_iter()
@@ -76,7 +118,102 @@ impl ProfileRepository for PostgresProfileRepository {
             .collect())
     }
 
-    async fn create(&self, profile: &Profile) -> Result<(), Box<dyn std::error::Error>> {
+    async fn search(&self, search: &ProfileSearch) -> Result<ProfilePage, AppError> {
+        let mut count = QueryBuilder::new("SELECT COUNT(*) FROM profiles WHERE TRUE");
+        push_filters(&mut count, search);
+        let (total,): (i64,) = count.build_query_as().fetch_one(&self.pool).await?;
+
+        let mut query = QueryBuilder::new(
+            "SELECT address, name, description, avatar_url, github_login, twitter_handle, linkedin_account, created_at, updated_at FROM profiles WHERE TRUE",
+        );
+        push_filters(&mut query, search);
+
+        if let Some(cursor) = &search.after {
+            match search.sort {
+                ProfileSort::Newest | ProfileSort::Oldest => {
+                    let at = DateTime::parse_from_rfc3339(&cursor.key)
+                        .map_err(|_| AppError::invalid_field("cursor", "Malformed cursor"))?
+                        .with_timezone(&Utc);
+                    let op = if search.sort == ProfileSort::Newest {
+                        "<"
+                    } else {
+                        ">"
+                    };
+                    query
+                        .push(format!(r#" AND (created_at, address COLLATE "C") {op} ("#))
+                        .push_bind(at)
+                        .push(", ")
+                        .push_bind(cursor.id.clone())
+                        .push(")");
+                }
+                ProfileSort::Name => {
+                    query
+                        .push(format!(r#" AND ({NAME_KEY}, address COLLATE "C") > ("#))
+                        .push_bind(cursor.key.clone())
+                        .push(", ")
+                        .push_bind(cursor.id.clone())
+                        .push(")");
+                }
+            }
+        }
+
+        query.push(match search.sort {
+            ProfileSort::Newest => {
+                r#" ORDER BY created_at DESC, address COLLATE "C" DESC"#.to_string()
+            }
+            ProfileSort::Oldest => {
+                r#" ORDER BY created_at ASC, address COLLATE "C" ASC"#.to_string()
+            }
+            ProfileSort::Name => format!(r#" ORDER BY {NAME_KEY} ASC, address COLLATE "C" ASC"#),
+        });
+        // One extra row tells whether there is a next page
+        query.push(" LIMIT ").push_bind(search.limit + 1);
+
+        let rows: Vec<ProfileRow> = query.build_query_as().fetch_all(&self.pool).await?;
+        let mut profiles: Vec<Profile> = rows
+            .into_iter()
+            .map(
+                |(
+                    address,
+                    name,
+                    description,
+                    avatar_url,
+                    github_login,
+                    twitter_handle,
+                    linkedin_account,
+                    created_at,
+                    updated_at,
+                )| Profile {
+                    address: WalletAddress(address),
+                    name,
+                    description,
+                    avatar_url,
+                    github_login,
+                    twitter_handle,
+                    linkedin_account,
+                    login_nonce: 0, // Not needed for regular profile queries
+                    created_at: created_at.unwrap_or_default(),
+                    updated_at: updated_at.unwrap_or_default(),
+                },
+            )
+            .collect();
+
+        let limit = search.limit.max(0) as usize;
+        let next_cursor = if limit > 0 && profiles.len() > limit {
+            Some(search.sort.cursor_for(&profiles[limit - 1]))
+        } else {
+            None
+        };
+        profiles.truncate(limit);
+
+        Ok(ProfilePage {
+            profiles,
+            total,
+            next_cursor,
+        })
+    }
+
+    async fn create(&self, profile: &Profile) -> Result<(), AppError> {
         sqlx::query!(
             r#"
             INSERT INTO profiles (address, name, description, avatar_url, github_login, twitter_handle, linkedin_account, login_nonce, created_at, updated_at)
@@ -94,13 +231,12 @@ impl ProfileRepository for PostgresProfileRepository {
             profile.updated_at
         )
         .execute(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .await?;
 
         Ok(())
     }
 
-    async fn update(&self, profile: &Profile) -> Result<(), Box<dyn std::error::Error>> {
+    async fn update(&self, profile: &Profile) -> Result<(), AppError> {
         sqlx::query!(
             r#"
             UPDATE profiles
@@ -117,13 +253,12 @@ impl ProfileRepository for PostgresProfileRepository {
             profile.updated_at
         )
         .execute(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .await?;
 
         Ok(())
     }
 
-    async fn delete(&self, address: &WalletAddress) -> Result<(), Box<dyn std::error::Error>> {
+    async fn delete(&self, address: &WalletAddress) -> Result<(), AppError> {
         sqlx::query!(
             r#"
             DELETE FROM profiles
@@ -132,16 +267,12 @@ impl ProfileRepository for PostgresProfileRepository {
             address.as_str()
         )
         .execute(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .await?;
 
         Ok(())
     }
 
-    async fn find_by_github_login(
-        &self,
-        github_login: &str,
-    ) -> Result<Option<Profile>, Box<dyn std::error::Error + Send + Sync>> {
+    async fn find_by_github_login(&self, github_login: &str) -> Result<Option<Profile>, AppError> {
         let row = sqlx::query!(
             r#"
             SELECT address, name, description, avatar_url, github_login, twitter_handle, linkedin_account, created_at, updated_at
@@ -151,8 +282,7 @@ impl ProfileRepository for PostgresProfileRepository {
             github_login
         )
         .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)?;
+        .await?;
 
         Ok(row.map(|r| Profile {
             address: WalletAddress(r.address),
@@ -171,7 +301,7 @@ impl ProfileRepository for PostgresProfileRepository {
     async fn find_by_twitter_handle(
         &self,
         twitter_handle: &str,
-    ) -> Result<Option<Profile>, Box<dyn std::error::Error + Send + Sync>> {
+    ) -> Result<Option<Profile>, AppError> {
         let row = sqlx::query!(
             r#"
             SELECT address, name, description, avatar_url, github_login, twitter_handle, linkedin_account, created_at, updated_at
@@ -181,8 +311,7 @@ impl ProfileRepository for PostgresProfileRepository {
             twitter_handle
         )
         .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)?;
+        .await?;
 
         Ok(row.map(|r| Profile {
             address: WalletAddress(r.address),
@@ -201,7 +330,7 @@ impl ProfileRepository for PostgresProfileRepository {
     async fn find_by_linkedin_account(
         &self,
         linkedin_account: &str,
-    ) -> Result<Option<Profile>, Box<dyn std::error::Error + Send + Sync>> {
+    ) -> Result<Option<Profile>, AppError> {
         let row = sqlx::query!(
             r#"
             SELECT address, name, description, avatar_url, github_login, twitter_handle, linkedin_account, created_at, updated_at
@@ -211,8 +340,7 @@ impl ProfileRepository for PostgresProfileRepository {
             linkedin_account
         )
         .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send + Sync>)?;
+        .await?;
 
         Ok(row.map(|r| Profile {
             address: WalletAddress(r.address),
@@ -231,7 +359,7 @@ impl ProfileRepository for PostgresProfileRepository {
     async fn get_login_nonce_by_wallet_address(
         &self,
         address: &WalletAddress,
-    ) -> Result<Option<i64>, Box<dyn std::error::Error>> {
+    ) -> Result<Option<i64>, AppError> {
         let row = sqlx::query!(
             r#"
             SELECT login_nonce
@@ -241,16 +369,12 @@ impl ProfileRepository for PostgresProfileRepository {
             address.as_str()
         )
         .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .await?;
 
         Ok(row.map(|r| r.login_nonce))
     }
 
-    async fn increment_login_nonce(
-        &self,
-        address: &WalletAddress,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    async fn increment_login_nonce(&self, address: &WalletAddress) -> Result<(), AppError> {
         sqlx::query!(
             r#"
             UPDATE profiles
@@ -260,8 +384,7 @@ impl ProfileRepository for PostgresProfileRepository {
             address.as_str()
         )
         .execute(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .await?;
 
         Ok(())
     }
@@ -1,12 +1,123 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use sqlx::{types::Uuid, PgPool, Postgres, QueryBuilder};
 
 use crate::domain::{
-    entities::projects::{Project, ProjectId, ProjectStatus},
-    repositories::project_repository::ProjectRepository,
+    entities::{
+        project_member::{MembershipStatus, ProjectMember, ProjectRole},
+        projects::{Project, ProjectApproval, ProjectId, ProjectStatus, ProjectTransition},
+    },
+    errors::AppError,
+    repositories::project_repository::{
+        ProjectPage, ProjectRepository, ProjectSearch, ProjectSort,
+    },
     value_objects::WalletAddress,
 };
 
+use super::postgres_search::push_prefix_match;
+
+type ProjectRow = (
+    Uuid,
+    String,
+    String,
+    String,
+    String,
+    DateTime<Utc>,
+    DateTime<Utc>,
+);
+
+type MemberRow = (
+    Uuid,
+    String,
+    String,
+    String,
+    Option<String>,
+    DateTime<Utc>,
+    Option<DateTime<Utc>>,
+);
+
+fn member_from_row(
+    (project_id, address, role, status, invited_by, invited_at, joined_at): MemberRow,
+) -> Result<ProjectMember, AppError> {
+    Ok(ProjectMember {
+        project_id: ProjectId::from_uuid(project_id),
+        address: WalletAddress(address),
+        role: role.parse::<ProjectRole>().map_err(AppError::internal)?,
+        status: status
+            .parse::<MembershipStatus>()
+            .map_err(AppError::internal)?,
+        invited_by: invited_by.map(WalletAddress),
+        invited_at,
+        joined_at,
+    })
+}
+
+const MEMBER_COLUMNS: &str = "project_id, address, role, status, invited_by, invited_at, joined_at";
+
+/// Must match the expression of the idx_projects_search GIN index.
+const SEARCH_DOCUMENT: &str = "to_tsvector('simple', name || ' ' || description)";
+
+const NAME_KEY: &str = r#"LOWER(name) COLLATE "C""#;
+
+fn push_filters(query: &mut QueryBuilder<'_, Postgres>, search: &ProjectSearch) {
+    push_prefix_match(query, SEARCH_DOCUMENT, &search.terms);
+    if let Some(status) = search.status {
+        query.push(" AND status = ").push_bind(status.as_str());
+    }
+    if let Some(creator) = &search.creator {
+        query
+            .push(" AND creator = ")
+            .push_bind(creator.as_str().to_string());
+    }
+    if let Some(after) = search.created_after {
+        query.push(" AND created_at >= ").push_bind(after);
+    }
+    if let Some(before) = search.created_before {
+        query.push(" AND created_at < ").push_bind(before);
+    }
+}
+
+async fn insert_member(
+    executor: &mut sqlx::PgConnection,
+    member: &ProjectMember,
+) -> Result<(), AppError> {
+    sqlx::query(&format!(
+        "INSERT INTO project_members ({MEMBER_COLUMNS}) VALUES ($1, $2, $3, $4, $5, $6, $7)"
+    ))
+    .bind(member.project_id.value())
+    .bind(member.address.as_str())
+    .bind(member.role.as_str())
+    .bind(member.status.as_str())
+    .bind(member.invited_by.as_ref().map(|a| a.as_str().to_string()))
+    .bind(member.invited_at)
+    .bind(member.joined_at)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+async fn insert_transition(
+    executor: &mut sqlx::PgConnection,
+    transition: &ProjectTransition,
+) -> Result<(), AppError> {
+    sqlx::query(
+        r#"
+        INSERT INTO project_history (id, project_id, from_status, to_status, actor, note, created_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        "#,
+    )
+    .bind(transition.id)
+    .bind(transition.project_id.value())
+    .bind(transition.from.map(|s| s.as_str()))
+    .bind(transition.to.as_str())
+    .bind(transition.actor.as_str())
+    .bind(transition.note.as_deref())
+    .bind(transition.created_at)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
 #[derive(Clone)]
 pub struct PostgresProjectRepository {
     pool: PgPool,
@@ -20,7 +131,9 @@ impl PostgresProjectRepository {
 
 #[async_trait]
 impl ProjectRepository for PostgresProjectRepository {
-    async fn create(&self, project: &Project) -> Result<(), Box<dyn std::error::Error>> {
+    async fn create(&self, project: &Project) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+
         sqlx::query!(
             r#"
             INSERT INTO projects (id, name, description, status, creator, created_at, updated_at)
@@ -34,17 +147,17 @@ impl ProjectRepository for PostgresProjectRepository {
             project.created_at,
             project.updated_at
         )
-        .execute(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .execute(&mut *tx)
+        .await?;
 
+        insert_member(&mut tx, &ProjectMember::founder(project)).await?;
+        insert_transition(&mut tx, &ProjectTransition::created(project)).await?;
+
+        tx.commit().await?;
         Ok(())
     }
 
-    async fn find_by_id(
-        &self,
-        id: &ProjectId,
-    ) -> Result<Option<Project>, Box<dyn std::error::Error>> {
+    async fn find_by_id(&self, id: &ProjectId) -> Result<Option<Project>, AppError> {
         let row = sqlx::query!(
             r#"
             SELECT id, name, description, status, creator, created_at, updated_at
@@ -54,126 +167,96 @@ impl ProjectRepository for PostgresProjectRepository {
             id.value()
         )
         .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .await?;
 
-        Ok(row.map(|r| {
-            let status = r.status.parse().unwrap_or(ProjectStatus::Proposal);
-            Project {
+        row.map(|r| {
+            Ok(Project {
                 id: ProjectId::from_uuid(r.id),
                 name: r.name,
                 description: r.description,
-                status,
+                status: r.status.parse().map_err(AppError::internal)?,
                 creator: WalletAddress(r.creator),
                 created_at: r.created_at,
                 updated_at: r.updated_at,
-            }
-        }))
+            })
+        })
+        .transpose()
     }
 
-    async fn find_all(
-        &self,
-        status: Option<ProjectStatus>,
-        creator: Option<&WalletAddress>,
-        limit: Option<i64>,
-        offset: Option<i64>,
-    ) -> Result<Vec<Project>, Box<dyn std::error::Error>> {
-        // Build query dynamically based on filters
-        let mut query = String::from(
-            "SELECT id, name, description, status, creator, created_at, updated_at FROM projects WHERE 1=1"
-        );
+    async fn search(&self, search: &ProjectSearch) -> Result<ProjectPage, AppError> {
+        let mut count = QueryBuilder::new("SELECT COUNT(*) FROM projects WHERE TRUE");
+        push_filters(&mut count, search);
+        let (total,): (i64,) = count.build_query_as().fetch_one(&self.pool).await?;
 
-        if status.is_some() {
-            query.push_str(" AND status = $1");
-        }
+        let mut query = QueryBuilder::new(
+            "SELECT id, name, description, status, creator, created_at, updated_at FROM projects WHERE TRUE",
+        );
+        push_filters(&mut query, search);
 
-        if creator.is_some() {
-            if status.is_some() {
-                query.push_str(" AND creator = $2");
+        if let Some(cursor) = &search.after {
+            let malformed = || AppError::invalid_field("cursor", "Malformed cursor");
+            let id: Uuid = cursor.id.parse().map_err(|_| malformed())?;
+            let (key, op) = match search.sort {
+                ProjectSort::Newest => ("created_at", "<"),
+                ProjectSort::Oldest => ("created_at", ">"),
+                ProjectSort::Updated => ("updated_at", "<"),
+                ProjectSort::Name => (NAME_KEY, ">"),
+            };
+            query.push(format!(" AND ({key}, id) {op} ("));
+            if search.sort == ProjectSort::Name {
+                query.push_bind(cursor.key.clone());
             } else {
-                query.push_str(" AND creator = $1");
+                let at = DateTime::parse_from_rfc3339(&cursor.key)
+                    .map_err(|_| malformed())?
+                    .with_timezone(&Utc);
+                query.push_bind(at);
             }
+            query.push(", ").push_bind(id).push(")");
         }
 
-        query.push_str(" ORDER BY created_at DESC");
-
-        if limit.is_some() {
-            let param_num = match (status.is_some(), creator.is_some()) {
-                (true, true) => 3,
-                (true, false) | (false, true) => 2,
-                (false, false) => 1,
-            };
-            query.push_str(&format!(" LIMIT ${}", param_num));
-        }
-
-        if offset.is_some() {
-            let param_num = match (status.is_some(), creator.is_some(), limit.is_some()) {
-                (true, true, true) => 4,
-                (true, true, false) => 3,
-                (true, false, true) | (false, true, true) => 3,
-                (true, false, false) | (false, true, false) | (false, false, true) => 2,
-                (false, false, false) => 1,
-            };
-            query.push_str(&format!(" OFFSET ${}", param_num));
-        }
-
-        let mut query_builder = sqlx::query_as::<
-            _,
-            (
-                sqlx::types::Uuid,
-                String,
-                String,
-                String,
-                String,
-                Option<chrono::DateTime<chrono::Utc>>,
-                Option<chrono::DateTime<chrono::Utc>>,
-            ),
-        >(&query);
-
-        if let Some(s) = status {
-            query_builder = query_builder.bind(s.as_str());
-        }
-
-        if let Some(c) = creator {
-            query_builder = query_builder.bind(c.as_str());
-        }
-
-        if let Some(l) = limit {
-            query_builder = query_builder.bind(l);
-        }
-
-        if let Some(o) = offset {
-            query_builder = query_builder.bind(o);
-        }
-
-        let rows = query_builder
-            .fetch_all(&self.pool)
-            .await
-            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        query.push(match search.sort {
+            ProjectSort::Newest => " ORDER BY created_at DESC, id DESC".to_string(),
+            ProjectSort::Oldest => " ORDER BY created_at ASC, id ASC".to_string(),
+            ProjectSort::Updated => " ORDER BY updated_at DESC, id DESC".to_string(),
+            ProjectSort::Name => format!(" ORDER BY {NAME_KEY} ASC, id ASC"),
+        });
+        // One extra row tells whether there is a next page
+        query.push(" LIMIT ").push_bind(search.limit + 1);
 
-        Ok(rows
+        let rows: Vec<ProjectRow> = query.build_query_as().fetch_all(&self.pool).await?;
+        let mut projects: Vec<Project> = rows
             .into_iter()
             .map(
                 |(id, name, description, status, creator, created_at, updated_at)| {
-                    let status = status.parse().unwrap_or(ProjectStatus::Proposal);
-                    Project {
+                    Ok(Project {
                         id: ProjectId::from_uuid(id),
                         name,
                         description,
-                        status,
+                        status: status.parse().map_err(AppError::internal)?,
                         creator: WalletAddress(creator),
-                        created_at: created_at.unwrap(),
-                        updated_at: updated_at.unwrap(),
-                    }
+                        created_at,
+                        updated_at,
+                    })
                 },
             )
-            .collect())
+            .collect::<Result<_, AppError>>()?;
+
+        let limit = search.limit.max(0) as usize;
+        let next_cursor = if limit > 0 && projects.len() > limit {
+            Some(search.sort.cursor_for(&projects[limit - 1]))
+        } else {
+            None
+        };
+        projects.truncate(limit);
+
+        Ok(ProjectPage {
+            projects,
+            total,
+            next_cursor,
+        })
     }
 
-    async fn find_by_creator(
-        &self,
-        creator: &WalletAddress,
-    ) -> Result<Vec<Project>, Box<dyn std::error::Error>> {
+    async fn find_by_creator(&self, creator: &WalletAddress) -> Result<Vec<Project>, AppError> {
         let rows = sqlx::query!(
             r#"
             SELECT id, name, description, status, creator, created_at, updated_at
@@ -184,47 +267,42 @@ impl ProjectRepository for PostgresProjectRepository {
             creator.as_str()
         )
         .fetch_all(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .await?;
 
-        Ok(rows
-            .into_iter()
+        rows.into_iter()
             .map(|r| {
-                let status = r.status.parse().unwrap_or(ProjectStatus::Proposal);
-                Project {
+                Ok(Project {
                     id: ProjectId::from_uuid(r.id),
                     name: r.name,
                     description: r.description,
-                    status,
+                    status: r.status.parse().map_err(AppError::internal)?,
                     creator: WalletAddress(r.creator),
                     created_at: r.created_at,
                     updated_at: r.updated_at,
-                }
+                })
             })
-            .collect())
+            .collect()
     }
 
-    async fn update(&self, project: &Project) -> Result<(), Box<dyn std::error::Error>> {
+    async fn update(&self, project: &Project) -> Result<(), AppError> {
         sqlx::query!(
             r#"
             UPDATE projects
-            SET name = $2, description = $3, status = $4, updated_at = $5
+            SET name = $2, description = $3, updated_at = $4
             WHERE id = $1
             "#,
             project.id.value(),
             project.name,
             project.description,
-            project.status.as_str(),
             project.updated_at
         )
         .execute(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .await?;
 
         Ok(())
     }
 
-    async fn delete(&self, id: &ProjectId) -> Result<(), Box<dyn std::error::Error>> {
+    async fn delete(&self, id: &ProjectId) -> Result<(), AppError> {
         sqlx::query!(
             r#"
             DELETE FROM projects
@@ -233,13 +311,12 @@ impl ProjectRepository for PostgresProjectRepository {
             id.value()
         )
         .execute(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .await?;
 
         Ok(())
     }
 
-    async fn exists(&self, id: &ProjectId) -> Result<bool, Box<dyn std::error::Error>> {
+    async fn exists(&self, id: &ProjectId) -> Result<bool, AppError> {
         let row = sqlx::query!(
             r#"
             SELECT EXISTS(SELECT 1 FROM projects WHERE id = $1) as "exists!"
@@ -247,16 +324,12 @@ impl ProjectRepository for PostgresProjectRepository {
             id.value()
         )
         .fetch_one(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .await?;
 
         Ok(row.exists)
     }
 
-    async fn profile_exists(
-        &self,
-        address: &WalletAddress,
-    ) -> Result<bool, Box<dyn std::error::Error>> {
+    async fn profile_exists(&self, address: &WalletAddress) -> Result<bool, AppError> {
         let row = sqlx::query!(
             r#"
             SELECT EXISTS(SELECT 1 FROM profiles WHERE address = $1) as "exists!"
@@ -264,9 +337,220 @@ impl ProjectRepository for PostgresProjectRepository {
             address.as_str()
         )
         .fetch_one(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .await?;
 
         Ok(row.exists)
     }
+
+    async fn list_members(&self, id: &ProjectId) -> Result<Vec<ProjectMember>, AppError> {
+        let rows: Vec<MemberRow> = sqlx::query_as(&format!(
+            "SELECT {MEMBER_COLUMNS} FROM project_members WHERE project_id = $1 ORDER BY invited_at, address"
+        ))
+        .bind(id.value())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(member_from_row).collect()
+    }
+
+    async fn find_member(
+        &self,
+        id: &ProjectId,
+        address: &WalletAddress,
+    ) -> Result<Option<ProjectMember>, AppError> {
+        let row: Option<MemberRow> = sqlx::query_as(&format!(
+            "SELECT {MEMBER_COLUMNS} FROM project_members WHERE project_id = $1 AND LOWER(address) = LOWER($2)"
+        ))
+        .bind(id.value())
+        .bind(address.as_str())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(member_from_row).transpose()
+    }
+
+    async fn add_member(&self, member: &ProjectMember) -> Result<(), AppError> {
+        let mut conn = self.pool.acquire().await?;
+        insert_member(&mut conn, member).await
+    }
+
+    async fn update_member(&self, member: &ProjectMember) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            UPDATE project_members
+            SET role = $3, status = $4, joined_at = $5
+            WHERE project_id = $1 AND LOWER(address) = LOWER($2)
+            "#,
+        )
+        .bind(member.project_id.value())
+        .bind(member.address.as_str())
+        .bind(member.role.as_str())
+        .bind(member.status.as_str())
+        .bind(member.joined_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn remove_member(&self, id: &ProjectId, address: &WalletAddress) -> Result<(), AppError> {
+        sqlx::query(
+            "DELETE FROM project_members WHERE project_id = $1 AND LOWER(address) = LOWER($2)",
+        )
+        .bind(id.value())
+        .bind(address.as_str())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn record_transition(
+        &self,
+        project: &Project,
+        transition: &ProjectTransition,
+    ) -> Result<(), AppError> {
+        let from = transition
+            .from
+            .ok_or_else(|| AppError::internal("A status change needs the status it leaves"))?;
+        let mut tx = self.pool.begin().await?;
+
+        let updated = sqlx::query(
+            "UPDATE projects SET status = $2, updated_at = $3 WHERE id = $1 AND status = $4",
+        )
+        .bind(project.id.value())
+        .bind(project.status.as_str())
+        .bind(project.updated_at)
+        .bind(from.as_str())
+        .execute(&mut *tx)
+        .await?;
+        if updated.rows_affected() == 0 {
+            let exists: Option<(i32,)> = sqlx::query_as("SELECT 1 FROM projects WHERE id = $1")
+                .bind(project.id.value())
+                .fetch_optional(&mut *tx)
+                .await?;
+            return Err(match exists {
+                Some(_) => AppError::conflict(format!(
+                    "Project {} is no longer {}",
+                    project.id.value(),
+                    from
+                )),
+                None => AppError::not_found("Project not found"),
+            });
+        }
+        insert_transition(&mut tx, transition).await?;
+        if transition.to == ProjectStatus::Proposal {
+            sqlx::query("DELETE FROM project_approvals WHERE project_id = $1")
+                .bind(project.id.value())
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn history(&self, id: &ProjectId) -> Result<Vec<ProjectTransition>, AppError> {
+        let rows: Vec<(
+            Uuid,
+            Uuid,
+            Option<String>,
+            String,
+            String,
+            Option<String>,
+            DateTime<Utc>,
+        )> = sqlx::query_as(
+            r#"
+            SELECT id, project_id, from_status, to_status, actor, note, created_at
+            FROM project_history
+            WHERE project_id = $1
+            ORDER BY created_at, seq
+            "#,
+        )
+        .bind(id.value())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|(id, project_id, from, to, actor, note, created_at)| {
+                Ok(ProjectTransition {
+                    id,
+                    project_id: ProjectId::from_uuid(project_id),
+                    from: from
+                        .map(|s| s.parse::<ProjectStatus>().map_err(AppError::internal))
+                        .transpose()?,
+                    to: to.parse::<ProjectStatus>().map_err(AppError::internal)?,
+                    actor: WalletAddress(actor),
+                    note,
+                    created_at,
+                })
+            })
+            .collect()
+    }
+
+    async fn add_approval(&self, approval: &ProjectApproval) -> Result<(), AppError> {
+        sqlx::query(
+            "INSERT INTO project_approvals (project_id, reviewer, approved_at) VALUES ($1, $2, $3)",
+        )
+        .bind(approval.project_id.value())
+        .bind(approval.reviewer.as_str())
+        .bind(approval.approved_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn list_approvals(&self, id: &ProjectId) -> Result<Vec<ProjectApproval>, AppError> {
+        let rows: Vec<(Uuid, String, DateTime<Utc>)> = sqlx::query_as(
+            r#"
+            SELECT project_id, reviewer, approved_at
+            FROM project_approvals
+            WHERE project_id = $1
+            ORDER BY approved_at, reviewer
+            "#,
+        )
+        .bind(id.value())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(project_id, reviewer, approved_at)| ProjectApproval {
+                project_id: ProjectId::from_uuid(project_id),
+                reviewer: WalletAddress(reviewer),
+                approved_at,
+            })
+            .collect())
+    }
+
+    async fn list_repos(&self, id: &ProjectId) -> Result<Vec<String>, AppError> {
+        let rows: Vec<(String,)> = sqlx::query_as(
+            "SELECT repo FROM project_repos WHERE project_id = $1 ORDER BY repo COLLATE \"C\"",
+        )
+        .bind(id.value())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|(repo,)| repo).collect())
+    }
+
+    async fn set_repos(&self, id: &ProjectId, repos: &[String]) -> Result<(), AppError> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM project_repos WHERE project_id = $1")
+            .bind(id.value())
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            "INSERT INTO project_repos (project_id, repo) SELECT $1, UNNEST($2::TEXT[]) ON CONFLICT DO NOTHING",
+        )
+        .bind(id.value())
+        .bind(repos)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
 }
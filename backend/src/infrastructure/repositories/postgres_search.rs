@@ -0,0 +1,31 @@
+use sqlx::{Postgres, QueryBuilder};
+
+use crate::domain::value_objects::SearchTerms;
+
+/// Turns the lexemes of `to_tsvector('simple', $terms)` into `'lexeme':* & ...`.
+/// Lexemes are quoted (backslashes and quotes escaped) and cast, so they are not
+/// parsed a second time.
+const PREFIX_TSQUERY: &str = r#"(
+    SELECT COALESCE(string_agg(
+        '''' || replace(replace(lexeme, '\', '\\'), '''', '''''') || ''':*', ' & '
+    ), '')::tsquery
+    FROM unnest(tsvector_to_array(to_tsvector('simple', "#;
+
+/// Appends ` AND <document> @@ <prefix tsquery>` for non-empty terms.
+///
+/// The terms go through the same `simple` parser as the document: it splits
+/// tokens such as `3e5abc` into `3e5` and `abc`, which a hand-built `3e5abc:*`
+/// would never match.
+pub(crate) fn push_prefix_match(
+    query: &mut QueryBuilder<'_, Postgres>,
+    document: &str,
+    terms: &SearchTerms,
+) {
+    if terms.is_empty() {
+        return;
+    }
+    query
+        .push(format!(" AND {document} @@ {PREFIX_TSQUERY}"))
+        .push_bind(terms.as_text())
+        .push("))) AS lexeme)");
+}
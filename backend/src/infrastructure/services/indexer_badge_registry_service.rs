@@ -0,0 +1,85 @@
+#[cfg(any(test, feature = "test-support"))]
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+
+use crate::domain::services::badge_registry_service::BadgeRegistryService;
+
+/// Looks badges up through the indexer API (`GET /badges/:name`).
+/// The base URL comes from INDEXER_API_URL.
+pub struct IndexerBadgeRegistryService {
+    client: reqwest::Client,
+    api_url: String,
+}
+
+impl Default for IndexerBadgeRegistryService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IndexerBadgeRegistryService {
+    pub fn new() -> Self {
+        let api_url = std::env::var("INDEXER_API_URL")
+            .unwrap_or_else(|_| "http://localhost:3002".to_string());
+
+        Self {
+            client: reqwest::Client::new(),
+            api_url,
+        }
+    }
+}
+
+#[async_trait]
+impl BadgeRegistryService for IndexerBadgeRegistryService {
+    async fn badge_exists(&self, name: &str) -> Result<bool, Box<dyn std::error::Error>> {
+        let mut url = reqwest::Url::parse(&self.api_url)?;
+        url.path_segments_mut()
+            .map_err(|_| "INDEXER_API_URL must be an http(s) URL")?
+            .pop_if_empty()
+            .push("badges")
+            .push(name);
+
+        let response = self.client.get(url).send().await?;
+
+        match response.status() {
+            reqwest::StatusCode::NOT_FOUND => Ok(false),
+            status if status.is_success() => Ok(true),
+            status => Err(format!("Indexer returned {status} for badge {name}").into()),
+        }
+    }
+}
+
+/// Badge registry backed by a fixed list of names, for tests and local runs.
+#[cfg(any(test, feature = "test-support"))]
+pub struct MockBadgeRegistryService {
+    names: HashSet<String>,
+}
+
+#[cfg(any(test, feature = "test-support"))]
+impl MockBadgeRegistryService {
+    pub fn new<I, S>(names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            names: names.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(any(test, feature = "test-support"))]
+impl Default for MockBadgeRegistryService {
+    fn default() -> Self {
+        Self::new(Vec::<String>::new())
+    }
+}
+
+#[cfg(any(test, feature = "test-support"))]
+#[async_trait]
+impl BadgeRegistryService for MockBadgeRegistryService {
+    async fn badge_exists(&self, name: &str) -> Result<bool, Box<dyn std::error::Error>> {
+        Ok(self.names.contains(name))
+    }
+}
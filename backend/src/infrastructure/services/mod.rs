@@ -1,2 +1,4 @@
 pub mod ethereum_address_verification_service;
+pub mod indexer_badge_registry_service;
 pub mod rest_github_service;
+pub mod rpc_mint_event_service;
@@ -0,0 +1,148 @@
+#[cfg(any(test, feature = "test-support"))]
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use ethers::providers::{Http, Middleware, Provider};
+use ethers::types::{Address, Log, H256, U256};
+use ethers::utils::{keccak256, to_checksum};
+
+use crate::domain::services::mint_event_service::{MintEvent, MintEventService, TransactionMints};
+use crate::domain::value_objects::TokenAmount;
+
+/// Reads mints from the transaction receipt through a JSON-RPC node.
+/// Configured with ETHEREUM_RPC_URL and CONTRIBUTION_TOKEN_ADDRESS (the TGC proxy).
+pub struct RpcMintEventService {
+    rpc_url: String,
+    token_address: Option<String>,
+}
+
+impl Default for RpcMintEventService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RpcMintEventService {
+    pub fn new() -> Self {
+        let rpc_url = std::env::var("ETHEREUM_RPC_URL")
+            .unwrap_or_else(|_| "http://localhost:8545".to_string());
+
+        Self {
+            rpc_url,
+            token_address: std::env::var("CONTRIBUTION_TOKEN_ADDRESS").ok(),
+        }
+    }
+}
+
+#[async_trait]
+impl MintEventService for RpcMintEventService {
+    async fn mints_in_transaction(
+        &self,
+        chain_id: i64,
+        tx_hash: &str,
+    ) -> Result<Option<TransactionMints>, Box<dyn std::error::Error>> {
+        let token: Address = self
+            .token_address
+            .as_deref()
+            .ok_or("CONTRIBUTION_TOKEN_ADDRESS is not set")?
+            .parse()?;
+        let provider = Provider::<Http>::try_from(self.rpc_url.as_str())?;
+
+        let provider_chain_id = provider.get_chainid().await?;
+        if provider_chain_id != U256::from(chain_id as u64) {
+            return Err(format!(
+                "ETHEREUM_RPC_URL serves chain {provider_chain_id}, distribution was sent on chain {chain_id}"
+            )
+            .into());
+        }
+
+        let hash: H256 = tx_hash.parse()?;
+        let Some(receipt) = provider.get_transaction_receipt(hash).await? else {
+            return Ok(None);
+        };
+        if receipt.block_number.is_none() {
+            return Ok(None);
+        }
+
+        Ok(Some(TransactionMints {
+            reverted: receipt.status == Some(0u64.into()),
+            mints: decode_mints(token, &receipt.logs),
+        }))
+    }
+}
+
+/// Extracts the token's mints from receipt logs. Each `Transfer` from the zero
+/// address is one mint; the `MintedWithReason` emitted right after it adds the
+/// reason hash. A `MintedWithReason` without a matching transfer still counts.
+pub fn decode_mints(token: Address, logs: &[Log]) -> Vec<MintEvent> {
+    let transfer = H256::from(keccak256("Transfer(address,address,uint256)"));
+    let minted_with_reason = H256::from(keccak256("MintedWithReason(address,uint256,bytes)"));
+
+    let mut mints: Vec<MintEvent> = Vec::new();
+    for log in logs.iter().filter(|log| log.address == token) {
+        let topics = &log.topics;
+        if topics.len() == 3 && topics[0] == transfer {
+            if Address::from(topics[1]) != Address::zero() {
+                continue;
+            }
+            mints.push(MintEvent {
+                recipient: to_checksum(&Address::from(topics[2]), None),
+                amount: TokenAmount(U256::from_big_endian(&log.data)),
+                reason_hash: None,
+            });
+        } else if topics.len() == 4 && topics[0] == minted_with_reason {
+            let recipient = to_checksum(&Address::from(topics[1]), None);
+            let amount = TokenAmount(U256::from_big_endian(topics[2].as_bytes()));
+            let reason_hash = Some(format!("{:#x}", topics[3]));
+
+            match mints.last_mut() {
+                Some(last)
+                    if last.reason_hash.is_none()
+                        && last.recipient == recipient
+                        && last.amount == amount =>
+                {
+                    last.reason_hash = reason_hash;
+                }
+                _ => mints.push(MintEvent {
+                    recipient,
+                    amount,
+                    reason_hash,
+                }),
+            }
+        }
+    }
+    mints
+}
+
+/// Mint source backed by fixed transactions, for tests and local runs.
+/// Unknown transactions are reported as not mined yet.
+#[cfg(any(test, feature = "test-support"))]
+#[derive(Default)]
+pub struct MockMintEventService {
+    transactions: HashMap<String, TransactionMints>,
+}
+
+#[cfg(any(test, feature = "test-support"))]
+impl MockMintEventService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_transaction(mut self, tx_hash: impl Into<String>, mints: TransactionMints) -> Self {
+        self.transactions
+            .insert(tx_hash.into().to_lowercase(), mints);
+        self
+    }
+}
+
+#[cfg(any(test, feature = "test-support"))]
+#[async_trait]
+impl MintEventService for MockMintEventService {
+    async fn mints_in_transaction(
+        &self,
+        _chain_id: i64,
+        tx_hash: &str,
+    ) -> Result<Option<TransactionMints>, Box<dyn std::error::Error>> {
+        Ok(self.transactions.get(&tx_hash.to_lowercase()).cloned())
+    }
+}
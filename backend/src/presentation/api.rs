@@ -1,10 +1,14 @@
+use std::collections::BTreeSet;
 use std::sync::Arc;
 
+use crate::domain::entities::projects::ReviewPolicy;
 use crate::domain::repositories::{
     DistributionRepository, GithubIssueRepository, ProfileRepository, ProjectRepository,
 };
 use crate::domain::services::auth_service::AuthService;
+use crate::domain::services::badge_registry_service::BadgeRegistryService;
 use crate::domain::services::github_service::GithubService;
+use crate::domain::services::mint_event_service::MintEventService;
 use crate::infrastructure::{
     repositories::{
         postgres_distribution_repository::PostgresDistributionRepository,
@@ -12,13 +16,15 @@ use crate::infrastructure::{
         postgres_project_repository::PostgresProjectRepository, PostgresProfileRepository,
     },
     services::ethereum_address_verification_service::EthereumAddressVerificationService,
+    services::indexer_badge_registry_service::IndexerBadgeRegistryService,
     services::rest_github_service::RestGithubService,
+    services::rpc_mint_event_service::RpcMintEventService,
 };
 use axum::middleware::{from_fn, from_fn_with_state};
 use axum::{
     extract::DefaultBodyLimit,
     http::Method,
-    routing::{delete, get, patch, post, put},
+    routing::{delete, get, patch, post, put, MethodRouter},
     Router,
 };
 use tower::ServiceBuilder;
@@ -28,123 +34,273 @@ use tower_http::{
 };
 
 use super::handlers::{
+    accept_project_invitation_handler,
     // Admin handlers
     admin_delete_profile_handler,
+    approve_project_handler,
+    confirm_distribution_handler,
     // Profile handlers
     create_profile_handler,
     // Project handlers
     create_project_handler,
     delete_profile_handler,
     delete_project_handler,
+    export_distribution_handler,
+    fail_distribution_handler,
     get_all_profiles_handler,
+    get_distribution_handler,
     get_nonce_handler,
     get_profile_handler,
+    get_project_approvals_handler,
     get_project_handler,
+    get_project_history_handler,
+    get_project_issues_handler,
+    get_project_repos_handler,
+    get_project_stats_handler,
     get_user_projects_handler,
     // GitHub sync handler
     github_sync_handler,
+    invite_project_member_handler,
+    leave_project_handler,
+    legacy_list_profiles_handler,
+    legacy_list_projects_handler,
     list_distributions_handler,
     list_github_issues_handler,
+    list_project_members_handler,
     list_projects_handler,
     login_handler,
+    reconcile_distribution_handler,
+    reconcile_submitted_distributions_handler,
     register_distribution_handler,
+    set_project_repos_handler,
+    submit_distribution_handler,
+    transition_project_handler,
     update_profile_handler,
     update_project_handler,
 };
 
-use super::middlewares::{admin_auth_layer, eth_auth_layer, test_auth_layer};
+use super::middlewares::{admin_auth_layer, deprecation_layer, eth_auth_layer, test_auth_layer};
+use super::openapi::{openapi_handler, swagger_ui_handler};
+
+/// How requests to protected and admin routes are authenticated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthStrategy {
+    /// SIWE signature verification; admin routes also check ADMIN_ADDRESSES.
+    Signature,
+    /// Trusts the `x-eth-address` header without verifying a signature (tests only).
+    TestHeader,
+}
+
+impl AuthStrategy {
+    /// `TestHeader` when TEST_MODE is set, `Signature` otherwise.
+    pub fn from_env() -> Self {
+        if std::env::var("TEST_MODE").is_ok() {
+            AuthStrategy::TestHeader
+        } else {
+            AuthStrategy::Signature
+        }
+    }
+}
 
 pub async fn create_app(pool: sqlx::PgPool) -> Router {
-    let profile_repository = Arc::from(PostgresProfileRepository::new(pool.clone()));
-    let project_repository = Arc::from(PostgresProjectRepository::new(pool.clone()));
-    let distribution_repository = Arc::from(PostgresDistributionRepository::new(pool.clone()));
-    let github_issue_repository = Arc::from(PostgresGithubIssueRepository::new(pool));
-    let auth_service = EthereumAddressVerificationService::new(profile_repository.clone());
-    let github_service: Arc<dyn GithubService> = Arc::from(RestGithubService::new());
-
-    let state: AppState = AppState {
-        profile_repository,
-        project_repository,
-        distribution_repository,
-        auth_service: Arc::from(auth_service),
-        github_issue_repository,
-        github_service,
-    };
+    build_app(AppState::postgres(pool), AuthStrategy::from_env())
+}
+
+/// Builds the full route table. Production and integration tests both go through
+/// here, differing only in the injected dependencies and auth strategy.
+pub fn build_app(state: AppState, auth: AuthStrategy) -> Router {
+    with_versioning(&state, auth).with_state(state).layer(
+        ServiceBuilder::new()
+            .layer(TraceLayer::new_for_http())
+            .layer(
+                CorsLayer::new()
+                    .allow_origin(Any)
+                    .allow_methods([
+                        Method::GET,
+                        Method::POST,
+                        Method::PUT,
+                        Method::PATCH,
+                        Method::DELETE,
+                    ])
+                    .allow_headers(Any),
+            )
+            .layer(DefaultBodyLimit::max(1024 * 1024)),
+    )
+}
+
+/// Which contract a route table serves: `/v1`, or the deprecated root aliases
+/// that keep the response shapes they had before versioning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ApiVersion {
+    V1,
+    Legacy,
+}
+
+/// A route table: paths in axum syntax with the methods served on them.
+type Routes = Vec<(&'static str, MethodRouter<AppState>)>;
 
-    // Protected routes (require authentication)
-    let protected_routes = Router::new()
+/// Routes that require authentication.
+fn protected_routes() -> Routes {
+    vec![
         // Profile protected routes
-        .route("/profiles", post(create_profile_handler))
-        .route("/profiles/", post(create_profile_handler))
-        .route("/profiles/:address", put(update_profile_handler))
-        .route("/profiles/:address", delete(delete_profile_handler))
-        .route("/auth/login", post(login_handler))
+        ("/profiles", post(create_profile_handler)),
+        ("/profiles/", post(create_profile_handler)),
+        ("/profiles/:address", put(update_profile_handler)),
+        ("/profiles/:address", delete(delete_profile_handler)),
+        ("/auth/login", post(login_handler)),
         // Project protected routes
-        .route("/projects", post(create_project_handler))
-        .route("/projects/:id", patch(update_project_handler))
-        .route("/projects/:id", delete(delete_project_handler))
-        .with_state(state.clone());
-
-    let protected_with_auth = if std::env::var("TEST_MODE").is_ok() {
-        protected_routes.layer(from_fn(test_auth_layer))
-    } else {
-        protected_routes.layer(from_fn_with_state(state.clone(), eth_auth_layer))
-    };
+        ("/projects", post(create_project_handler)),
+        ("/projects/:id", patch(update_project_handler)),
+        ("/projects/:id", delete(delete_project_handler)),
+        ("/projects/:id/members", post(invite_project_member_handler)),
+        (
+            "/projects/:id/members/accept",
+            post(accept_project_invitation_handler),
+        ),
+        ("/projects/:id/members/leave", post(leave_project_handler)),
+        (
+            "/projects/:id/transitions",
+            post(transition_project_handler),
+        ),
+        ("/projects/:id/approvals", post(approve_project_handler)),
+        ("/projects/:id/repos", put(set_project_repos_handler)),
+    ]
+}
 
-    // Admin routes (require admin authentication via SIWE with admin wallet)
-    let admin_routes = Router::new()
-        .route(
+/// Routes that require admin authentication via SIWE with an admin wallet.
+fn admin_routes() -> Routes {
+    vec![
+        (
             "/admin/profiles/:address",
             delete(admin_delete_profile_handler),
-        )
-        .route("/admin/github/sync", post(github_sync_handler))
-        .route("/admin/distributions", post(register_distribution_handler))
-        .route("/admin/distributions", get(list_distributions_handler))
-        .with_state(state.clone());
-
-    let admin_with_auth = if std::env::var("TEST_MODE").is_ok() {
-        // In test mode, still check x-eth-address header but skip signature verification
-        admin_routes.layer(from_fn(test_auth_layer))
-    } else {
-        admin_routes.layer(from_fn_with_state(state.clone(), admin_auth_layer))
-    };
+        ),
+        ("/admin/github/sync", post(github_sync_handler)),
+        ("/admin/distributions", post(register_distribution_handler)),
+        ("/admin/distributions", get(list_distributions_handler)),
+        ("/admin/distributions/:id", get(get_distribution_handler)),
+        (
+            "/admin/distributions/:id/submit",
+            post(submit_distribution_handler),
+        ),
+        (
+            "/admin/distributions/:id/confirm",
+            post(confirm_distribution_handler),
+        ),
+        (
+            "/admin/distributions/:id/fail",
+            post(fail_distribution_handler),
+        ),
+        (
+            "/admin/distributions/reconcile",
+            post(reconcile_submitted_distributions_handler),
+        ),
+        (
+            "/admin/distributions/:id/reconcile",
+            post(reconcile_distribution_handler),
+        ),
+        (
+            "/admin/distributions/:id/export",
+            get(export_distribution_handler),
+        ),
+    ]
+}
 
-    // Public routes (no authentication)
-    let public_routes = Router::new()
+/// Routes without authentication.
+fn public_routes(version: ApiVersion) -> Routes {
+    vec![
         // Profile public routes
-        .route("/profiles/:address", get(get_profile_handler))
-        .route("/profiles", get(get_all_profiles_handler))
-        .route("/auth/nonce/:address", get(get_nonce_handler))
+        ("/profiles/:address", get(get_profile_handler)),
+        (
+            "/profiles",
+            match version {
+                ApiVersion::V1 => get(get_all_profiles_handler),
+                ApiVersion::Legacy => get(legacy_list_profiles_handler),
+            },
+        ),
+        ("/auth/nonce/:address", get(get_nonce_handler)),
         // Project public routes
-        .route("/projects", get(list_projects_handler))
-        .route("/projects/:id", get(get_project_handler))
-        .route("/users/:address/projects", get(get_user_projects_handler))
+        (
+            "/projects",
+            match version {
+                ApiVersion::V1 => get(list_projects_handler),
+                ApiVersion::Legacy => get(legacy_list_projects_handler),
+            },
+        ),
+        ("/projects/:id", get(get_project_handler)),
+        ("/projects/:id/members", get(list_project_members_handler)),
+        ("/projects/:id/history", get(get_project_history_handler)),
+        (
+            "/projects/:id/approvals",
+            get(get_project_approvals_handler),
+        ),
+        ("/projects/:id/repos", get(get_project_repos_handler)),
+        ("/projects/:id/issues", get(get_project_issues_handler)),
+        ("/projects/:id/stats", get(get_project_stats_handler)),
+        ("/users/:address/projects", get(get_user_projects_handler)),
         // GitHub issues public route
-        .route("/github/issues", get(list_github_issues_handler))
-        .with_state(state.clone());
+        ("/github/issues", get(list_github_issues_handler)),
+    ]
+}
+
+/// Every path the API serves, in axum syntax and relative to its version
+/// prefix. Tests check it against the OpenAPI document.
+pub fn api_paths() -> BTreeSet<&'static str> {
+    [
+        protected_routes(),
+        admin_routes(),
+        public_routes(ApiVersion::V1),
+        public_routes(ApiVersion::Legacy),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|(path, _)| path)
+    .collect()
+}
+
+fn router(routes: Routes, state: &AppState) -> Router<AppState> {
+    routes
+        .into_iter()
+        .fold(Router::new(), |router, (path, methods)| {
+            router.route(path, methods)
+        })
+        .with_state(state.clone())
+}
+
+fn api_routes(state: &AppState, auth: AuthStrategy, version: ApiVersion) -> Router<AppState> {
+    let protected_routes = router(protected_routes(), state);
+    let protected_with_auth = match auth {
+        AuthStrategy::Signature => {
+            protected_routes.layer(from_fn_with_state(state.clone(), eth_auth_layer))
+        }
+        AuthStrategy::TestHeader => protected_routes.layer(from_fn(test_auth_layer)),
+    };
+
+    let admin_routes = router(admin_routes(), state);
+    let admin_with_auth = match auth {
+        AuthStrategy::Signature => {
+            admin_routes.layer(from_fn_with_state(state.clone(), admin_auth_layer))
+        }
+        // Still check x-eth-address header but skip signature verification
+        AuthStrategy::TestHeader => admin_routes.layer(from_fn(test_auth_layer)),
+    };
 
     Router::new()
         .merge(protected_with_auth)
         .merge(admin_with_auth)
-        .merge(public_routes)
-        .with_state(state.clone())
-        .layer(
-            ServiceBuilder::new()
-                .layer(TraceLayer::new_for_http())
-                .layer(
-                    CorsLayer::new()
-                        .allow_origin(Any)
-                        .allow_methods([
-                            Method::GET,
-                            Method::POST,
-                            Method::PUT,
-                            Method::PATCH,
-                            Method::DELETE,
-                        ])
-                        .allow_headers(Any),
-                )
-                .layer(DefaultBodyLimit::max(1024 * 1024)),
-        )
+        .merge(router(public_routes(version), state))
+}
+
+/// Prefix under which the current API version is mounted.
+pub const API_V1_PREFIX: &str = "/v1";
+
+/// Mounts the API under `/v1`, keeps the original root paths as deprecated
+/// aliases and adds the unversioned documentation routes.
+fn with_versioning(state: &AppState, auth: AuthStrategy) -> Router<AppState> {
+    Router::new()
+        .nest(API_V1_PREFIX, api_routes(state, auth, ApiVersion::V1))
+        .merge(api_routes(state, auth, ApiVersion::Legacy).layer(from_fn(deprecation_layer)))
+        .route("/openapi.json", get(openapi_handler))
+        .route("/docs", get(swagger_ui_handler))
 }
 
 #[derive(Clone)]
@@ -155,69 +311,27 @@ pub struct AppState {
     pub auth_service: Arc<dyn AuthService>,
     pub github_issue_repository: Arc<dyn GithubIssueRepository>,
     pub github_service: Arc<dyn GithubService>,
+    pub badge_registry: Arc<dyn BadgeRegistryService>,
+    pub mint_event_service: Arc<dyn MintEventService>,
+    pub review_policy: ReviewPolicy,
 }
 
-pub fn test_api(state: AppState) -> Router {
-    // Protected routes (require authentication)
-    let protected_routes = Router::new()
-        // Profile protected routes
-        .route("/profiles", post(create_profile_handler))
-        .route("/profiles/:address", put(update_profile_handler))
-        .route("/profiles/:address", delete(delete_profile_handler))
-        .route("/auth/login", post(login_handler))
-        // Project protected routes
-        .route("/projects", post(create_project_handler))
-        .route("/projects/:id", patch(update_project_handler))
-        .route("/projects/:id", delete(delete_project_handler))
-        .with_state(state.clone())
-        .layer(from_fn(test_auth_layer));
+impl AppState {
+    /// Postgres-backed repositories with the real signature verifier and GitHub client.
+    pub fn postgres(pool: sqlx::PgPool) -> Self {
+        let profile_repository = Arc::new(PostgresProfileRepository::new(pool.clone()));
+        let auth_service = EthereumAddressVerificationService::new(profile_repository.clone());
 
-    // Admin routes (require admin authentication)
-    let admin_routes = Router::new()
-        .route(
-            "/admin/profiles/:address",
-            delete(admin_delete_profile_handler),
-        )
-        .route("/admin/github/sync", post(github_sync_handler))
-        .route("/admin/distributions", post(register_distribution_handler))
-        .route("/admin/distributions", get(list_distributions_handler))
-        .with_state(state.clone())
-        .layer(from_fn(test_auth_layer));
-
-    // Public routes (no authentication)
-    let public_routes = Router::new()
-        // Profile public routes
-        .route("/profiles/:address", get(get_profile_handler))
-        .route("/profiles", get(get_all_profiles_handler))
-        .route("/auth/nonce/:address", get(get_nonce_handler))
-        // Project public routes
-        .route("/projects", get(list_projects_handler))
-        .route("/projects/:id", get(get_project_handler))
-        .route("/users/:address/projects", get(get_user_projects_handler))
-        // GitHub issues public route
-        .route("/github/issues", get(list_github_issues_handler))
-        .with_state(state.clone());
-
-    Router::new()
-        .merge(protected_routes)
-        .merge(admin_routes)
-        .merge(public_routes)
-        .with_state(state.clone())
-        .layer(
-            ServiceBuilder::new()
-                .layer(TraceLayer::new_for_http())
-                .layer(
-                    CorsLayer::new()
-                        .allow_origin(Any)
-                        .allow_methods([
-                            Method::GET,
-                            Method::POST,
-                            Method::PUT,
-                            Method::PATCH,
-                            Method::DELETE,
-                        ])
-                        .allow_headers(Any),
-                )
-                .layer(DefaultBodyLimit::max(1024 * 1024)),
-        )
+        AppState {
+            profile_repository,
+            project_repository: Arc::new(PostgresProjectRepository::new(pool.clone())),
+            distribution_repository: Arc::new(PostgresDistributionRepository::new(pool.clone())),
+            auth_service: Arc::new(auth_service),
+            github_issue_repository: Arc::new(PostgresGithubIssueRepository::new(pool)),
+            github_service: Arc::new(RestGithubService::new()),
+            badge_registry: Arc::new(IndexerBadgeRegistryService::new()),
+            mint_event_service: Arc::new(RpcMintEventService::new()),
+            review_policy: ReviewPolicy::from_env(),
+        }
+    }
 }
@@ -1,45 +1,74 @@
 use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
-    response::IntoResponse,
-    Extension, Json,
+    extract::State,
+    http::{header, HeaderName, StatusCode},
+    response::{IntoResponse, Response},
+    Extension,
 };
 use serde::Deserialize;
+use utoipa::IntoParams;
 
 // Profile imports
 use crate::{
     application::{
         commands::{create_profile::create_profile, login::login, update_profile::update_profile},
         dtos::{
-            AuthTokenResponse, CreateProfileRequest, NonceResponse, ProfileResponse,
-            UpdateProfileRequest,
+            AuthTokenResponse, CreateProfileRequest, ListProfilesQuery, NonceResponse,
+            ProfilePageResponse, ProfileResponse, UpdateProfileRequest,
         },
         queries::{
-            get_all_profiles::get_all_profiles, get_login_nonce::get_login_nonce,
+            get_all_profiles::{get_all_profiles, get_all_profiles_unpaged},
+            get_login_nonce::get_login_nonce,
             get_profile::get_profile,
         },
     },
-    domain::value_objects::WalletAddress,
+    domain::{errors::AppError, value_objects::WalletAddress},
 };
 
 // Project imports
 use crate::application::{
     commands::{
+        accept_project_invitation::accept_project_invitation, approve_project::approve_project,
         create_project::create_project, delete_project::delete_project,
+        invite_project_member::invite_project_member, leave_project::leave_project,
+        set_project_repos::set_project_repos, transition_project::transition_project,
         update_project::update_project,
     },
-    dtos::project_dtos::{CreateProjectRequest, UpdateProjectRequest},
+    dtos::project_dtos::{
+        CreateProjectRequest, InviteMemberRequest, ListProjectsQuery, ProjectIssuesQuery,
+        ProjectRepos, ProjectResponse, TransitionProjectRequest, UpdateProjectRequest,
+    },
     queries::{
-        get_all_projects::get_all_projects, get_project::get_project,
+        get_all_projects::{get_all_projects, get_all_projects_unpaged},
+        get_project::get_project,
+        get_project_approvals::get_project_approvals,
+        get_project_history::get_project_history,
+        get_project_issues::get_project_issues,
+        get_project_members::get_project_members,
+        get_project_repos::get_project_repos,
+        get_project_stats::get_project_stats,
         get_projects_by_creator::get_projects_by_creator,
     },
 };
 
 // Distribution imports
 use crate::application::{
-    commands::register_distribution::register_distribution,
-    dtos::distribution_dtos::RegisterDistributionRequest,
-    queries::list_distributions::list_distributions,
+    commands::{
+        confirm_distribution::confirm_distribution,
+        fail_distribution::fail_distribution,
+        reconcile_distribution::{reconcile_distribution, reconcile_submitted_distributions},
+        register_distribution::register_distribution,
+        submit_distribution::submit_distribution,
+    },
+    dtos::distribution_dtos::{
+        DistributionDetailResponse, DistributionReconciliationReport, RegisterDistributionRequest,
+        SubmitDistributionRequest,
+    },
+    queries::{
+        export_distribution::{export_distribution, ExportFormat},
+        get_distribution::get_distribution,
+        list_distributions::list_distributions,
+        validate_distribution::validate_distribution,
+    },
 };
 
 // GitHub sync imports
@@ -48,228 +77,607 @@ use crate::application::{
     dtos::github_dtos::{GithubIssuesQuery, GithubSyncRequest, GithubSyncResponse},
 };
 
-use super::{api::AppState, middlewares::VerifiedWallet};
+use super::{
+    api::AppState,
+    extractors::{Json, Path, Query},
+    middlewares::VerifiedWallet,
+};
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct RegisterDistributionQuery {
+    /// Validate and return a per-line report without persisting anything
+    #[serde(rename = "dryRun", default)]
+    pub dry_run: bool,
+}
 
-/// Query parameters for listing projects
-#[derive(Debug, Deserialize)]
-pub struct ListProjectsQuery {
-    pub status: Option<String>,
-    pub creator: Option<String>,
-    pub limit: Option<i64>,
-    pub offset: Option<i64>,
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ExportDistributionQuery {
+    /// attestations, mints or csv
+    #[param(inline)]
+    pub format: ExportFormat,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
 pub struct ListDistributionsQuery {
     #[serde(rename = "distributionId")]
     pub distribution_id: Option<String>,
 }
 
+#[utoipa::path(
+    post,
+    path = "/profiles",
+    tag = "profiles",
+    request_body = CreateProfileRequest,
+    responses(
+        (status = 201, description = "Profile created", body = ProfileResponse),
+        (status = 400, description = "Invalid input", body = ErrorBody),
+        (status = 409, description = "Profile or handle already exists", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
 pub async fn create_profile_handler(
     State(state): State<AppState>,
     Extension(VerifiedWallet(wallet)): Extension<VerifiedWallet>,
     Json(payload): Json<CreateProfileRequest>,
-) -> impl axum::response::IntoResponse {
-    match create_profile(state.profile_repository, wallet, payload).await {
-        Ok(profile) => (StatusCode::CREATED, Json(profile)).into_response(),
-        Err(e) => (
-            axum::http::StatusCode::BAD_REQUEST,
-            axum::Json(serde_json::json!({"error": e})),
-        )
-            .into_response(),
-    }
+) -> Result<impl IntoResponse, AppError> {
+    let profile = create_profile(state.profile_repository, wallet, payload).await?;
+    Ok((StatusCode::CREATED, Json(profile)))
 }
 
+#[utoipa::path(
+    get,
+    path = "/profiles/{address}",
+    tag = "profiles",
+    params(("address" = String, Path, description = "Wallet address")),
+    responses(
+        (status = 200, description = "Profile found", body = ProfileResponse),
+        (status = 404, description = "Profile not found", body = ErrorBody)
+    )
+)]
 pub async fn get_profile_handler(
     State(state): State<AppState>,
     Path(address): Path<String>,
-) -> impl IntoResponse {
-    match get_profile(state.profile_repository, address).await {
-        Ok(profile) => Json(profile).into_response(),
-        Err(e) => (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": e}))).into_response(),
-    }
+) -> Result<Json<ProfileResponse>, AppError> {
+    Ok(Json(get_profile(state.profile_repository, address).await?))
+}
+
+#[utoipa::path(
+    get,
+    path = "/profiles",
+    tag = "profiles",
+    params(ListProfilesQuery),
+    responses(
+        (status = 200, description = "One page of matching profiles", body = ProfilePageResponse),
+        (status = 400, description = "Invalid sort, limit or cursor", body = ErrorBody)
+    )
+)]
+pub async fn get_all_profiles_handler(
+    State(state): State<AppState>,
+    Query(params): Query<ListProfilesQuery>,
+) -> Result<Json<ProfilePageResponse>, AppError> {
+    Ok(Json(
+        get_all_profiles(state.profile_repository, params).await?,
+    ))
 }
 
-pub async fn get_all_profiles_handler(State(state): State<AppState>) -> Json<Vec<ProfileResponse>> {
-    Json(get_all_profiles(state.profile_repository).await.unwrap())
+/// GET /profiles on the deprecated root alias: every matching profile as a bare
+/// array, the shape it had before `/v1`.
+pub async fn legacy_list_profiles_handler(
+    State(state): State<AppState>,
+    Query(params): Query<ListProfilesQuery>,
+) -> Result<Json<Vec<ProfileResponse>>, AppError> {
+    Ok(Json(
+        get_all_profiles_unpaged(state.profile_repository, params).await?,
+    ))
 }
 
+#[utoipa::path(
+    put,
+    path = "/profiles/{address}",
+    tag = "profiles",
+    params(("address" = String, Path, description = "Wallet address")),
+    request_body = UpdateProfileRequest,
+    responses(
+        (status = 200, description = "Profile updated", body = ProfileResponse),
+        (status = 400, description = "Invalid input", body = ErrorBody),
+        (status = 404, description = "Profile not found", body = ErrorBody),
+        (status = 409, description = "Handle already taken", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
 pub async fn update_profile_handler(
     State(state): State<AppState>,
     Extension(VerifiedWallet(wallet)): Extension<VerifiedWallet>,
     Json(payload): Json<UpdateProfileRequest>,
-) -> impl axum::response::IntoResponse {
-    match update_profile(state.profile_repository, wallet, payload).await {
-        Ok(profile) => (StatusCode::OK, axum::Json(profile)).into_response(),
-        Err(e) => {
-            let status = if e.contains("already taken") {
-                axum::http::StatusCode::CONFLICT
-            } else {
-                axum::http::StatusCode::BAD_REQUEST
-            };
-            (status, axum::Json(serde_json::json!({"error": e}))).into_response()
-        }
-    }
+) -> Result<Json<ProfileResponse>, AppError> {
+    Ok(Json(
+        update_profile(state.profile_repository, wallet, payload).await?,
+    ))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/profiles/{address}",
+    tag = "profiles",
+    params(("address" = String, Path, description = "Wallet address")),
+    responses((status = 202, description = "Profile deleted")),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
 pub async fn delete_profile_handler(
     State(state): State<AppState>,
     Extension(VerifiedWallet(wallet)): Extension<VerifiedWallet>,
-) -> StatusCode {
+) -> Result<StatusCode, AppError> {
     state
         .profile_repository
         .delete(&WalletAddress(wallet))
-        .await
-        .unwrap();
-    StatusCode::ACCEPTED
+        .await?;
+    Ok(StatusCode::ACCEPTED)
 }
 
+#[utoipa::path(
+    get,
+    path = "/auth/nonce/{address}",
+    tag = "auth",
+    params(("address" = String, Path, description = "Wallet address")),
+    responses((status = 200, description = "Current login nonce", body = NonceResponse))
+)]
 pub async fn get_nonce_handler(
     State(state): State<AppState>,
     Path(address): Path<String>,
-) -> impl IntoResponse {
-    match get_login_nonce(state.profile_repository, address.clone()).await {
-        Ok(nonce) => Json(NonceResponse { nonce, address }).into_response(),
-        Err(e) => (StatusCode::NOT_FOUND, Json(serde_json::json!({"error": e}))).into_response(),
-    }
+) -> Result<Json<NonceResponse>, AppError> {
+    let nonce = get_login_nonce(state.profile_repository, address.clone()).await?;
+    Ok(Json(NonceResponse { nonce, address }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/auth/login",
+    tag = "auth",
+    responses((status = 200, description = "JWT issued", body = AuthTokenResponse)),
+    security(("eth_signature" = []))
+)]
 pub async fn login_handler(
     Extension(VerifiedWallet(address)): Extension<VerifiedWallet>,
-) -> impl IntoResponse {
-    match login(address.clone()).await {
-        Ok(token) => (StatusCode::OK, Json(AuthTokenResponse { token, address })).into_response(),
-        Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({"error": e})),
-        )
-            .into_response(),
-    }
+) -> Result<Json<AuthTokenResponse>, AppError> {
+    let token = login(address.clone()).await?;
+    Ok(Json(AuthTokenResponse { token, address }))
 }
 
 /// GET /projects - List all projects with optional filters
+#[utoipa::path(
+    get,
+    path = "/projects",
+    tag = "projects",
+    params(ListProjectsQuery),
+    responses(
+        (status = 200, description = "One page of matching projects", body = ProjectPageResponse),
+        (status = 400, description = "Invalid filter, sort, limit or cursor", body = ErrorBody)
+    )
+)]
 pub async fn list_projects_handler(
     State(state): State<AppState>,
     Query(params): Query<ListProjectsQuery>,
-) -> impl IntoResponse {
-    match get_all_projects(
-        state.project_repository.clone(),
-        params.status,
-        params.creator,
-        params.limit,
-        params.offset,
-    )
-    .await
-    {
-        Ok(projects) => (StatusCode::OK, Json(projects)).into_response(),
-        Err(e) => {
-            let status = if e.contains("Invalid status") {
-                StatusCode::BAD_REQUEST
-            } else {
-                StatusCode::INTERNAL_SERVER_ERROR
-            };
-            (status, Json(serde_json::json!({"error": e}))).into_response()
-        }
-    }
+) -> Result<impl IntoResponse, AppError> {
+    let projects = get_all_projects(state.project_repository.clone(), params).await?;
+    Ok((StatusCode::OK, Json(projects)))
+}
+
+/// GET /projects on the deprecated root alias: every matching project as a bare
+/// array, the shape it had before `/v1`.
+pub async fn legacy_list_projects_handler(
+    State(state): State<AppState>,
+    Query(params): Query<ListProjectsQuery>,
+) -> Result<Json<Vec<ProjectResponse>>, AppError> {
+    Ok(Json(
+        get_all_projects_unpaged(state.project_repository.clone(), params).await?,
+    ))
 }
 
 // GET /projects/:id - Get a single project by ID
+#[utoipa::path(
+    get,
+    path = "/projects/{id}",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    responses(
+        (status = 200, description = "Project found", body = ProjectResponse),
+        (status = 404, description = "Project not found", body = ErrorBody)
+    )
+)]
 pub async fn get_project_handler(
     State(state): State<AppState>,
     Path(id): Path<String>,
-) -> impl IntoResponse {
-    match get_project(state.project_repository.clone(), id).await {
-        Ok(project) => (StatusCode::OK, Json(project)).into_response(),
-        Err(e) => {
-            let status = if e.contains("not found") {
-                StatusCode::NOT_FOUND
-            } else {
-                StatusCode::BAD_REQUEST
-            };
-            (status, Json(serde_json::json!({"error": e}))).into_response()
-        }
-    }
+) -> Result<impl IntoResponse, AppError> {
+    let project = get_project(state.project_repository.clone(), id).await?;
+    Ok((StatusCode::OK, Json(project)))
 }
 
 /// GET /users/:address/projects - Get all projects by a creator
+#[utoipa::path(
+    get,
+    path = "/users/{address}/projects",
+    tag = "projects",
+    params(("address" = String, Path, description = "Creator wallet address")),
+    responses((status = 200, description = "Projects created by the user", body = [ProjectResponse]))
+)]
 pub async fn get_user_projects_handler(
     State(state): State<AppState>,
     Path(address): Path<String>,
-) -> impl IntoResponse {
-    match get_projects_by_creator(state.project_repository.clone(), address).await {
-        Ok(projects) => (StatusCode::OK, Json(projects)).into_response(),
-        Err(e) => (
-            StatusCode::BAD_REQUEST,
-            Json(serde_json::json!({"error": e})),
-        )
-            .into_response(),
-    }
+) -> Result<impl IntoResponse, AppError> {
+    let projects = get_projects_by_creator(state.project_repository.clone(), address).await?;
+    Ok((StatusCode::OK, Json(projects)))
 }
 
 /// POST /projects - Create a new project (Protected)
+#[utoipa::path(
+    post,
+    path = "/projects",
+    tag = "projects",
+    request_body = CreateProjectRequest,
+    responses(
+        (status = 201, description = "Project created", body = ProjectResponse),
+        (status = 400, description = "Invalid input", body = ErrorBody),
+        (status = 403, description = "Creator has no profile", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
 pub async fn create_project_handler(
     State(state): State<AppState>,
     Extension(VerifiedWallet(verified_wallet)): Extension<VerifiedWallet>,
     Json(request): Json<CreateProjectRequest>,
-) -> impl IntoResponse {
-    match create_project(state.project_repository.clone(), verified_wallet, request).await {
-        Ok(project) => (StatusCode::CREATED, Json(project)).into_response(),
-        Err(e) => (
-            StatusCode::BAD_REQUEST,
-            Json(serde_json::json!({"error": e})),
-        )
-            .into_response(),
-    }
+) -> Result<impl IntoResponse, AppError> {
+    let project =
+        create_project(state.project_repository.clone(), verified_wallet, request).await?;
+    Ok((StatusCode::CREATED, Json(project)))
 }
 
-/// PATCH /projects/:id - Update a project (Protected, creator only)
+/// PATCH /projects/:id - Update a project (Protected, maintainers only)
+#[utoipa::path(
+    patch,
+    path = "/projects/{id}",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    request_body = UpdateProjectRequest,
+    responses(
+        (status = 200, description = "Project updated", body = ProjectResponse),
+        (status = 403, description = "Not a maintainer", body = ErrorBody),
+        (status = 404, description = "Project not found", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
 pub async fn update_project_handler(
     State(state): State<AppState>,
     Extension(VerifiedWallet(verified_wallet)): Extension<VerifiedWallet>,
     Path(id): Path<String>,
     Json(request): Json<UpdateProjectRequest>,
-) -> impl IntoResponse {
-    match update_project(
+) -> Result<impl IntoResponse, AppError> {
+    let project = update_project(
         state.project_repository.clone(),
         verified_wallet,
         id,
         request,
     )
-    .await
-    {
-        Ok(project) => (StatusCode::OK, Json(project)).into_response(),
-        Err(e) => {
-            let status = if e.contains("not found") {
-                StatusCode::NOT_FOUND
-            } else if e.contains("Only the creator") {
-                StatusCode::FORBIDDEN
-            } else {
-                StatusCode::BAD_REQUEST
-            };
-            (status, Json(serde_json::json!({"error": e}))).into_response()
-        }
-    }
+    .await?;
+    Ok((StatusCode::OK, Json(project)))
 }
 
-/// DELETE /projects/:id - Delete a project (Protected, creator only)
+/// DELETE /projects/:id - Delete a project (Protected, maintainers only)
+#[utoipa::path(
+    delete,
+    path = "/projects/{id}",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    responses(
+        (status = 204, description = "Project deleted"),
+        (status = 403, description = "Not a maintainer", body = ErrorBody),
+        (status = 404, description = "Project not found", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
 pub async fn delete_project_handler(
     State(state): State<AppState>,
     Extension(VerifiedWallet(verified_wallet)): Extension<VerifiedWallet>,
     Path(id): Path<String>,
-) -> impl IntoResponse {
-    match delete_project(state.project_repository.clone(), verified_wallet, id).await {
-        Ok(_) => StatusCode::NO_CONTENT.into_response(),
-        Err(e) => {
-            let status = if e.contains("not found") {
-                StatusCode::NOT_FOUND
-            } else if e.contains("Only the creator") {
-                StatusCode::FORBIDDEN
-            } else {
-                StatusCode::BAD_REQUEST
-            };
-            (status, Json(serde_json::json!({"error": e}))).into_response()
-        }
-    }
+) -> Result<StatusCode, AppError> {
+    delete_project(state.project_repository.clone(), verified_wallet, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /projects/:id/members - Members and pending invitations of a project
+#[utoipa::path(
+    get,
+    path = "/projects/{id}/members",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    responses(
+        (status = 200, description = "Members, oldest invitation first", body = [ProjectMemberResponse]),
+        (status = 404, description = "Project not found", body = ErrorBody)
+    )
+)]
+pub async fn list_project_members_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let members = get_project_members(state.project_repository.clone(), id).await?;
+    Ok((StatusCode::OK, Json(members)))
+}
+
+/// POST /projects/:id/members - Invite a wallet with a role (Protected, maintainers only)
+#[utoipa::path(
+    post,
+    path = "/projects/{id}/members",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    request_body = InviteMemberRequest,
+    responses(
+        (status = 201, description = "Invitation created", body = ProjectMemberResponse),
+        (status = 400, description = "Invalid address or invitee has no profile", body = ErrorBody),
+        (status = 403, description = "Not a maintainer", body = ErrorBody),
+        (status = 404, description = "Project not found", body = ErrorBody),
+        (status = 409, description = "Already a member or invited", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
+pub async fn invite_project_member_handler(
+    State(state): State<AppState>,
+    Extension(VerifiedWallet(verified_wallet)): Extension<VerifiedWallet>,
+    Path(id): Path<String>,
+    Json(request): Json<InviteMemberRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let member = invite_project_member(
+        state.project_repository.clone(),
+        verified_wallet,
+        id,
+        request,
+    )
+    .await?;
+    Ok((StatusCode::CREATED, Json(member)))
+}
+
+/// POST /projects/:id/members/accept - Accept an invitation (Protected, invitee)
+#[utoipa::path(
+    post,
+    path = "/projects/{id}/members/accept",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    responses(
+        (status = 200, description = "Invitation accepted", body = ProjectMemberResponse),
+        (status = 404, description = "No invitation for this wallet", body = ErrorBody),
+        (status = 409, description = "Already accepted", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
+pub async fn accept_project_invitation_handler(
+    State(state): State<AppState>,
+    Extension(VerifiedWallet(verified_wallet)): Extension<VerifiedWallet>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let member =
+        accept_project_invitation(state.project_repository.clone(), verified_wallet, id).await?;
+    Ok((StatusCode::OK, Json(member)))
+}
+
+/// POST /projects/:id/members/leave - Leave a project or decline an invitation (Protected)
+#[utoipa::path(
+    post,
+    path = "/projects/{id}/members/leave",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    responses(
+        (status = 204, description = "Membership removed"),
+        (status = 404, description = "Not a member", body = ErrorBody),
+        (status = 409, description = "Last maintainer", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
+pub async fn leave_project_handler(
+    State(state): State<AppState>,
+    Extension(VerifiedWallet(verified_wallet)): Extension<VerifiedWallet>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    leave_project(state.project_repository.clone(), verified_wallet, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /projects/:id/repos - GitHub repositories linked to a project
+#[utoipa::path(
+    get,
+    path = "/projects/{id}/repos",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    responses(
+        (status = 200, description = "Linked repositories", body = ProjectRepos),
+        (status = 404, description = "Project not found", body = ErrorBody)
+    )
+)]
+pub async fn get_project_repos_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let repos = get_project_repos(state.project_repository.clone(), id).await?;
+    Ok((StatusCode::OK, Json(repos)))
+}
+
+/// PUT /projects/:id/repos - Replace the linked GitHub repositories (Protected, maintainers only)
+#[utoipa::path(
+    put,
+    path = "/projects/{id}/repos",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    request_body = ProjectRepos,
+    responses(
+        (status = 200, description = "Repositories now linked", body = ProjectRepos),
+        (status = 400, description = "Invalid repository name", body = ErrorBody),
+        (status = 403, description = "Not a maintainer", body = ErrorBody),
+        (status = 404, description = "Project not found", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
+pub async fn set_project_repos_handler(
+    State(state): State<AppState>,
+    Extension(VerifiedWallet(verified_wallet)): Extension<VerifiedWallet>,
+    Path(id): Path<String>,
+    Json(request): Json<ProjectRepos>,
+) -> Result<impl IntoResponse, AppError> {
+    let repos = set_project_repos(
+        state.project_repository.clone(),
+        verified_wallet,
+        id,
+        request,
+    )
+    .await?;
+    Ok((StatusCode::OK, Json(repos)))
+}
+
+/// GET /projects/:id/issues - Synced issues of the project's repositories, newest first
+#[utoipa::path(
+    get,
+    path = "/projects/{id}/issues",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project id"), ProjectIssuesQuery),
+    responses(
+        (status = 200, description = "Issues", body = [GithubIssue]),
+        (status = 400, description = "Invalid state", body = ErrorBody),
+        (status = 404, description = "Project not found", body = ErrorBody)
+    )
+)]
+pub async fn get_project_issues_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(params): Query<ProjectIssuesQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let issues = get_project_issues(
+        state.project_repository.clone(),
+        state.github_issue_repository.clone(),
+        id,
+        params,
+    )
+    .await?;
+    Ok((StatusCode::OK, Json(issues)))
+}
+
+/// GET /projects/:id/stats - Issue counts, delivered points and top contributors
+#[utoipa::path(
+    get,
+    path = "/projects/{id}/stats",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    responses(
+        (status = 200, description = "Project statistics", body = ProjectStatsResponse),
+        (status = 404, description = "Project not found", body = ErrorBody)
+    )
+)]
+pub async fn get_project_stats_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let stats = get_project_stats(
+        state.project_repository.clone(),
+        state.github_issue_repository.clone(),
+        id,
+    )
+    .await?;
+    Ok((StatusCode::OK, Json(stats)))
+}
+
+/// POST /projects/:id/transitions - Move a project through its lifecycle (Protected)
+#[utoipa::path(
+    post,
+    path = "/projects/{id}/transitions",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    request_body = TransitionProjectRequest,
+    responses(
+        (status = 200, description = "Status changed and recorded in the history", body = ProjectResponse),
+        (status = 403, description = "Not allowed to make this transition", body = ErrorBody),
+        (status = 404, description = "Project not found", body = ErrorBody),
+        (status = 409, description = "Transition not allowed from the current status, or not enough approvals", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
+pub async fn transition_project_handler(
+    State(state): State<AppState>,
+    Extension(VerifiedWallet(verified_wallet)): Extension<VerifiedWallet>,
+    Path(id): Path<String>,
+    Json(request): Json<TransitionProjectRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let project = transition_project(
+        state.project_repository.clone(),
+        state.review_policy,
+        verified_wallet,
+        id,
+        request,
+    )
+    .await?;
+    Ok((StatusCode::OK, Json(project)))
+}
+
+/// GET /projects/:id/history - Status changes of a project, oldest first
+#[utoipa::path(
+    get,
+    path = "/projects/{id}/history",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    responses(
+        (status = 200, description = "Project history", body = [ProjectTransitionResponse]),
+        (status = 404, description = "Project not found", body = ErrorBody)
+    )
+)]
+pub async fn get_project_history_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let history = get_project_history(state.project_repository.clone(), id).await?;
+    Ok((StatusCode::OK, Json(history)))
+}
+
+/// GET /projects/:id/approvals - Reviewer approvals of the current review round
+#[utoipa::path(
+    get,
+    path = "/projects/{id}/approvals",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    responses(
+        (status = 200, description = "Approvals and the number required", body = ProjectApprovalsResponse),
+        (status = 404, description = "Project not found", body = ErrorBody)
+    )
+)]
+pub async fn get_project_approvals_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let approvals =
+        get_project_approvals(state.project_repository.clone(), state.review_policy, id).await?;
+    Ok((StatusCode::OK, Json(approvals)))
+}
+
+/// POST /projects/:id/approvals - Approve a project under review (Protected, reviewers only)
+#[utoipa::path(
+    post,
+    path = "/projects/{id}/approvals",
+    tag = "projects",
+    params(("id" = String, Path, description = "Project id")),
+    responses(
+        (status = 201, description = "Approval recorded", body = ProjectApprovalsResponse),
+        (status = 403, description = "Not an active reviewer", body = ErrorBody),
+        (status = 404, description = "Project not found", body = ErrorBody),
+        (status = 409, description = "Not under review, or already approved", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
+pub async fn approve_project_handler(
+    State(state): State<AppState>,
+    Extension(VerifiedWallet(verified_wallet)): Extension<VerifiedWallet>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let approvals = approve_project(
+        state.project_repository.clone(),
+        state.review_policy,
+        verified_wallet,
+        id,
+    )
+    .await?;
+    Ok((StatusCode::CREATED, Json(approvals)))
 }
 
 // ============================================================================
@@ -278,53 +686,37 @@ pub async fn delete_project_handler(
 
 /// DELETE /admin/profiles/:address - Admin delete a profile (bypasses ownership check)
 /// Used for deleting deprecated profiles or spam that we don't have access to anymore.
+#[utoipa::path(
+    delete,
+    path = "/admin/profiles/{address}",
+    tag = "admin",
+    params(("address" = String, Path, description = "Wallet address")),
+    responses(
+        (status = 204, description = "Profile deleted"),
+        (status = 404, description = "Profile not found", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
 pub async fn admin_delete_profile_handler(
     State(state): State<AppState>,
     Path(address): Path<String>,
-) -> axum::response::Response {
+) -> Result<StatusCode, AppError> {
     let wallet_address = WalletAddress(address.clone());
 
     // Check if profile exists first
-    let profile_exists = match state
+    if state
         .profile_repository
         .find_by_address(&wallet_address)
-        .await
+        .await?
+        .is_none()
     {
-        Ok(Some(_)) => true,
-        Ok(None) => false,
-        Err(e) => {
-            tracing::error!("Error finding profile {}: {}", address, e);
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(serde_json::json!({"error": format!("Error finding profile: {}", e)})),
-            )
-                .into_response();
-        }
-    };
-
-    if !profile_exists {
-        return (
-            StatusCode::NOT_FOUND,
-            Json(serde_json::json!({"error": "Profile not found"})),
-        )
-            .into_response();
+        return Err(AppError::not_found("Profile not found"));
     }
 
     // Profile exists, delete it
-    match state.profile_repository.delete(&wallet_address).await {
-        Ok(_) => {
-            tracing::info!("Admin deleted profile: {}", address);
-            StatusCode::NO_CONTENT.into_response()
-        }
-        Err(e) => {
-            tracing::error!("Failed to delete profile {}: {}", address, e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(serde_json::json!({"error": format!("Failed to delete profile: {}", e)})),
-            )
-                .into_response()
-        }
-    }
+    state.profile_repository.delete(&wallet_address).await?;
+    tracing::info!("Admin deleted profile: {}", address);
+    Ok(StatusCode::NO_CONTENT)
 }
 
 // ============================================================================
@@ -332,94 +724,307 @@ pub async fn admin_delete_profile_handler(
 // ============================================================================
 
 /// POST /admin/github/sync - Sync GitHub issues for specified repos (Admin only)
+#[utoipa::path(
+    post,
+    path = "/admin/github/sync",
+    tag = "admin",
+    request_body = GithubSyncRequest,
+    responses(
+        (status = 200, description = "Issues synced", body = GithubSyncResponse),
+        (status = 400, description = "Invalid input", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
 pub async fn github_sync_handler(
     State(state): State<AppState>,
     Json(request): Json<GithubSyncRequest>,
-) -> impl IntoResponse {
+) -> Result<impl IntoResponse, AppError> {
     if request.repos.is_empty() {
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(serde_json::json!({"error": "repos must not be empty"})),
-        )
-            .into_response();
+        return Err(AppError::invalid_field("repos", "repos must not be empty"));
     }
 
     tracing::info!(repos = ?request.repos, since = ?request.since, "Starting GitHub issue sync");
 
-    match sync_github_issues(
+    let synced = sync_github_issues(
         state.github_service.clone(),
         state.github_issue_repository.clone(),
         request.repos.clone(),
         request.since,
     )
-    .await
-    {
-        Ok(synced) => (
-            StatusCode::OK,
-            Json(GithubSyncResponse {
-                synced,
-                repos: request.repos,
-            }),
-        )
-            .into_response(),
-        Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({"error": e})),
-        )
-            .into_response(),
-    }
+    .await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(GithubSyncResponse {
+            synced,
+            repos: request.repos,
+        }),
+    ))
 }
 
 /// GET /github/issues?repo=<name>&state=<open|closed> - List synced GitHub issues (Public)
+#[utoipa::path(
+    get,
+    path = "/github/issues",
+    tag = "github",
+    params(GithubIssuesQuery),
+    responses((status = 200, description = "Synced issues", body = [GithubIssue]))
+)]
 pub async fn list_github_issues_handler(
     State(state): State<AppState>,
     Query(params): Query<GithubIssuesQuery>,
-) -> impl IntoResponse {
-    match state
+) -> Result<impl IntoResponse, AppError> {
+    let issues = state
         .github_issue_repository
         .list_by_repo(&params.repo, params.state.as_deref())
-        .await
-    {
-        Ok(issues) => (StatusCode::OK, Json(issues)).into_response(),
-        Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({"error": format!("Failed to fetch issues: {e}")})),
-        )
-            .into_response(),
-    }
+        .await?;
+    Ok((StatusCode::OK, Json(issues)))
 }
 
-/// POST /admin/distributions - Register distributions in batch (Admin only)
+/// POST /admin/distributions?dryRun=<bool> - Register distributions in batch (Admin only)
+#[utoipa::path(
+    post,
+    path = "/admin/distributions",
+    tag = "admin",
+    params(RegisterDistributionQuery),
+    request_body = RegisterDistributionRequest,
+    responses(
+        (status = 201, description = "Distributions registered", body = RegisterDistributionResponse),
+        (status = 200, description = "Dry-run report; nothing persisted", body = DistributionDryRunReport),
+        (status = 400, description = "Invalid input or distributionId not matching content", body = ErrorBody),
+        (status = 422, description = "Lines failing the dry-run checks; nothing registered", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
 pub async fn register_distribution_handler(
     State(state): State<AppState>,
+    Extension(VerifiedWallet(wallet)): Extension<VerifiedWallet>,
+    Query(params): Query<RegisterDistributionQuery>,
     Json(request): Json<RegisterDistributionRequest>,
-) -> impl IntoResponse {
-    match register_distribution(state.distribution_repository.clone(), request).await {
-        Ok(_) => StatusCode::CREATED.into_response(),
-        Err(e) => (
-            StatusCode::BAD_REQUEST,
-            Json(serde_json::json!({"error": e})),
+) -> Result<Response, AppError> {
+    if params.dry_run {
+        let report = validate_distribution(
+            state.profile_repository.clone(),
+            state.distribution_repository.clone(),
+            state.badge_registry.clone(),
+            wallet,
+            request,
         )
-            .into_response(),
+        .await?;
+        return Ok((StatusCode::OK, Json(report)).into_response());
     }
+
+    let registered = register_distribution(
+        state.profile_repository.clone(),
+        state.distribution_repository.clone(),
+        state.badge_registry.clone(),
+        wallet,
+        request,
+    )
+    .await?;
+    Ok((StatusCode::CREATED, Json(registered)).into_response())
 }
 
 /// GET /admin/distributions?distributionId=<id> - List distributions (Admin only)
+#[utoipa::path(
+    get,
+    path = "/admin/distributions",
+    tag = "admin",
+    params(ListDistributionsQuery),
+    responses((status = 200, description = "Registered distributions", body = [DistributionResponse])),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
 pub async fn list_distributions_handler(
     State(state): State<AppState>,
     Query(params): Query<ListDistributionsQuery>,
-) -> impl IntoResponse {
-    match list_distributions(
+) -> Result<impl IntoResponse, AppError> {
+    let distributions = list_distributions(
         state.distribution_repository.clone(),
         params.distribution_id,
     )
-    .await
-    {
-        Ok(distributions) => (StatusCode::OK, Json(distributions)).into_response(),
-        Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(serde_json::json!({"error": e})),
+    .await?;
+    Ok((StatusCode::OK, Json(distributions)))
+}
+
+/// GET /admin/distributions/:id - Distribution header, totals and lines (Admin only)
+#[utoipa::path(
+    get,
+    path = "/admin/distributions/{id}",
+    tag = "admin",
+    params(("id" = String, Path, description = "Distribution ID")),
+    responses(
+        (status = 200, description = "Distribution", body = DistributionDetailResponse),
+        (status = 404, description = "Distribution not found", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
+pub async fn get_distribution_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<DistributionDetailResponse>, AppError> {
+    Ok(Json(
+        get_distribution(state.distribution_repository.clone(), id).await?,
+    ))
+}
+
+/// POST /admin/distributions/:id/submit - Record the broadcast transaction (Admin only)
+#[utoipa::path(
+    post,
+    path = "/admin/distributions/{id}/submit",
+    tag = "admin",
+    params(("id" = String, Path, description = "Distribution ID")),
+    request_body = SubmitDistributionRequest,
+    responses(
+        (status = 200, description = "Distribution submitted", body = DistributionDetailResponse),
+        (status = 400, description = "Invalid tx hash or chain id", body = ErrorBody),
+        (status = 404, description = "Distribution not found", body = ErrorBody),
+        (status = 409, description = "Illegal status transition", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
+pub async fn submit_distribution_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(request): Json<SubmitDistributionRequest>,
+) -> Result<Json<DistributionDetailResponse>, AppError> {
+    Ok(Json(
+        submit_distribution(state.distribution_repository.clone(), id, request).await?,
+    ))
+}
+
+/// POST /admin/distributions/:id/confirm - Mark a submitted distribution confirmed (Admin only)
+#[utoipa::path(
+    post,
+    path = "/admin/distributions/{id}/confirm",
+    tag = "admin",
+    params(("id" = String, Path, description = "Distribution ID")),
+    responses(
+        (status = 200, description = "Distribution confirmed", body = DistributionDetailResponse),
+        (status = 404, description = "Distribution not found", body = ErrorBody),
+        (status = 409, description = "Illegal status transition", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
+pub async fn confirm_distribution_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<DistributionDetailResponse>, AppError> {
+    Ok(Json(
+        confirm_distribution(state.distribution_repository.clone(), id).await?,
+    ))
+}
+
+/// POST /admin/distributions/:id/fail - Mark a submitted distribution failed (Admin only)
+#[utoipa::path(
+    post,
+    path = "/admin/distributions/{id}/fail",
+    tag = "admin",
+    params(("id" = String, Path, description = "Distribution ID")),
+    responses(
+        (status = 200, description = "Distribution failed", body = DistributionDetailResponse),
+        (status = 404, description = "Distribution not found", body = ErrorBody),
+        (status = 409, description = "Illegal status transition", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
+pub async fn fail_distribution_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<DistributionDetailResponse>, AppError> {
+    Ok(Json(
+        fail_distribution(state.distribution_repository.clone(), id).await?,
+    ))
+}
+
+/// POST /admin/distributions/:id/reconcile - Compare a submitted distribution with the
+/// mints of its transaction; confirms it when everything matches (Admin only)
+#[utoipa::path(
+    post,
+    path = "/admin/distributions/{id}/reconcile",
+    tag = "admin",
+    params(("id" = String, Path, description = "Distribution ID")),
+    responses(
+        (status = 200, description = "Reconciliation report", body = DistributionReconciliationReport),
+        (status = 400, description = "Lines without an amount", body = ErrorBody),
+        (status = 404, description = "Distribution not found", body = ErrorBody),
+        (status = 409, description = "Distribution was never submitted", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
+pub async fn reconcile_distribution_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<DistributionReconciliationReport>, AppError> {
+    Ok(Json(
+        reconcile_distribution(
+            state.distribution_repository.clone(),
+            state.mint_event_service.clone(),
+            id,
         )
-            .into_response(),
-    }
+        .await?,
+    ))
+}
+
+/// POST /admin/distributions/reconcile - Reconcile every submitted distribution (Admin only)
+#[utoipa::path(
+    post,
+    path = "/admin/distributions/reconcile",
+    tag = "admin",
+    responses(
+        (status = 200, description = "One report per submitted distribution", body = [DistributionReconciliationReport])
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
+pub async fn reconcile_submitted_distributions_handler(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<DistributionReconciliationReport>>, AppError> {
+    Ok(Json(
+        reconcile_submitted_distributions(
+            state.distribution_repository.clone(),
+            state.mint_event_service.clone(),
+        )
+        .await?,
+    ))
+}
+
+/// GET /admin/distributions/:id/export?format=<attestations|mints|csv> - Render a distribution
+/// as the JSON files read by the Foundry scripts, or as CSV (Admin only)
+#[utoipa::path(
+    get,
+    path = "/admin/distributions/{id}/export",
+    tag = "admin",
+    params(
+        ("id" = String, Path, description = "Distribution ID"),
+        ExportDistributionQuery
+    ),
+    responses(
+        (status = 200, description = "Export file; keccak256 of the body in X-Content-Hash", body = String),
+        (status = 400, description = "Lines without amount for a mints export", body = ErrorBody),
+        (status = 404, description = "Distribution not found", body = ErrorBody)
+    ),
+    security(("bearer_auth" = []), ("eth_signature" = []))
+)]
+pub async fn export_distribution_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(params): Query<ExportDistributionQuery>,
+) -> Result<Response, AppError> {
+    let export =
+        export_distribution(state.distribution_repository.clone(), id, params.format).await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, export.content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", export.filename),
+            ),
+            (
+                HeaderName::from_static("x-content-hash"),
+                export.content_hash,
+            ),
+        ],
+        export.body,
+    )
+        .into_response())
 }
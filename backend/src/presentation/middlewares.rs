@@ -1,7 +1,7 @@
 use axum::{
     body::Body,
     extract::State,
-    http::{Request, StatusCode},
+    http::{header, HeaderValue, Request, StatusCode},
     middleware::Next,
     response::Response,
 };
@@ -9,7 +9,10 @@ use axum::{
 use crate::domain::services::auth_service::AuthChallenge;
 use crate::infrastructure::jwt::JwtManager;
 
-use super::api::AppState;
+use super::api::{AppState, API_V1_PREFIX};
+
+/// Default sunset date for the unversioned routes, overridable with LEGACY_API_SUNSET.
+const DEFAULT_LEGACY_API_SUNSET: &str = "Thu, 01 Apr 2027 00:00:00 GMT";
 
 #[derive(Clone, Debug)]
 pub struct VerifiedWallet(pub String);
@@ -203,3 +206,23 @@ pub async fn admin_auth_layer(
 
     Ok(next.run(req).await)
 }
+
+/// Middleware for the legacy unversioned routes.
+/// Serves the request unchanged but flags the response as deprecated (`Deprecation`),
+/// announces its removal date (`Sunset`, RFC 8594) and points to the `/v1` equivalent.
+pub async fn deprecation_layer(req: Request<Body>, next: Next) -> Response {
+    let successor = format!("{}{}", API_V1_PREFIX, req.uri().path());
+    let sunset = std::env::var("LEGACY_API_SUNSET")
+        .unwrap_or_else(|_| DEFAULT_LEGACY_API_SUNSET.to_string());
+
+    let mut response = next.run(req).await;
+    let headers = response.headers_mut();
+    headers.insert("deprecation", HeaderValue::from_static("true"));
+    if let Ok(value) = HeaderValue::from_str(&sunset) {
+        headers.insert("sunset", value);
+    }
+    if let Ok(value) = HeaderValue::from_str(&format!("<{successor}>; rel=\"successor-version\"")) {
+        headers.insert(header::LINK, value);
+    }
+    response
+}
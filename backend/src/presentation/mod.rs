@@ -1,3 +1,6 @@
 pub mod api;
+pub mod errors;
+pub mod extractors;
 pub mod handlers;
 pub mod middlewares;
+pub mod openapi;
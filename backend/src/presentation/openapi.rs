@@ -0,0 +1,190 @@
+use axum::{response::Html, Json};
+use utoipa::{
+    openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme},
+    Modify, OpenApi,
+};
+
+use crate::{
+    application::dtos::{
+        distribution_dtos::{
+            DistributionDetailResponse, DistributionDryRunReport, DistributionLineIssue,
+            DistributionLineReport, DistributionReconciliationReport, DistributionResponse,
+            ReconciliationIssue, RegisterDistributionItem, RegisterDistributionRequest,
+            RegisterDistributionResponse, SubmitDistributionRequest,
+        },
+        github_dtos::{GithubSyncRequest, GithubSyncResponse},
+        AuthTokenResponse, ContributorStats, CreateProfileRequest, CreateProjectRequest,
+        InviteMemberRequest, NonceResponse, ProfilePageResponse, ProfileResponse,
+        ProjectApprovalResponse, ProjectApprovalsResponse, ProjectMemberResponse,
+        ProjectPageResponse, ProjectRepos, ProjectResponse, ProjectStatsResponse,
+        ProjectTransitionResponse, TransitionProjectRequest, UpdateProfileRequest,
+        UpdateProjectRequest,
+    },
+    domain::{
+        entities::{
+            github_issue::GithubIssue, DistributionStatus, MembershipStatus, ProjectRole,
+            ProjectStatus,
+        },
+        errors::FieldError,
+        value_objects::{TokenAmount, WalletAddress},
+    },
+};
+
+use super::{errors::ErrorBody, handlers};
+
+/// OpenAPI document for every route registered in `build_app`.
+/// Paths are relative to the `/v1` server.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "The Guild Genesis API",
+        description = "Profiles, projects, GitHub issues and badge distributions."
+    ),
+    servers((url = "/v1", description = "Current API version")),
+    paths(
+        handlers::create_profile_handler,
+        handlers::get_profile_handler,
+        handlers::get_all_profiles_handler,
+        handlers::update_profile_handler,
+        handlers::delete_profile_handler,
+        handlers::get_nonce_handler,
+        handlers::login_handler,
+        handlers::list_projects_handler,
+        handlers::get_project_handler,
+        handlers::get_user_projects_handler,
+        handlers::create_project_handler,
+        handlers::update_project_handler,
+        handlers::delete_project_handler,
+        handlers::list_project_members_handler,
+        handlers::invite_project_member_handler,
+        handlers::accept_project_invitation_handler,
+        handlers::leave_project_handler,
+        handlers::transition_project_handler,
+        handlers::get_project_history_handler,
+        handlers::get_project_approvals_handler,
+        handlers::approve_project_handler,
+        handlers::get_project_repos_handler,
+        handlers::set_project_repos_handler,
+        handlers::get_project_issues_handler,
+        handlers::get_project_stats_handler,
+        handlers::admin_delete_profile_handler,
+        handlers::github_sync_handler,
+        handlers::list_github_issues_handler,
+        handlers::register_distribution_handler,
+        handlers::list_distributions_handler,
+        handlers::get_distribution_handler,
+        handlers::submit_distribution_handler,
+        handlers::confirm_distribution_handler,
+        handlers::fail_distribution_handler,
+        handlers::reconcile_distribution_handler,
+        handlers::reconcile_submitted_distributions_handler,
+        handlers::export_distribution_handler,
+    ),
+    components(schemas(
+        AuthTokenResponse,
+        ContributorStats,
+        CreateProfileRequest,
+        CreateProjectRequest,
+        DistributionDetailResponse,
+        DistributionDryRunReport,
+        DistributionLineIssue,
+        DistributionLineReport,
+        DistributionReconciliationReport,
+        DistributionResponse,
+        DistributionStatus,
+        ErrorBody,
+        FieldError,
+        GithubIssue,
+        GithubSyncRequest,
+        GithubSyncResponse,
+        InviteMemberRequest,
+        MembershipStatus,
+        NonceResponse,
+        ProfilePageResponse,
+        ProfileResponse,
+        ProjectApprovalResponse,
+        ProjectApprovalsResponse,
+        ProjectMemberResponse,
+        ProjectPageResponse,
+        ProjectRepos,
+        ProjectResponse,
+        ProjectRole,
+        ProjectStatsResponse,
+        ProjectStatus,
+        ProjectTransitionResponse,
+        ReconciliationIssue,
+        RegisterDistributionItem,
+        RegisterDistributionRequest,
+        RegisterDistributionResponse,
+        SubmitDistributionRequest,
+        TokenAmount,
+        TransitionProjectRequest,
+        UpdateProfileRequest,
+        UpdateProjectRequest,
+        WalletAddress,
+    )),
+    modifiers(&SecurityAddon),
+    tags(
+        (name = "profiles", description = "Member profiles"),
+        (name = "auth", description = "Wallet authentication"),
+        (name = "projects", description = "Guild projects"),
+        (name = "github", description = "Synced GitHub issues"),
+        (name = "admin", description = "Admin-only operations"),
+    )
+)]
+pub struct ApiDoc;
+
+/// Registers the two ways a wallet can authenticate: a JWT from `/auth/login`
+/// or a signed nonce sent in the `x-eth-signature` header.
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+        components.add_security_scheme(
+            "eth_signature",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::with_description(
+                "x-eth-signature",
+                "Signature of the login nonce; send the wallet in x-eth-address",
+            ))),
+        );
+    }
+}
+
+/// GET /openapi.json - OpenAPI 3 document
+pub async fn openapi_handler() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+/// GET /docs - Swagger UI rendering `/openapi.json`
+pub async fn swagger_ui_handler() -> Html<&'static str> {
+    Html(SWAGGER_UI_HTML)
+}
+
+const SWAGGER_UI_HTML: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="utf-8" />
+    <title>The Guild Genesis API</title>
+    <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css" />
+</head>
+<body>
+    <div id="swagger-ui"></div>
+    <script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js" crossorigin></script>
+    <script>
+        window.onload = () => {
+            window.ui = SwaggerUIBundle({ url: "/openapi.json", dom_id: "#swagger-ui" });
+        };
+    </script>
+</body>
+</html>
+"##;
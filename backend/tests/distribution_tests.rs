@@ -1,8 +1,25 @@
+use guild_backend::domain::entities::distribution::{
+    keccak256_hex, Distribution, DistributionLine,
+};
+use guild_backend::domain::entities::{Profile, ReviewPolicy};
+use guild_backend::domain::errors::AppError;
+use guild_backend::domain::repositories::{DistributionRepository, ProfileRepository};
+use guild_backend::domain::services::mint_event_service::{MintEvent, TransactionMints};
+use guild_backend::domain::value_objects::{TokenAmount, WalletAddress};
 use guild_backend::infrastructure::repositories::postgres_distribution_repository::PostgresDistributionRepository;
 use guild_backend::infrastructure::repositories::postgres_github_issue_repository::PostgresGithubIssueRepository;
 use guild_backend::infrastructure::repositories::postgres_project_repository::PostgresProjectRepository;
+use guild_backend::infrastructure::repositories::{
+    InMemoryDistributionRepository, InMemoryGithubIssueRepository, InMemoryProfileRepository,
+    InMemoryProjectRepository, InMemoryStore,
+};
+use guild_backend::infrastructure::services::ethereum_address_verification_service::MockEthereumAddressVerificationService;
+use guild_backend::infrastructure::services::indexer_badge_registry_service::MockBadgeRegistryService;
 use guild_backend::infrastructure::services::rest_github_service::RestGithubService;
-use guild_backend::presentation::api::{test_api, AppState};
+use guild_backend::infrastructure::services::rpc_mint_event_service::{
+    decode_mints, MockMintEventService,
+};
+use guild_backend::presentation::api::{build_app, AppState, AuthStrategy};
 use serde_json::json;
 use std::sync::Arc;
 use tokio::net::TcpListener;
@@ -28,6 +45,16 @@ async fn register_distribution_list_succeeds() {
     let github_service: Arc<dyn guild_backend::domain::services::github_service::GithubService> =
         Arc::from(RestGithubService::new());
 
+    // Recipients need a profile to pass registration; earlier runs may have created them
+    for address in [
+        "0x1234567890123456789012345678901234567890",
+        "0x1234567890123456789012345678901234567891",
+    ] {
+        let _ = profile_repository
+            .create(&Profile::new(WalletAddress(address.to_string())))
+            .await;
+    }
+
     let state = AppState {
         profile_repository,
         project_repository,
@@ -35,8 +62,11 @@ async fn register_distribution_list_succeeds() {
         auth_service: std::sync::Arc::new(auth_service),
         github_issue_repository,
         github_service,
+        badge_registry: Arc::new(MockBadgeRegistryService::new(["Contributor", "Reviewer"])),
+        mint_event_service: Arc::new(MockMintEventService::new()),
+        review_policy: ReviewPolicy::default(),
     };
-    let app = test_api(state);
+    let app = build_app(state, AuthStrategy::TestHeader);
 
     let server = axum::serve(listener, app);
     tokio::spawn(async move { server.await.unwrap() });
@@ -44,25 +74,6 @@ async fn register_distribution_list_succeeds() {
     let base = format!("http://{}", addr);
     let client = reqwest::Client::new();
 
-    sqlx::query(
-        r#"
-        CREATE TABLE IF NOT EXISTS distributions (
-            address TEXT NOT NULL,
-            badge_name TEXT NOT NULL,
-            distribution_id TEXT NOT NULL
-        )
-        "#,
-    )
-    .execute(&pool)
-    .await
-    .unwrap();
-
-    sqlx::query("DELETE FROM distributions WHERE distribution_id = $1")
-        .bind("dist-test-001")
-        .execute(&pool)
-        .await
-        .unwrap();
-
     let response = client
         .post(format!("{}/admin/distributions", base))
         .header(
@@ -73,13 +84,11 @@ async fn register_distribution_list_succeeds() {
             "distributions": [
                 {
                     "address": "0x1234567890123456789012345678901234567890",
-                    "badgeName": "Contributor",
-                    "distributionId": "dist-test-001"
+                    "badgeName": "Contributor"
                 },
                 {
                     "address": "0x1234567890123456789012345678901234567891",
-                    "badgeName": "Reviewer",
-                    "distributionId": "dist-test-001"
+                    "badgeName": "Reviewer"
                 }
             ]
         }))
@@ -88,11 +97,16 @@ async fn register_distribution_list_succeeds() {
         .unwrap();
 
     assert_eq!(response.status(), reqwest::StatusCode::CREATED);
+    let registered: serde_json::Value = response.json().await.unwrap();
+    let id = registered["distributionIds"][0]
+        .as_str()
+        .unwrap()
+        .to_string();
 
     let list_resp = client
         .get(format!(
-            "{}/admin/distributions?distributionId=dist-test-001",
-            base
+            "{}/admin/distributions?distributionId={}",
+            base, id
         ))
         .header(
             "x-eth-address",
@@ -106,3 +120,716 @@ async fn register_distribution_list_succeeds() {
     let listed: serde_json::Value = list_resp.json().await.unwrap();
     assert_eq!(listed.as_array().unwrap().len(), 2);
 }
+
+const ADMIN: &str = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e";
+
+/// Store holding a profile for each address, so registrations pass the dry-run checks.
+async fn store_with_profiles(addresses: &[&str]) -> InMemoryStore {
+    let store = InMemoryStore::new();
+    let profiles = InMemoryProfileRepository::new(store.clone());
+    for address in addresses {
+        profiles
+            .create(&Profile::new(WalletAddress(address.to_string())))
+            .await
+            .unwrap();
+    }
+    store
+}
+
+async fn spawn_in_memory_app(store: InMemoryStore, badges: &[&str]) -> String {
+    spawn_app_with_mints(store, badges, MockMintEventService::new()).await
+}
+
+async fn spawn_app_with_mints(
+    store: InMemoryStore,
+    badges: &[&str],
+    mints: MockMintEventService,
+) -> String {
+    let state = AppState {
+        profile_repository: Arc::new(InMemoryProfileRepository::new(store.clone())),
+        project_repository: Arc::new(InMemoryProjectRepository::new(store.clone())),
+        distribution_repository: Arc::new(InMemoryDistributionRepository::new(store.clone())),
+        auth_service: Arc::new(MockEthereumAddressVerificationService::new()),
+        github_issue_repository: Arc::new(InMemoryGithubIssueRepository::new(store)),
+        github_service: Arc::new(RestGithubService::new()),
+        badge_registry: Arc::new(MockBadgeRegistryService::new(badges.iter().copied())),
+        mint_event_service: Arc::new(mints),
+        review_policy: ReviewPolicy::default(),
+    };
+    let app = build_app(state, AuthStrategy::TestHeader);
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    format!("http://{}/v1", addr)
+}
+
+#[tokio::test]
+async fn distribution_lifecycle_rejects_illegal_moves() {
+    let store = store_with_profiles(&[
+        "0x1234567890123456789012345678901234567890",
+        "0x1234567890123456789012345678901234567891",
+    ])
+    .await;
+    let base = spawn_in_memory_app(store, &["Contributor"]).await;
+    let client = reqwest::Client::new();
+    let body = json!({
+        "distributions": [
+            { "address": "0x1234567890123456789012345678901234567890", "badgeName": "Contributor", "amount": 40 },
+            { "address": "0x1234567890123456789012345678901234567891", "badgeName": "Contributor", "amount": 80 }
+        ]
+    });
+    let post = |path: &str| {
+        client
+            .post(format!("{}/admin/distributions{}", base, path))
+            .header("x-eth-address", ADMIN)
+    };
+
+    // Registering the same lines twice yields the same id and does not duplicate them
+    let mut ids = Vec::new();
+    for _ in 0..2 {
+        let resp = post("").json(&body).send().await.unwrap();
+        assert_eq!(resp.status(), reqwest::StatusCode::CREATED);
+        let registered: serde_json::Value = resp.json().await.unwrap();
+        ids.push(
+            registered["distributionIds"][0]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        );
+    }
+    assert_eq!(ids[0], ids[1]);
+    let id = ids[0].clone();
+
+    let detail: serde_json::Value = client
+        .get(format!("{}/admin/distributions/{}", base, id))
+        .header("x-eth-address", ADMIN)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(detail["status"], "draft");
+    assert_eq!(detail["creator"], ADMIN);
+    assert_eq!(detail["lineCount"], 2);
+    assert_eq!(detail["totalAmount"], "120");
+    assert_eq!(detail["contentHash"], id.as_str());
+
+    // A supplied id must match the content
+    let mut tampered = body.clone();
+    tampered["distributions"][0]["distributionId"] = json!(id);
+    tampered["distributions"][1]["distributionId"] = json!(id);
+    tampered["distributions"][1]["amount"] = json!(81);
+    let resp = post("").json(&tampered).send().await.unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::BAD_REQUEST);
+
+    let resp = post(&format!("/{id}/confirm")).send().await.unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::CONFLICT);
+
+    let resp = post(&format!("/{id}/submit"))
+        .json(&json!({ "txHash": "0x1234", "chainId": 80002 }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::BAD_REQUEST);
+
+    let tx_hash = format!("0x{}", "ab".repeat(32));
+    let resp = post(&format!("/{id}/submit"))
+        .json(&json!({ "txHash": tx_hash, "chainId": 80002 }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::OK);
+    let submitted: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(submitted["status"], "submitted");
+    assert_eq!(submitted["txHash"], tx_hash);
+    assert_eq!(submitted["chainId"], 80002);
+
+    // Registering the same lines again leaves the submitted distribution untouched
+    let resp = post("").json(&body).send().await.unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::CREATED);
+    let detail: serde_json::Value = client
+        .get(format!("{}/admin/distributions/{}", base, id))
+        .header("x-eth-address", ADMIN)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(detail["status"], "submitted");
+
+    let resp = post(&format!("/{id}/confirm")).send().await.unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::OK);
+
+    let resp = post(&format!("/{id}/fail")).send().await.unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::CONFLICT);
+
+    let resp = post("/unknown/confirm").send().await.unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn dry_run_reports_each_line_without_persisting() {
+    let known = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e";
+    let store = InMemoryStore::new();
+    InMemoryProfileRepository::new(store.clone())
+        .create(&Profile::new(WalletAddress(known.to_string())))
+        .await
+        .unwrap();
+    InMemoryDistributionRepository::new(store.clone())
+        .register(&Distribution::new(
+            "dist-earlier".into(),
+            WalletAddress(ADMIN.to_string()),
+            vec![DistributionLine {
+                distribution_id: "dist-earlier".into(),
+                address: known.to_string(),
+                badge_name: "Reviewer".into(),
+                amount: None,
+                reason: None,
+            }],
+        ))
+        .await
+        .unwrap();
+    let base = spawn_in_memory_app(store, &["Contributor", "Reviewer"]).await;
+    let client = reqwest::Client::new();
+
+    let line = |address: &str, badge: &str| json!({ "address": address, "badgeName": badge });
+    let resp = client
+        .post(format!("{}/admin/distributions?dryRun=true", base))
+        .header("x-eth-address", ADMIN)
+        .json(&json!({
+            "distributions": [
+                line(known, "Contributor"),
+                line(&known.to_lowercase(), "Contributor"),
+                line("0x1234", "Contributor"),
+                line("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", "Unknown"),
+                line(known, "Reviewer"),
+            ]
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::OK);
+
+    let report: serde_json::Value = resp.json().await.unwrap();
+    let codes = |index: usize| -> Vec<String> {
+        report["lines"][index]["issues"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|issue| issue["code"].as_str().unwrap().to_string())
+            .collect()
+    };
+    assert_eq!(report["valid"], false);
+    assert_eq!(report["lineCount"], 5);
+    assert_eq!(report["invalidCount"], 4);
+    assert_eq!(report["lines"][0]["valid"], true);
+    assert_eq!(codes(1), vec!["invalid_checksum", "duplicate_in_request"]);
+    assert_eq!(codes(2), vec!["invalid_address"]);
+    assert_eq!(codes(3), vec!["unknown_profile", "unknown_badge"]);
+    assert_eq!(codes(4), vec!["already_distributed"]);
+
+    // Every line reports the content-derived id, and nothing was stored under it
+    let id = report["lines"][0]["distributionId"].as_str().unwrap();
+    assert!(id.starts_with("0x") && id.len() == 66);
+    assert_eq!(report["lines"][4]["distributionId"], id);
+    let resp = client
+        .get(format!("{}/admin/distributions/{}", base, id))
+        .header("x-eth-address", ADMIN)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn registration_rejects_lines_failing_the_dry_run_checks() {
+    let known = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e";
+    let store = store_with_profiles(&[known]).await;
+    let base = spawn_in_memory_app(store, &["Contributor"]).await;
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(format!("{}/admin/distributions", base))
+        .header("x-eth-address", ADMIN)
+        .json(&json!({
+            "distributions": [
+                { "address": "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed", "badgeName": "Contributor" },
+                { "address": known, "badgeName": "Contributor" },
+                { "address": known, "badgeName": "A badge name longer than thirty-two bytes" }
+            ]
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = resp.json().await.unwrap();
+    assert_eq!(body["code"], "unprocessable");
+    let fields: Vec<&str> = body["details"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|detail| detail["field"].as_str().unwrap())
+        .collect();
+    assert!(fields.contains(&"distributions[0]"));
+    assert!(fields.contains(&"distributions[2]"));
+    assert!(!fields.contains(&"distributions[1]"));
+    assert!(body["details"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|detail| detail["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("invalid_badge_name")));
+
+    // The valid line was not stored either
+    let listed: serde_json::Value = client
+        .get(format!("{}/admin/distributions", base))
+        .header("x-eth-address", ADMIN)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(listed, json!([]));
+}
+
+#[tokio::test]
+async fn exports_match_foundry_script_shapes() {
+    let store = store_with_profiles(&[
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0x742d35Cc6634C0532925a3b844Bc454e4438f44e",
+    ])
+    .await;
+    let base = spawn_in_memory_app(store, &["Rust", "Solidity"]).await;
+    let client = reqwest::Client::new();
+
+    let register = |lines: serde_json::Value| {
+        let request = client
+            .post(format!("{}/admin/distributions", base))
+            .header("x-eth-address", ADMIN)
+            .json(&json!({ "distributions": lines }));
+        async move {
+            let resp = request.send().await.unwrap();
+            assert_eq!(resp.status(), reqwest::StatusCode::CREATED);
+            let registered: serde_json::Value = resp.json().await.unwrap();
+            registered["distributionIds"][0]
+                .as_str()
+                .unwrap()
+                .to_string()
+        }
+    };
+    let with_amounts = register(json!([
+        { "address": "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", "badgeName": "Rust", "amount": 80, "reason": "Indexer, \"reorg\" handling" },
+        { "address": "0x742d35Cc6634C0532925a3b844Bc454e4438f44e", "badgeName": "Rust", "amount": 40, "reason": "Add doc for indexer" }
+    ]))
+    .await;
+    let without_amount = register(json!([
+        { "address": "0x742d35Cc6634C0532925a3b844Bc454e4438f44e", "badgeName": "Solidity" }
+    ]))
+    .await;
+
+    let export = |id: &str, format: &str| {
+        client
+            .get(format!(
+                "{}/admin/distributions/{}/export?format={}",
+                base, id, format
+            ))
+            .header("x-eth-address", ADMIN)
+            .send()
+    };
+
+    let resp = export(&with_amounts, "attestations").await.unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::OK);
+    let hash = resp.headers()["x-content-hash"]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let body = resp.bytes().await.unwrap();
+    assert_eq!(hash, keccak256_hex(&body));
+    let attestations: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(
+        attestations,
+        json!({
+            "attestations": [
+                { "recipient": "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", "badgeName": "Rust", "justification": "Indexer, \"reorg\" handling" },
+                { "recipient": "0x742d35Cc6634C0532925a3b844Bc454e4438f44e", "badgeName": "Rust", "justification": "Add doc for indexer" }
+            ]
+        })
+    );
+
+    // Same content, same hash
+    let again = export(&with_amounts, "attestations").await.unwrap();
+    assert_eq!(again.headers()["x-content-hash"].to_str().unwrap(), hash);
+
+    let mints: serde_json::Value = export(&with_amounts, "mints")
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(mints["mints"][0]["amount"], "80");
+    assert_eq!(
+        mints["mints"][1]["recipient"],
+        "0x742d35Cc6634C0532925a3b844Bc454e4438f44e"
+    );
+    assert_eq!(mints["mints"][1]["reason"], "Add doc for indexer");
+
+    let csv = export(&with_amounts, "csv")
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert_eq!(
+        csv,
+        "recipient,badge_name,amount,reason\n\
+         0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed,Rust,80,\"Indexer, \"\"reorg\"\" handling\"\n\
+         0x742d35Cc6634C0532925a3b844Bc454e4438f44e,Rust,40,Add doc for indexer\n"
+    );
+
+    let resp = export(&without_amount, "mints").await.unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::BAD_REQUEST);
+}
+
+fn line(address: &str, badge: &str, amount: Option<u64>) -> DistributionLine {
+    DistributionLine {
+        distribution_id: String::new(),
+        address: address.to_string(),
+        badge_name: badge.to_string(),
+        amount: amount.map(TokenAmount::from),
+        reason: None,
+    }
+}
+
+#[test]
+fn distribution_id_is_derived_from_canonical_content() {
+    let creator = WalletAddress(ADMIN.to_string());
+    let a = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+    let b = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e";
+
+    let first = Distribution::from_lines(
+        creator.clone(),
+        vec![line(a, "Rust", Some(80)), line(b, "Rust", Some(40))],
+    )
+    .unwrap();
+    // Order and address case do not change the id
+    let reordered = Distribution::from_lines(
+        creator.clone(),
+        vec![
+            line(&b.to_lowercase(), "Rust", Some(40)),
+            line(&a.to_lowercase(), "Rust", Some(80)),
+        ],
+    )
+    .unwrap();
+    assert_eq!(first.id, reordered.id);
+    assert!(reordered
+        .lines
+        .iter()
+        .all(|l| l.distribution_id == first.id));
+    assert_eq!(
+        Distribution::canonical_csv(&first.lines),
+        format!("address,badge_name,amount,reason\n{a},Rust,80,\n{b},Rust,40,\n")
+    );
+    assert_eq!(
+        first.id,
+        keccak256_hex(Distribution::canonical_csv(&first.lines).as_bytes())
+    );
+
+    // A repeated pair is rejected with its rows, even when amount and reason differ
+    let mut repeated = line(&a.to_lowercase(), "Rust", Some(40));
+    repeated.reason = Some("Another issue".into());
+    match Distribution::from_lines(
+        creator.clone(),
+        vec![
+            line(a, "Rust", Some(80)),
+            line(b, "Rust", Some(40)),
+            repeated,
+        ],
+    ) {
+        Err(AppError::Validation { details, .. }) => {
+            assert_eq!(details.len(), 1);
+            assert_eq!(details[0].field, "lines[2]");
+            assert!(details[0].message.contains("lines[0]"));
+        }
+        other => panic!("expected a validation error, got {other:?}"),
+    }
+
+    let changed = Distribution::from_lines(
+        creator.clone(),
+        vec![line(a, "Rust", Some(81)), line(b, "Rust", Some(40))],
+    )
+    .unwrap();
+    assert_ne!(first.id, changed.id);
+
+    // The reason is part of the content
+    let mut with_reason = line(a, "Rust", Some(80));
+    with_reason.reason = Some("Indexer".into());
+    let reasoned = Distribution::from_lines(
+        creator.clone(),
+        vec![with_reason, line(b, "Rust", Some(40))],
+    )
+    .unwrap();
+    assert_ne!(first.id, reasoned.id);
+
+    // Separators inside a field are quoted, so they cannot forge another row
+    let mut quoted = line(a, "Rust", None);
+    quoted.reason = Some("x\n0x742d35Cc6634C0532925a3b844Bc454e4438f44e,Rust,,".into());
+    let forged = Distribution::from_lines(creator.clone(), vec![quoted]).unwrap();
+    let genuine = Distribution::from_lines(
+        creator,
+        vec![
+            {
+                let mut l = line(a, "Rust", None);
+                l.reason = Some("x".into());
+                l
+            },
+            line(b, "Rust", None),
+        ],
+    )
+    .unwrap();
+    assert_ne!(forged.id, genuine.id);
+    assert!(Distribution::canonical_csv(&forged.lines).contains("\"x\n0x742d"));
+
+    // Lines edited after the id was computed can no longer be submitted
+    let mut tampered = first.clone();
+    tampered.lines[0].amount = Some(TokenAmount::from(81u64));
+    let tx_hash = format!("0x{}", "ab".repeat(32));
+    assert!(matches!(
+        tampered.submit(tx_hash.clone(), 1),
+        Err(AppError::Conflict(_))
+    ));
+    let mut untouched = first.clone();
+    assert!(untouched.submit(tx_hash, 1).is_ok());
+}
+
+#[tokio::test]
+async fn reconciliation_confirms_only_fully_minted_distributions() {
+    let a = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+    let b = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e";
+    let c = "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359";
+    let d = "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB";
+    let tx = |byte: &str| format!("0x{}", byte.repeat(32));
+    let mint = |recipient: &str, amount: u64, reason: Option<&str>| MintEvent {
+        recipient: recipient.to_string(),
+        amount: TokenAmount::from(amount),
+        reason_hash: reason.map(|r| keccak256_hex(r.as_bytes())),
+    };
+    let mined = |mints: Vec<MintEvent>| TransactionMints {
+        reverted: false,
+        mints,
+    };
+
+    let mints = MockMintEventService::new()
+        .with_transaction(
+            tx("11"),
+            mined(vec![mint(a, 80, Some("Indexer")), mint(b, 40, None)]),
+        )
+        .with_transaction(
+            tx("22"),
+            mined(vec![mint(a, 11, None), mint(b, 20, None), mint(d, 5, None)]),
+        )
+        .with_transaction(
+            tx("33"),
+            TransactionMints {
+                reverted: true,
+                mints: Vec::new(),
+            },
+        );
+    let store = store_with_profiles(&[a, b, c, d]).await;
+    let base = spawn_app_with_mints(store, &["Rust", "Solidity"], mints).await;
+    let client = reqwest::Client::new();
+
+    let register = |lines: serde_json::Value| {
+        let request = client
+            .post(format!("{}/admin/distributions", base))
+            .header("x-eth-address", ADMIN)
+            .json(&json!({ "distributions": lines }));
+        async move {
+            let registered: serde_json::Value = request.send().await.unwrap().json().await.unwrap();
+            registered["distributionIds"][0]
+                .as_str()
+                .unwrap()
+                .to_string()
+        }
+    };
+    let post = |path: String| {
+        client
+            .post(format!("{}/admin/distributions{}", base, path))
+            .header("x-eth-address", ADMIN)
+    };
+    let submit = |id: String, tx_hash: String| {
+        let request =
+            post(format!("/{id}/submit")).json(&json!({ "txHash": tx_hash, "chainId": 80002 }));
+        async move {
+            assert_eq!(request.send().await.unwrap().status(), 200);
+        }
+    };
+    let reconcile = |id: String| {
+        let request = post(format!("/{id}/reconcile"));
+        async move {
+            let resp = request.send().await.unwrap();
+            assert_eq!(resp.status(), 200);
+            resp.json::<serde_json::Value>().await.unwrap()
+        }
+    };
+
+    let complete = register(json!([
+        { "address": a, "badgeName": "Rust", "amount": 80, "reason": "Indexer" },
+        { "address": b, "badgeName": "Rust", "amount": 40, "reason": "Docs" }
+    ]))
+    .await;
+    let partial = register(json!([
+        { "address": a, "badgeName": "Solidity", "amount": 10 },
+        { "address": b, "badgeName": "Solidity", "amount": 20 },
+        { "address": c, "badgeName": "Solidity", "amount": 30 }
+    ]))
+    .await;
+    let reverted = register(json!([{ "address": c, "badgeName": "Rust", "amount": 7 }])).await;
+    let pending = register(json!([{ "address": d, "badgeName": "Rust", "amount": 9 }])).await;
+    let draft = register(json!([{ "address": d, "badgeName": "Solidity", "amount": 1 }])).await;
+
+    submit(complete.clone(), tx("11")).await;
+    submit(partial.clone(), tx("22")).await;
+    submit(reverted.clone(), tx("33")).await;
+    submit(pending.clone(), tx("44")).await;
+
+    let report = reconcile(complete).await;
+    assert_eq!(report["matched"], true);
+    assert_eq!(report["matchedCount"], 2);
+    assert_eq!(report["status"], "confirmed");
+
+    let report = reconcile(partial.clone()).await;
+    assert_eq!(report["matched"], false);
+    assert_eq!(report["matchedCount"], 1);
+    assert_eq!(report["status"], "submitted");
+    assert_eq!(
+        report["issues"],
+        json!([
+            { "code": "amount_mismatch", "address": a, "badgeName": "Solidity", "expectedAmount": "10", "mintedAmount": "11" },
+            { "code": "missing", "address": c, "badgeName": "Solidity", "expectedAmount": "30", "mintedAmount": null },
+            { "code": "extra", "address": d, "badgeName": null, "expectedAmount": null, "mintedAmount": "5" }
+        ])
+    );
+
+    let report = reconcile(reverted).await;
+    assert_eq!(report["reverted"], true);
+    assert_eq!(report["status"], "failed");
+
+    let report = reconcile(pending.clone()).await;
+    assert_eq!(report["mined"], false);
+    assert_eq!(report["status"], "submitted");
+
+    let resp = post(format!("/{draft}/reconcile")).send().await.unwrap();
+    assert_eq!(resp.status(), reqwest::StatusCode::CONFLICT);
+
+    // The batch run only revisits distributions that are still submitted
+    let reports: Vec<serde_json::Value> = post("/reconcile".to_string())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let mut ids: Vec<&str> = reports
+        .iter()
+        .map(|r| r["distributionId"].as_str().unwrap())
+        .collect();
+    ids.sort();
+    let mut expected = vec![partial.as_str(), pending.as_str()];
+    expected.sort();
+    assert_eq!(ids, expected);
+}
+
+#[test]
+fn decodes_mints_from_receipt_logs() {
+    use ethers::types::{Address, Bytes, Log, H256, U256};
+    use ethers::utils::keccak256;
+
+    let token: Address = "0x00000000000000000000000000000000000000aa"
+        .parse()
+        .unwrap();
+    let recipient: Address = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e"
+        .parse()
+        .unwrap();
+    let other: Address = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        .parse()
+        .unwrap();
+    let topic = |address: Address| H256::from(address);
+    let word = |value: u64| {
+        let mut bytes = [0u8; 32];
+        U256::from(value).to_big_endian(&mut bytes);
+        bytes
+    };
+    let transfer = H256::from(keccak256("Transfer(address,address,uint256)"));
+    let minted = H256::from(keccak256("MintedWithReason(address,uint256,bytes)"));
+    let reason = H256::from(keccak256("Indexer"));
+    let log = |address: Address, topics: Vec<H256>, data: Vec<u8>| Log {
+        address,
+        topics,
+        data: Bytes::from(data),
+        ..Default::default()
+    };
+
+    let logs = vec![
+        // batchMintWithReason: Transfer then MintedWithReason for the same mint
+        log(
+            token,
+            vec![transfer, topic(Address::zero()), topic(recipient)],
+            word(40).to_vec(),
+        ),
+        log(
+            token,
+            vec![minted, topic(recipient), H256::from(word(40)), reason],
+            Vec::new(),
+        ),
+        // plain batchMint
+        log(
+            token,
+            vec![transfer, topic(Address::zero()), topic(other)],
+            word(5).to_vec(),
+        ),
+        // amounts past u128 are kept exactly
+        log(
+            token,
+            vec![transfer, topic(Address::zero()), topic(other)],
+            vec![0xff; 32],
+        ),
+        // regular transfer and another contract's mint are ignored
+        log(
+            token,
+            vec![transfer, topic(recipient), topic(other)],
+            word(1).to_vec(),
+        ),
+        log(
+            other,
+            vec![transfer, topic(Address::zero()), topic(other)],
+            word(9).to_vec(),
+        ),
+    ];
+
+    assert_eq!(
+        decode_mints(token, &logs),
+        vec![
+            MintEvent {
+                recipient: "0x742d35Cc6634C0532925a3b844Bc454e4438f44e".into(),
+                amount: TokenAmount::from(40u64),
+                reason_hash: Some(keccak256_hex(b"Indexer")),
+            },
+            MintEvent {
+                recipient: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".into(),
+                amount: TokenAmount::from(5u64),
+                reason_hash: None,
+            },
+            MintEvent {
+                recipient: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".into(),
+                amount: TokenAmount(U256::MAX),
+                reason_hash: None,
+            },
+        ]
+    );
+}
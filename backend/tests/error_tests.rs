@@ -0,0 +1,131 @@
+mod common;
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Request, StatusCode},
+    response::IntoResponse,
+};
+use common::offline_app;
+use guild_backend::domain::errors::AppError;
+use tower::ServiceExt;
+
+async fn response_parts(err: AppError) -> (StatusCode, serde_json::Value) {
+    let response = err.into_response();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[tokio::test]
+async fn not_found_maps_to_404_with_code() {
+    let (status, body) = response_parts(AppError::not_found("Profile not found")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["error"], "Profile not found");
+    assert_eq!(body["code"], "not_found");
+    assert!(body.get("details").is_none());
+}
+
+#[tokio::test]
+async fn validation_includes_field_details() {
+    let (status, body) = response_parts(AppError::invalid_field(
+        "github_login",
+        "Invalid GitHub handle format",
+    ))
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "validation_error");
+    assert_eq!(body["details"][0]["field"], "github_login");
+    assert_eq!(
+        body["details"][0]["message"],
+        "Invalid GitHub handle format"
+    );
+}
+
+#[tokio::test]
+async fn each_variant_has_its_own_status() {
+    let cases = [
+        (
+            AppError::conflict("taken"),
+            StatusCode::CONFLICT,
+            "conflict",
+        ),
+        (
+            AppError::unprocessable("bad lines", Vec::new()),
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "unprocessable",
+        ),
+        (
+            AppError::forbidden("nope"),
+            StatusCode::FORBIDDEN,
+            "forbidden",
+        ),
+        (
+            AppError::internal("boom"),
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "internal_error",
+        ),
+    ];
+
+    for (err, expected_status, expected_code) in cases {
+        let (status, body) = response_parts(err).await;
+        assert_eq!(status, expected_status);
+        assert_eq!(body["code"], expected_code);
+    }
+}
+
+#[test]
+fn row_not_found_converts_to_not_found() {
+    let err: AppError = sqlx::Error::RowNotFound.into();
+    assert!(matches!(err, AppError::NotFound(_)));
+}
+
+#[tokio::test]
+async fn internal_errors_hide_their_detail() {
+    let (status, body) = response_parts(AppError::internal(
+        "Database error: relation \"profiles\" does not exist",
+    ))
+    .await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(body["error"], "Internal server error");
+}
+
+/// Sends `request` to the offline router and returns the status and JSON body.
+async fn send(request: Request<Body>) -> (StatusCode, serde_json::Value) {
+    let response = offline_app().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[tokio::test]
+async fn extractor_rejections_use_the_error_body() {
+    let create_project = |content_type: &str, body: &'static str| {
+        Request::post("/v1/projects")
+            .header(
+                "x-eth-address",
+                "0x742d35Cc6634C0532925a3b844Bc454e4438f44e",
+            )
+            .header(header::CONTENT_TYPE, content_type)
+            .body(Body::from(body))
+            .unwrap()
+    };
+    let requests = [
+        create_project("application/json", "{"),
+        create_project("application/json", r#"{"name": "No description"}"#),
+        create_project("text/plain", "{}"),
+        Request::get("/v1/projects?limit=many")
+            .body(Body::empty())
+            .unwrap(),
+        Request::get("/v1/profiles/%FF")
+            .body(Body::empty())
+            .unwrap(),
+    ];
+
+    for request in requests {
+        let uri = request.uri().clone();
+        let (status, body) = send(request).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{uri}: {body}");
+        assert_eq!(body["code"], "validation_error", "{uri}");
+        assert!(!body["error"].as_str().unwrap().is_empty(), "{uri}");
+    }
+}
@@ -7,6 +7,7 @@ mod github_sync_tests {
         derive_points, sync_github_issues, transform_issue,
     };
     use guild_backend::domain::entities::github_issue::GithubIssue;
+    use guild_backend::domain::errors::AppError;
     use guild_backend::domain::repositories::github_issue_repository::GithubIssueRepository;
     use guild_backend::domain::services::github_service::{
         GitHubApiIssue, GitHubApiLabel, GitHubApiUser, GithubService,
@@ -22,7 +23,7 @@ mod github_sync_tests {
 
     #[async_trait]
     impl GithubIssueRepository for FakeGithubIssueRepo {
-        async fn upsert(&self, issue: &GithubIssue) -> Result<(), Box<dyn std::error::Error>> {
+        async fn upsert(&self, issue: &GithubIssue) -> Result<(), AppError> {
             let mut list = self.issues.lock().unwrap();
             // Upsert: replace if exists, otherwise insert
             if let Some(existing) = list
@@ -40,7 +41,7 @@ mod github_sync_tests {
             &self,
             repo_id: i64,
             github_issue_id: i64,
-        ) -> Result<Option<GithubIssue>, Box<dyn std::error::Error>> {
+        ) -> Result<Option<GithubIssue>, AppError> {
             let list = self.issues.lock().unwrap();
             Ok(list
                 .iter()
@@ -52,7 +53,7 @@ mod github_sync_tests {
             &self,
             repo: &str,
             state: Option<&str>,
-        ) -> Result<Vec<GithubIssue>, Box<dyn std::error::Error>> {
+        ) -> Result<Vec<GithubIssue>, AppError> {
             let list = self.issues.lock().unwrap();
             Ok(list
                 .iter()
@@ -1,9 +1,12 @@
 use guild_backend::application::dtos::profile_dtos::ProfileResponse;
+use guild_backend::domain::entities::ReviewPolicy;
 use guild_backend::infrastructure::repositories::postgres_distribution_repository::PostgresDistributionRepository;
 use guild_backend::infrastructure::repositories::postgres_github_issue_repository::PostgresGithubIssueRepository;
 use guild_backend::infrastructure::repositories::postgres_project_repository::PostgresProjectRepository;
+use guild_backend::infrastructure::services::indexer_badge_registry_service::MockBadgeRegistryService;
 use guild_backend::infrastructure::services::rest_github_service::RestGithubService;
-use guild_backend::presentation::api::{test_api, AppState};
+use guild_backend::infrastructure::services::rpc_mint_event_service::MockMintEventService;
+use guild_backend::presentation::api::{build_app, AppState, AuthStrategy};
 use serde_json::json;
 use std::sync::Arc;
 use tokio::net::TcpListener;
@@ -36,8 +39,11 @@ async fn valid_github_handle_works() {
         auth_service: std::sync::Arc::new(auth_service),
         github_issue_repository,
         github_service,
+        badge_registry: Arc::new(MockBadgeRegistryService::default()),
+        mint_event_service: Arc::new(MockMintEventService::new()),
+        review_policy: ReviewPolicy::default(),
     };
-    let app = test_api(state);
+    let app = build_app(state, AuthStrategy::TestHeader);
 
     let server = axum::serve(listener, app);
     tokio::spawn(async move { server.await.unwrap() });
@@ -113,8 +119,11 @@ async fn invalid_format_rejected() {
         auth_service: std::sync::Arc::new(auth_service),
         github_issue_repository,
         github_service,
+        badge_registry: Arc::new(MockBadgeRegistryService::default()),
+        mint_event_service: Arc::new(MockMintEventService::new()),
+        review_policy: ReviewPolicy::default(),
     };
-    let app = test_api(state);
+    let app = build_app(state, AuthStrategy::TestHeader);
 
     let server = axum::serve(listener, app);
     tokio::spawn(async move { server.await.unwrap() });
@@ -196,8 +205,11 @@ async fn conflict_case_insensitive() {
         auth_service: std::sync::Arc::new(auth_service),
         github_issue_repository,
         github_service,
+        badge_registry: Arc::new(MockBadgeRegistryService::default()),
+        mint_event_service: Arc::new(MockMintEventService::new()),
+        review_policy: ReviewPolicy::default(),
     };
-    let app = test_api(state);
+    let app = build_app(state, AuthStrategy::TestHeader);
 
     let server = axum::serve(listener, app);
     tokio::spawn(async move { server.await.unwrap() });
@@ -279,5 +291,6 @@ async fn conflict_case_insensitive() {
     if let Ok(err_json) = conflict_resp.json::<serde_json::Value>().await {
         let msg = err_json["error"].as_str().unwrap_or("");
         assert!(msg.contains("already taken"));
+        assert_eq!(err_json["code"], "conflict");
     }
 }
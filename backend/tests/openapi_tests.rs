@@ -0,0 +1,113 @@
+mod common;
+
+use std::collections::BTreeSet;
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{Method, Request, StatusCode},
+};
+use common::offline_app;
+use guild_backend::presentation::api::{api_paths, API_V1_PREFIX};
+use guild_backend::presentation::openapi::ApiDoc;
+use regex::Regex;
+use tower::ServiceExt;
+use utoipa::OpenApi;
+
+const METHODS: [Method; 5] = [
+    Method::GET,
+    Method::POST,
+    Method::PUT,
+    Method::PATCH,
+    Method::DELETE,
+];
+
+fn documented_operations() -> BTreeSet<(String, String)> {
+    let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let mut operations = BTreeSet::new();
+    for (path, item) in spec["paths"].as_object().unwrap() {
+        for method in item.as_object().unwrap().keys() {
+            operations.insert((method.to_uppercase(), path.clone()));
+        }
+    }
+    operations
+}
+
+/// Asks the built router which methods it serves on each path it registers,
+/// named in OpenAPI syntax. The router answers wrong methods with 405 and
+/// unknown paths with an empty 404; handler-level 404s always carry a JSON
+/// error body.
+async fn routed_operations() -> BTreeSet<(String, String)> {
+    let app = offline_app();
+    let param = Regex::new(r":(\w+)").unwrap();
+    let mut operations = BTreeSet::new();
+
+    for route in api_paths() {
+        let uri = format!(
+            "{API_V1_PREFIX}{}",
+            param.replace_all(route, "0x742d35Cc6634C0532925a3b844Bc454e4438f44e")
+        );
+        // Trailing-slash aliases are documented under the path they alias
+        let path = param
+            .replace_all(route.strip_suffix('/').unwrap_or(route), "{$1}")
+            .into_owned();
+        for method in METHODS {
+            let request = Request::builder()
+                .method(method.clone())
+                .uri(uri.as_str())
+                .header(
+                    "x-eth-address",
+                    "0x742d35Cc6634C0532925a3b844Bc454e4438f44e",
+                )
+                .body(Body::empty())
+                .unwrap();
+
+            let response = app.clone().oneshot(request).await.unwrap();
+            let status = response.status();
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+            let unrouted = status == StatusCode::METHOD_NOT_ALLOWED
+                || (status == StatusCode::NOT_FOUND && body.is_empty());
+            if !unrouted {
+                operations.insert((method.to_string(), path.clone()));
+            }
+        }
+    }
+    operations
+}
+
+#[tokio::test]
+async fn spec_matches_routed_operations() {
+    let documented = documented_operations();
+    let routed = routed_operations().await;
+
+    let missing_from_spec: Vec<_> = routed.difference(&documented).collect();
+    let missing_from_router: Vec<_> = documented.difference(&routed).collect();
+
+    assert!(
+        missing_from_spec.is_empty(),
+        "routed operations without OpenAPI docs: {missing_from_spec:?}"
+    );
+    assert!(
+        missing_from_router.is_empty(),
+        "documented operations without a route: {missing_from_router:?}"
+    );
+}
+
+#[tokio::test]
+async fn openapi_document_is_served() {
+    let response = offline_app()
+        .oneshot(
+            Request::builder()
+                .uri("/openapi.json")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let spec: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    assert!(spec["openapi"].as_str().unwrap().starts_with("3."));
+    assert!(spec["components"]["schemas"]["ProfileResponse"].is_object());
+}
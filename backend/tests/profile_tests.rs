@@ -6,118 +6,24 @@ mod github_handle_tests {
         CreateProfileRequest, UpdateProfileRequest,
     };
     use guild_backend::domain::entities::profile::Profile;
+    use guild_backend::domain::errors::AppError;
     use guild_backend::domain::repositories::profile_repository::ProfileRepository;
     use guild_backend::domain::value_objects::WalletAddress;
+    use guild_backend::infrastructure::repositories::{InMemoryProfileRepository, InMemoryStore};
     use std::sync::Arc;
 
-    // A fake in-memory repository for testing
-    struct FakeRepo {
-        profiles: std::sync::Mutex<Vec<Profile>>,
-    }
-
-    #[async_trait::async_trait]
-    impl ProfileRepository for FakeRepo {
-        async fn find_by_address(
-            &self,
-            address: &WalletAddress,
-        ) -> Result<Option<Profile>, Box<dyn std::error::Error>> {
-            let list = self.profiles.lock().unwrap();
-            Ok(list.iter().find(|&p| p.address == *address).cloned())
-        }
-
-        async fn find_all(&self) -> Result<Vec<Profile>, Box<dyn std::error::Error>> {
-            let list = self.profiles.lock().unwrap();
-            Ok(list.clone())
-        }
-
-        async fn create(&self, profile: &Profile) -> Result<(), Box<dyn std::error::Error>> {
-            let mut list = self.profiles.lock().unwrap();
-            list.push(profile.clone());
-            Ok(())
-        }
-
-        async fn update(&self, profile: &Profile) -> Result<(), Box<dyn std::error::Error>> {
-            let mut list = self.profiles.lock().unwrap();
-            if let Some(slot) = list.iter_mut().find(|p| p.address == profile.address) {
-                *slot = profile.clone();
-                Ok(())
-            } else {
-                Err("Not found".into())
-            }
-        }
-
-        async fn delete(&self, _address: &WalletAddress) -> Result<(), Box<dyn std::error::Error>> {
-            unimplemented!()
-        }
-
-        async fn find_by_github_login(
-            &self,
-            github_login: &str,
-        ) -> Result<Option<Profile>, Box<dyn std::error::Error + Send + Sync>> {
-            let lower = github_login.to_lowercase();
-            let list = self.profiles.lock().unwrap();
-            Ok(list
-                .iter()
-                .find(|&p| {
-                    p.github_login
-                        .as_ref()
-                        .is_some_and(|h| h.to_lowercase() == lower)
-                })
-                .cloned())
-        }
-
-        async fn find_by_twitter_handle(
-            &self,
-            twitter_handle: &str,
-        ) -> Result<Option<Profile>, Box<dyn std::error::Error + Send + Sync>> {
-            let lower = twitter_handle.to_lowercase();
-            let list = self.profiles.lock().unwrap();
-            Ok(list
-                .iter()
-                .find(|&p| {
-                    p.twitter_handle
-                        .as_ref()
-                        .is_some_and(|h| h.to_lowercase() == lower)
-                })
-                .cloned())
-        }
-
-        async fn find_by_linkedin_account(
-            &self,
-            linkedin_account: &str,
-        ) -> Result<Option<Profile>, Box<dyn std::error::Error + Send + Sync>> {
-            let lower = linkedin_account.to_lowercase();
-            let list = self.profiles.lock().unwrap();
-            Ok(list
-                .iter()
-                .find(|&p| {
-                    p.linkedin_account
-                        .as_ref()
-                        .is_some_and(|h| h.to_lowercase() == lower)
-                })
-                .cloned())
-        }
-
-        async fn get_login_nonce_by_wallet_address(
-            &self,
-            _address: &WalletAddress,
-        ) -> Result<Option<i64>, Box<dyn std::error::Error>> {
-            Ok(Some(1))
-        }
-
-        async fn increment_login_nonce(
-            &self,
-            _address: &WalletAddress,
-        ) -> Result<(), Box<dyn std::error::Error>> {
-            Ok(())
+    /// An in-memory repository holding the given profiles.
+    async fn repo_with(profiles: &[Profile]) -> Arc<InMemoryProfileRepository> {
+        let repo = Arc::new(InMemoryProfileRepository::new(InMemoryStore::new()));
+        for profile in profiles {
+            repo.create(profile).await.unwrap();
         }
+        repo
     }
 
     #[tokio::test]
     async fn create_profile_with_linkedin_account_succeeds() {
-        let repo = Arc::new(FakeRepo {
-            profiles: std::sync::Mutex::new(vec![]),
-        });
+        let repo = repo_with(&[]).await;
 
         let req = CreateProfileRequest {
             name: "Alice".into(),
@@ -154,9 +60,7 @@ mod github_handle_tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
-        let repo = Arc::new(FakeRepo {
-            profiles: std::sync::Mutex::new(vec![profile.clone()]),
-        });
+        let repo = repo_with(std::slice::from_ref(&profile)).await;
 
         // Try updating with a valid handle
         let req = UpdateProfileRequest {
@@ -189,9 +93,7 @@ mod github_handle_tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
-        let repo = Arc::new(FakeRepo {
-            profiles: std::sync::Mutex::new(vec![profile.clone()]),
-        });
+        let repo = repo_with(std::slice::from_ref(&profile)).await;
 
         // Try invalid handle (has @)
         let req = UpdateProfileRequest {
@@ -205,8 +107,10 @@ mod github_handle_tests {
 
         let err = update_profile(repo.clone(), profile.address.to_string(), req).await;
         assert!(err.is_err());
-        let err_msg = err.unwrap_err();
-        assert!(err_msg.contains("Invalid GitHub handle format"));
+        let err = err.unwrap_err();
+        assert_eq!(err.code(), "validation_error");
+        assert_eq!(err.details()[0].field, "github_login");
+        assert!(err.to_string().contains("Invalid GitHub handle format"));
     }
 
     #[tokio::test]
@@ -239,9 +143,7 @@ mod github_handle_tests {
             updated_at: chrono::Utc::now(),
         };
 
-        let repo = Arc::new(FakeRepo {
-            profiles: std::sync::Mutex::new(vec![profile1.clone(), profile2.clone()]),
-        });
+        let repo = repo_with(&[profile1.clone(), profile2.clone()]).await;
 
         // Try to claim "alice" from profile2 (lowercase) → conflict
         let req = UpdateProfileRequest {
@@ -255,8 +157,9 @@ mod github_handle_tests {
 
         let err = update_profile(repo.clone(), profile2.address.to_string(), req).await;
         assert!(err.is_err());
-        let err_msg = err.unwrap_err();
-        assert!(err_msg.contains("GitHub handle already taken"));
+        let err = err.unwrap_err();
+        assert!(matches!(err, AppError::Conflict(_)));
+        assert!(err.to_string().contains("GitHub handle already taken"));
     }
 
     #[tokio::test]
@@ -274,9 +177,7 @@ mod github_handle_tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
-        let repo = Arc::new(FakeRepo {
-            profiles: std::sync::Mutex::new(vec![profile.clone()]),
-        });
+        let repo = repo_with(std::slice::from_ref(&profile)).await;
 
         // Try updating to empty github handle
         let req = UpdateProfileRequest {
@@ -309,9 +210,7 @@ mod github_handle_tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
-        let repo = Arc::new(FakeRepo {
-            profiles: std::sync::Mutex::new(vec![profile.clone()]),
-        });
+        let repo = repo_with(std::slice::from_ref(&profile)).await;
 
         // Try updating with the same handle (should succeed)
         let req = UpdateProfileRequest {
@@ -344,9 +243,7 @@ mod github_handle_tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
-        let repo = Arc::new(FakeRepo {
-            profiles: std::sync::Mutex::new(vec![profile.clone()]),
-        });
+        let repo = repo_with(std::slice::from_ref(&profile)).await;
 
         let req = UpdateProfileRequest {
             name: None,
@@ -378,9 +275,7 @@ mod github_handle_tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
-        let repo = Arc::new(FakeRepo {
-            profiles: std::sync::Mutex::new(vec![profile.clone()]),
-        });
+        let repo = repo_with(std::slice::from_ref(&profile)).await;
 
         // Twitter handles can't have @ or be longer than 15 chars
         let req = UpdateProfileRequest {
@@ -394,7 +289,10 @@ mod github_handle_tests {
 
         let err = update_profile(repo.clone(), profile.address.to_string(), req).await;
         assert!(err.is_err());
-        assert!(err.unwrap_err().contains("Invalid Twitter handle format"));
+        assert!(err
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid Twitter handle format"));
     }
 
     #[tokio::test]
@@ -425,9 +323,7 @@ mod github_handle_tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
-        let repo = Arc::new(FakeRepo {
-            profiles: std::sync::Mutex::new(vec![profile1.clone(), profile2.clone()]),
-        });
+        let repo = repo_with(&[profile1.clone(), profile2.clone()]).await;
 
         // Try to claim "takenhandle" (lowercase) from profile2 → conflict
         let req = UpdateProfileRequest {
@@ -441,7 +337,10 @@ mod github_handle_tests {
 
         let err = update_profile(repo.clone(), profile2.address.to_string(), req).await;
         assert!(err.is_err());
-        assert!(err.unwrap_err().contains("Twitter handle already taken"));
+        assert!(err
+            .unwrap_err()
+            .to_string()
+            .contains("Twitter handle already taken"));
     }
 
     #[tokio::test]
@@ -459,9 +358,7 @@ mod github_handle_tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
-        let repo = Arc::new(FakeRepo {
-            profiles: std::sync::Mutex::new(vec![profile.clone()]),
-        });
+        let repo = repo_with(std::slice::from_ref(&profile)).await;
 
         let req = UpdateProfileRequest {
             name: None,
@@ -474,7 +371,10 @@ mod github_handle_tests {
 
         let err = update_profile(repo.clone(), profile.address.to_string(), req).await;
         assert!(err.is_err());
-        assert!(err.unwrap_err().contains("Invalid LinkedIn account format"));
+        assert!(err
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid LinkedIn account format"));
     }
 
     #[tokio::test]
@@ -505,9 +405,7 @@ mod github_handle_tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
-        let repo = Arc::new(FakeRepo {
-            profiles: std::sync::Mutex::new(vec![profile1.clone(), profile2.clone()]),
-        });
+        let repo = repo_with(&[profile1.clone(), profile2.clone()]).await;
 
         let req = UpdateProfileRequest {
             name: None,
@@ -520,7 +418,10 @@ mod github_handle_tests {
 
         let err = update_profile(repo.clone(), profile2.address.to_string(), req).await;
         assert!(err.is_err());
-        assert!(err.unwrap_err().contains("LinkedIn account already taken"));
+        assert!(err
+            .unwrap_err()
+            .to_string()
+            .contains("LinkedIn account already taken"));
     }
 
     #[tokio::test]
@@ -538,9 +439,7 @@ mod github_handle_tests {
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         };
-        let repo = Arc::new(FakeRepo {
-            profiles: std::sync::Mutex::new(vec![profile.clone()]),
-        });
+        let repo = repo_with(std::slice::from_ref(&profile)).await;
 
         let req = UpdateProfileRequest {
             name: None,
@@ -557,3 +456,89 @@ mod github_handle_tests {
         assert_eq!(resp.linkedin_account, Some("Eve-LinkedIn".to_string()));
     }
 }
+
+mod profile_listing_tests {
+    use guild_backend::application::dtos::profile_dtos::ListProfilesQuery;
+    use guild_backend::application::queries::get_all_profiles::get_all_profiles;
+    use guild_backend::domain::entities::profile::Profile;
+    use guild_backend::domain::repositories::ProfileRepository;
+    use guild_backend::domain::value_objects::WalletAddress;
+    use guild_backend::infrastructure::repositories::{InMemoryProfileRepository, InMemoryStore};
+    use std::sync::Arc;
+
+    async fn repo_with(count: usize) -> Arc<InMemoryProfileRepository> {
+        let repo = Arc::new(InMemoryProfileRepository::new(InMemoryStore::new()));
+        for i in 0..count {
+            let mut profile = Profile::new(WalletAddress(format!("0x{:040x}", i + 1)));
+            profile.name = Some(format!("Member {i}"));
+            repo.create(&profile).await.unwrap();
+        }
+        repo
+    }
+
+    #[tokio::test]
+    async fn pages_follow_the_encoded_cursor() {
+        let repo = repo_with(5).await;
+
+        let mut seen = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = get_all_profiles(
+                repo.clone(),
+                ListProfilesQuery {
+                    sort: Some("name".into()),
+                    limit: Some(2),
+                    cursor,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+            assert_eq!(page.total, 5);
+            seen.extend(page.items.into_iter().map(|p| p.name));
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(
+            seen,
+            (0..5).map(|i| format!("Member {i}")).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_listing_params() {
+        let repo = repo_with(3).await;
+        let field = |params: ListProfilesQuery| {
+            let repo = repo.clone();
+            async move {
+                let err = get_all_profiles(repo, params).await.unwrap_err();
+                assert_eq!(err.code(), "validation_error");
+                err.details()[0].field.clone()
+            }
+        };
+
+        let params =
+            |sort: Option<&str>, limit: Option<i64>, cursor: Option<&str>| ListProfilesQuery {
+                sort: sort.map(str::to_string),
+                limit,
+                cursor: cursor.map(str::to_string),
+                ..Default::default()
+            };
+        assert_eq!(field(params(Some("karma"), None, None)).await, "sort");
+        assert_eq!(field(params(None, Some(0), None)).await, "limit");
+        assert_eq!(field(params(None, Some(101), None)).await, "limit");
+        assert_eq!(field(params(None, None, Some("not-hex"))).await, "cursor");
+
+        // A cursor only continues the sort it was issued for
+        let page = get_all_profiles(repo.clone(), params(Some("name"), Some(1), None))
+            .await
+            .unwrap();
+        let cursor = page.next_cursor.unwrap();
+        assert_eq!(
+            field(params(Some("newest"), Some(1), Some(&cursor))).await,
+            "cursor"
+        );
+    }
+}
@@ -7,106 +7,42 @@ mod project_tests {
         CreateProjectRequest, UpdateProjectRequest,
     };
     use guild_backend::application::queries::get_project::get_project;
-    use guild_backend::domain::entities::projects::{Project, ProjectId, ProjectStatus};
-    use guild_backend::domain::repositories::project_repository::ProjectRepository;
+    use guild_backend::domain::entities::profile::Profile;
+    use guild_backend::domain::entities::projects::{Project, ProjectStatus};
+    use guild_backend::domain::errors::AppError;
+    use guild_backend::domain::repositories::{ProfileRepository, ProjectRepository};
     use guild_backend::domain::value_objects::WalletAddress;
+    use guild_backend::infrastructure::repositories::{
+        InMemoryProfileRepository, InMemoryProjectRepository, InMemoryStore,
+    };
     use std::sync::Arc;
 
-    // A fake in-memory repository for testing
-    struct FakeProjectRepo {
-        projects: std::sync::Mutex<Vec<Project>>,
-    }
-
-    #[async_trait::async_trait]
-    impl ProjectRepository for FakeProjectRepo {
-        async fn create(&self, project: &Project) -> Result<(), Box<dyn std::error::Error>> {
-            let mut list = self.projects.lock().unwrap();
-            list.push(project.clone());
-            Ok(())
-        }
-
-        async fn find_by_id(
-            &self,
-            id: &ProjectId,
-        ) -> Result<Option<Project>, Box<dyn std::error::Error>> {
-            let list = self.projects.lock().unwrap();
-            Ok(list.iter().find(|p| p.id == *id).cloned())
-        }
-
-        async fn find_all(
-            &self,
-            status: Option<ProjectStatus>,
-            creator: Option<&WalletAddress>,
-            _limit: Option<i64>,
-            _offset: Option<i64>,
-        ) -> Result<Vec<Project>, Box<dyn std::error::Error>> {
-            let list = self.projects.lock().unwrap();
-            let filtered: Vec<Project> = list
-                .iter()
-                .filter(|p| {
-                    let status_match = status.map(|s| p.status == s).unwrap_or(true);
-                    let creator_match = creator.map(|c| &p.creator == c).unwrap_or(true);
-                    status_match && creator_match
-                })
-                .cloned()
-                .collect();
-            Ok(filtered)
-        }
-
-        async fn find_by_creator(
-            &self,
-            creator: &WalletAddress,
-        ) -> Result<Vec<Project>, Box<dyn std::error::Error>> {
-            let list = self.projects.lock().unwrap();
-            Ok(list
-                .iter()
-                .filter(|p| &p.creator == creator)
-                .cloned()
-                .collect())
-        }
-
-        async fn update(&self, project: &Project) -> Result<(), Box<dyn std::error::Error>> {
-            let mut list = self.projects.lock().unwrap();
-            if let Some(slot) = list.iter_mut().find(|p| p.id == project.id) {
-                *slot = project.clone();
-                Ok(())
-            } else {
-                Err("Project not found".into())
-            }
-        }
-
-        async fn delete(&self, id: &ProjectId) -> Result<(), Box<dyn std::error::Error>> {
-            let mut list = self.projects.lock().unwrap();
-            list.retain(|p| &p.id != id);
-            Ok(())
-        }
-
-        async fn exists(&self, id: &ProjectId) -> Result<bool, Box<dyn std::error::Error>> {
-            let list = self.projects.lock().unwrap();
-            Ok(list.iter().any(|p| &p.id == id))
-        }
+    const CREATOR: &str = "0x1234567890123456789012345678901234567890";
 
-        async fn profile_exists(
-            &self,
-            _address: &WalletAddress,
-        ) -> Result<bool, Box<dyn std::error::Error>> {
-            // For testing, assume profile always exists
-            Ok(true)
+    /// A repository holding the given projects, with a profile for CREATOR.
+    async fn repo_with(projects: &[Project]) -> Arc<InMemoryProjectRepository> {
+        let store = InMemoryStore::new();
+        InMemoryProfileRepository::new(store.clone())
+            .create(&Profile::new(WalletAddress(CREATOR.into())))
+            .await
+            .unwrap();
+        let repo = Arc::new(InMemoryProjectRepository::new(store));
+        for project in projects {
+            repo.create(project).await.unwrap();
         }
+        repo
     }
 
     #[tokio::test]
     async fn create_project_succeeds() {
-        let repo = Arc::new(FakeProjectRepo {
-            projects: std::sync::Mutex::new(vec![]),
-        });
+        let repo = repo_with(&[]).await;
 
-        let creator_address = "0x1234567890123456789012345678901234567890".to_string();
+        let creator_address = CREATOR.to_string();
 
         let req = CreateProjectRequest {
             name: "Test Project".into(),
             description: "A test project".into(),
-            status: ProjectStatus::Proposal,
+            status: Some(ProjectStatus::Proposal),
         };
 
         let result = create_project(repo.clone(), creator_address.clone(), req).await;
@@ -119,29 +55,27 @@ mod project_tests {
 
     #[tokio::test]
     async fn create_project_validates_name() {
-        let repo = Arc::new(FakeProjectRepo {
-            projects: std::sync::Mutex::new(vec![]),
-        });
+        let repo = repo_with(&[]).await;
 
-        let creator_address = "0x1234567890123456789012345678901234567890".to_string();
+        let creator_address = CREATOR.to_string();
 
         // Empty name should fail
         let req = CreateProjectRequest {
             name: "".into(),
             description: "Description".into(),
-            status: ProjectStatus::Proposal,
+            status: Some(ProjectStatus::Proposal),
         };
 
         let result = create_project(repo.clone(), creator_address, req).await;
         assert!(result.is_err());
-        let err_msg = result.unwrap_err();
-        assert!(err_msg.contains("empty"));
+        let err = result.unwrap_err();
+        assert_eq!(err.details()[0].field, "name");
+        assert!(err.to_string().contains("empty"));
     }
 
     #[tokio::test]
     async fn update_project_by_creator_succeeds() {
-        let creator =
-            WalletAddress::new("0x1234567890123456789012345678901234567890".to_string()).unwrap();
+        let creator = WalletAddress(CREATOR.into());
 
         let project = Project::new(
             "Original Name".into(),
@@ -151,14 +85,12 @@ mod project_tests {
         );
         let project_id = project.id;
 
-        let repo = Arc::new(FakeProjectRepo {
-            projects: std::sync::Mutex::new(vec![project]),
-        });
+        let repo = repo_with(&[project]).await;
 
         let req = UpdateProjectRequest {
             name: Some("Updated Name".into()),
             description: None,
-            status: Some(ProjectStatus::Ongoing),
+            status: Some(ProjectStatus::Proposal),
         };
 
         let result = update_project(
@@ -172,13 +104,43 @@ mod project_tests {
         assert!(result.is_ok());
         let response = result.unwrap();
         assert_eq!(response.name, "Updated Name");
-        assert_eq!(response.status, ProjectStatus::Ongoing);
+        assert_eq!(response.status, ProjectStatus::Proposal);
+    }
+
+    #[tokio::test]
+    async fn update_project_cannot_change_status() {
+        let creator = WalletAddress(CREATOR.into());
+
+        let project = Project::new(
+            "Original Name".into(),
+            "Original Description".into(),
+            ProjectStatus::Proposal,
+            creator.clone(),
+        );
+        let project_id = project.id;
+
+        let repo = repo_with(&[project]).await;
+
+        let req = UpdateProjectRequest {
+            name: None,
+            description: None,
+            status: Some(ProjectStatus::Ongoing),
+        };
+
+        let err = update_project(
+            repo.clone(),
+            creator.to_string(),
+            project_id.value().to_string(),
+            req,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.details()[0].field, "status");
     }
 
     #[tokio::test]
     async fn update_project_by_non_creator_fails() {
-        let creator =
-            WalletAddress::new("0x1234567890123456789012345678901234567890".to_string()).unwrap();
+        let creator = WalletAddress(CREATOR.into());
         let other_user =
             WalletAddress::new("0x0987654321098765432109876543210987654321".to_string()).unwrap();
 
@@ -190,9 +152,7 @@ mod project_tests {
         );
         let project_id = project.id;
 
-        let repo = Arc::new(FakeProjectRepo {
-            projects: std::sync::Mutex::new(vec![project]),
-        });
+        let repo = repo_with(&[project]).await;
 
         let req = UpdateProjectRequest {
             name: Some("Hacked Name".into()),
@@ -209,14 +169,13 @@ mod project_tests {
         .await;
 
         assert!(result.is_err());
-        let err_msg = result.unwrap_err();
-        assert!(err_msg.contains("Only the creator"));
+        let err_msg = result.unwrap_err().to_string();
+        assert!(err_msg.contains("Only maintainers"));
     }
 
     #[tokio::test]
     async fn delete_project_by_creator_succeeds() {
-        let creator =
-            WalletAddress::new("0x1234567890123456789012345678901234567890".to_string()).unwrap();
+        let creator = WalletAddress(CREATOR.into());
 
         let project = Project::new(
             "To Delete".into(),
@@ -226,9 +185,7 @@ mod project_tests {
         );
         let project_id = project.id;
 
-        let repo = Arc::new(FakeProjectRepo {
-            projects: std::sync::Mutex::new(vec![project]),
-        });
+        let repo = repo_with(&[project]).await;
 
         let result = delete_project(
             repo.clone(),
@@ -246,8 +203,7 @@ mod project_tests {
 
     #[tokio::test]
     async fn delete_project_by_non_creator_fails() {
-        let creator =
-            WalletAddress::new("0x1234567890123456789012345678901234567890".to_string()).unwrap();
+        let creator = WalletAddress(CREATOR.into());
         let other_user =
             WalletAddress::new("0x0987654321098765432109876543210987654321".to_string()).unwrap();
 
@@ -259,9 +215,7 @@ mod project_tests {
         );
         let project_id = project.id;
 
-        let repo = Arc::new(FakeProjectRepo {
-            projects: std::sync::Mutex::new(vec![project]),
-        });
+        let repo = repo_with(&[project]).await;
 
         let result = delete_project(
             repo.clone(),
@@ -271,14 +225,14 @@ mod project_tests {
         .await;
 
         assert!(result.is_err());
-        let err_msg = result.unwrap_err();
-        assert!(err_msg.contains("Only the creator"));
+        let err = result.unwrap_err();
+        assert!(matches!(err, AppError::Forbidden(_)));
+        assert!(err.to_string().contains("Only maintainers"));
     }
 
     #[tokio::test]
     async fn get_project_by_id_succeeds() {
-        let creator =
-            WalletAddress::new("0x1234567890123456789012345678901234567890".to_string()).unwrap();
+        let creator = WalletAddress(CREATOR.into());
 
         let project = Project::new(
             "Findable Project".into(),
@@ -288,9 +242,7 @@ mod project_tests {
         );
         let project_id = project.id;
 
-        let repo = Arc::new(FakeProjectRepo {
-            projects: std::sync::Mutex::new(vec![project.clone()]),
-        });
+        let repo = repo_with(&[project]).await;
 
         let result = get_project(repo.clone(), project_id.value().to_string()).await;
 
@@ -302,15 +254,781 @@ mod project_tests {
 
     #[tokio::test]
     async fn get_nonexistent_project_fails() {
-        let repo = Arc::new(FakeProjectRepo {
-            projects: std::sync::Mutex::new(vec![]),
-        });
+        let repo = repo_with(&[]).await;
 
         let fake_id = uuid::Uuid::new_v4().to_string();
         let result = get_project(repo.clone(), fake_id).await;
 
         assert!(result.is_err());
-        let err_msg = result.unwrap_err();
-        assert!(err_msg.contains("not found"));
+        let err = result.unwrap_err();
+        assert!(matches!(err, AppError::NotFound(_)));
+        assert!(err.to_string().contains("not found"));
+    }
+}
+
+mod project_listing_tests {
+    use guild_backend::application::dtos::project_dtos::ListProjectsQuery;
+    use guild_backend::application::queries::get_all_projects::get_all_projects;
+    use guild_backend::domain::entities::profile::Profile;
+    use guild_backend::domain::entities::projects::{Project, ProjectStatus};
+    use guild_backend::domain::repositories::{ProfileRepository, ProjectRepository};
+    use guild_backend::domain::value_objects::WalletAddress;
+    use guild_backend::infrastructure::repositories::{
+        InMemoryProfileRepository, InMemoryProjectRepository, InMemoryStore,
+    };
+    use std::sync::Arc;
+
+    async fn repo_with(count: usize) -> Arc<InMemoryProjectRepository> {
+        let store = InMemoryStore::new();
+        let creator = WalletAddress(format!("0x{:040x}", 1));
+        InMemoryProfileRepository::new(store.clone())
+            .create(&Profile::new(creator.clone()))
+            .await
+            .unwrap();
+        let repo = Arc::new(InMemoryProjectRepository::new(store));
+        for i in 0..count {
+            let project = Project::new(
+                format!("Project {i}"),
+                "Listed".into(),
+                ProjectStatus::Proposal,
+                creator.clone(),
+            );
+            repo.create(&project).await.unwrap();
+        }
+        repo
+    }
+
+    #[tokio::test]
+    async fn pages_follow_the_encoded_cursor() {
+        let repo = repo_with(5).await;
+
+        let mut seen = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = get_all_projects(
+                repo.clone(),
+                ListProjectsQuery {
+                    sort: Some("name".into()),
+                    limit: Some(2),
+                    cursor,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+            assert_eq!(page.total, 5);
+            seen.extend(page.items.into_iter().map(|p| p.name));
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(
+            seen,
+            (0..5).map(|i| format!("Project {i}")).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_listing_params() {
+        let repo = repo_with(2).await;
+        let field = |params: ListProjectsQuery| {
+            let repo = repo.clone();
+            async move {
+                let err = get_all_projects(repo, params).await.unwrap_err();
+                assert_eq!(err.code(), "validation_error");
+                err.details()[0].field.clone()
+            }
+        };
+
+        let invalid = [
+            (
+                ListProjectsQuery {
+                    status: Some("paused".into()),
+                    ..Default::default()
+                },
+                "status",
+            ),
+            (
+                ListProjectsQuery {
+                    creator: Some("nope".into()),
+                    ..Default::default()
+                },
+                "creator",
+            ),
+            (
+                ListProjectsQuery {
+                    created_after: Some("yesterday".into()),
+                    ..Default::default()
+                },
+                "created_after",
+            ),
+            (
+                ListProjectsQuery {
+                    sort: Some("stars".into()),
+                    ..Default::default()
+                },
+                "sort",
+            ),
+            (
+                ListProjectsQuery {
+                    limit: Some(0),
+                    ..Default::default()
+                },
+                "limit",
+            ),
+            (
+                ListProjectsQuery {
+                    cursor: Some("not-hex".into()),
+                    ..Default::default()
+                },
+                "cursor",
+            ),
+        ];
+        for (params, expected) in invalid {
+            assert_eq!(field(params).await, expected);
+        }
+
+        // A cursor only continues the sort it was issued for
+        let page = get_all_projects(
+            repo.clone(),
+            ListProjectsQuery {
+                sort: Some("name".into()),
+                limit: Some(1),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        let cursor = page.next_cursor;
+        assert_eq!(
+            field(ListProjectsQuery {
+                sort: Some("oldest".into()),
+                cursor,
+                ..Default::default()
+            })
+            .await,
+            "cursor"
+        );
+    }
+}
+
+mod project_membership_tests {
+    use guild_backend::application::commands::accept_project_invitation::accept_project_invitation;
+    use guild_backend::application::commands::approve_project::approve_project;
+    use guild_backend::application::commands::create_project::create_project;
+    use guild_backend::application::commands::delete_project::delete_project;
+    use guild_backend::application::commands::invite_project_member::invite_project_member;
+    use guild_backend::application::commands::leave_project::leave_project;
+    use guild_backend::application::commands::transition_project::transition_project;
+    use guild_backend::application::commands::update_project::update_project;
+    use guild_backend::application::dtos::project_dtos::{
+        CreateProjectRequest, InviteMemberRequest, TransitionProjectRequest, UpdateProjectRequest,
+    };
+    use guild_backend::application::queries::get_project_approvals::get_project_approvals;
+    use guild_backend::application::queries::get_project_history::get_project_history;
+    use guild_backend::application::queries::get_project_members::get_project_members;
+    use guild_backend::domain::entities::profile::Profile;
+    use guild_backend::domain::entities::project_member::{MembershipStatus, ProjectRole};
+    use guild_backend::domain::entities::projects::{ProjectStatus, ReviewPolicy};
+    use guild_backend::domain::errors::AppError;
+    use guild_backend::domain::repositories::ProfileRepository;
+    use guild_backend::domain::value_objects::WalletAddress;
+    use guild_backend::infrastructure::repositories::{
+        InMemoryProfileRepository, InMemoryProjectRepository, InMemoryStore,
+    };
+    use std::sync::Arc;
+
+    const CREATOR: &str = "0x1000000000000000000000000000000000000001";
+    const MAINTAINER: &str = "0x2000000000000000000000000000000000000002";
+    const REVIEWER: &str = "0x3000000000000000000000000000000000000003";
+    const SECOND_REVIEWER: &str = "0x4000000000000000000000000000000000000004";
+    const LOWERCASE: &str = "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed";
+
+    /// A project created by CREATOR, with profiles for every address above.
+    async fn setup() -> (Arc<InMemoryProjectRepository>, String) {
+        let store = InMemoryStore::new();
+        let profiles = InMemoryProfileRepository::new(store.clone());
+        for address in [CREATOR, MAINTAINER, REVIEWER, SECOND_REVIEWER, LOWERCASE] {
+            profiles
+                .create(&Profile::new(WalletAddress(address.into())))
+                .await
+                .unwrap();
+        }
+        let repo = Arc::new(InMemoryProjectRepository::new(store));
+        let project = create_project(
+            repo.clone(),
+            CREATOR.into(),
+            CreateProjectRequest {
+                name: "Shared".into(),
+                description: "Maintained together".into(),
+                status: None,
+            },
+        )
+        .await
+        .unwrap();
+        (repo, project.id)
+    }
+
+    fn invite(address: &str, role: ProjectRole) -> InviteMemberRequest {
+        InviteMemberRequest {
+            address: address.into(),
+            role,
+        }
+    }
+
+    fn rename(name: &str) -> UpdateProjectRequest {
+        UpdateProjectRequest {
+            name: Some(name.into()),
+            description: None,
+            status: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn invited_maintainer_can_edit_after_accepting() {
+        let (repo, id) = setup().await;
+
+        let invited = invite_project_member(
+            repo.clone(),
+            CREATOR.into(),
+            id.clone(),
+            invite(MAINTAINER, ProjectRole::Maintainer),
+        )
+        .await
+        .unwrap();
+        assert_eq!(invited.status, MembershipStatus::Invited);
+        assert_eq!(invited.invited_by.as_deref(), Some(CREATOR));
+
+        // A pending invitation grants nothing yet
+        let err = update_project(repo.clone(), MAINTAINER.into(), id.clone(), rename("Early"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::Forbidden(_)));
+
+        let accepted = accept_project_invitation(repo.clone(), MAINTAINER.into(), id.clone())
+            .await
+            .unwrap();
+        assert_eq!(accepted.status, MembershipStatus::Active);
+        assert!(accepted.joined_at.is_some());
+        let err = accept_project_invitation(repo.clone(), MAINTAINER.into(), id.clone())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::Conflict(_)));
+
+        let updated = update_project(repo.clone(), MAINTAINER.into(), id.clone(), rename("Ours"))
+            .await
+            .unwrap();
+        assert_eq!(updated.name, "Ours");
+
+        let members = get_project_members(repo.clone(), id.clone()).await.unwrap();
+        let roles: Vec<_> = members
+            .iter()
+            .map(|m| (m.address.as_str(), m.role, m.status))
+            .collect();
+        assert_eq!(
+            roles,
+            vec![
+                (CREATOR, ProjectRole::Maintainer, MembershipStatus::Active),
+                (
+                    MAINTAINER,
+                    ProjectRole::Maintainer,
+                    MembershipStatus::Active
+                ),
+            ]
+        );
+
+        // The creator can hand the project over and leave
+        leave_project(repo.clone(), CREATOR.into(), id.clone())
+            .await
+            .unwrap();
+        let err = update_project(repo.clone(), CREATOR.into(), id.clone(), rename("Back"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::Forbidden(_)));
+        delete_project(repo.clone(), MAINTAINER.into(), id.clone())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn member_addresses_match_in_any_case() {
+        let (repo, id) = setup().await;
+
+        let invited = invite_project_member(
+            repo.clone(),
+            CREATOR.into(),
+            id.clone(),
+            invite(LOWERCASE, ProjectRole::Reviewer),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            invited.address,
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+
+        let uppercase = format!("0x{}", LOWERCASE[2..].to_uppercase());
+        let accepted = accept_project_invitation(repo.clone(), uppercase, id.clone())
+            .await
+            .unwrap();
+        assert_eq!(accepted.status, MembershipStatus::Active);
+    }
+
+    #[tokio::test]
+    async fn only_maintainers_manage_members() {
+        let (repo, id) = setup().await;
+
+        invite_project_member(
+            repo.clone(),
+            CREATOR.into(),
+            id.clone(),
+            invite(REVIEWER, ProjectRole::Reviewer),
+        )
+        .await
+        .unwrap();
+        accept_project_invitation(repo.clone(), REVIEWER.into(), id.clone())
+            .await
+            .unwrap();
+
+        // Reviewers can neither edit, delete nor invite
+        let err = update_project(repo.clone(), REVIEWER.into(), id.clone(), rename("Mine"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::Forbidden(_)));
+        let err = delete_project(repo.clone(), REVIEWER.into(), id.clone())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::Forbidden(_)));
+        let err = invite_project_member(
+            repo.clone(),
+            REVIEWER.into(),
+            id.clone(),
+            invite(MAINTAINER, ProjectRole::Maintainer),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, AppError::Forbidden(_)));
+
+        let err = invite_project_member(
+            repo.clone(),
+            CREATOR.into(),
+            id.clone(),
+            invite(REVIEWER, ProjectRole::Contributor),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, AppError::Conflict(_)));
+
+        let err = invite_project_member(
+            repo.clone(),
+            CREATOR.into(),
+            id.clone(),
+            invite(
+                "0x9000000000000000000000000000000000000009",
+                ProjectRole::Contributor,
+            ),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.details()[0].field, "address");
+
+        // The last maintainer stays; everyone else may leave
+        let err = leave_project(repo.clone(), CREATOR.into(), id.clone())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::Conflict(_)));
+        leave_project(repo.clone(), REVIEWER.into(), id.clone())
+            .await
+            .unwrap();
+        let err = leave_project(repo.clone(), REVIEWER.into(), id.clone())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::NotFound(_)));
+    }
+
+    async fn join(
+        repo: &Arc<InMemoryProjectRepository>,
+        id: &str,
+        address: &str,
+        role: ProjectRole,
+    ) {
+        invite_project_member(
+            repo.clone(),
+            CREATOR.into(),
+            id.into(),
+            invite(address, role),
+        )
+        .await
+        .unwrap();
+        accept_project_invitation(repo.clone(), address.into(), id.into())
+            .await
+            .unwrap();
+    }
+
+    async fn move_to(
+        repo: &Arc<InMemoryProjectRepository>,
+        actor: &str,
+        id: &str,
+        status: ProjectStatus,
+    ) -> Result<ProjectStatus, AppError> {
+        let policy = ReviewPolicy {
+            required_approvals: 2,
+        };
+        let request = TransitionProjectRequest {
+            status,
+            note: Some(format!("to {status}")),
+        };
+        transition_project(repo.clone(), policy, actor.into(), id.into(), request)
+            .await
+            .map(|project| project.status)
+    }
+
+    #[tokio::test]
+    async fn approval_needs_enough_reviewers_and_is_recorded() {
+        let (repo, id) = setup().await;
+        let policy = ReviewPolicy {
+            required_approvals: 2,
+        };
+        join(&repo, &id, REVIEWER, ProjectRole::Reviewer).await;
+        join(&repo, &id, SECOND_REVIEWER, ProjectRole::Reviewer).await;
+
+        // Approvals are only collected during a review
+        let err = approve_project(repo.clone(), policy, REVIEWER.into(), id.clone())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::Conflict(_)));
+        let err = move_to(&repo, CREATOR, &id, ProjectStatus::Ongoing)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::Conflict(_)));
+        let err = move_to(&repo, REVIEWER, &id, ProjectStatus::UnderReview)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::Forbidden(_)));
+
+        move_to(&repo, CREATOR, &id, ProjectStatus::UnderReview)
+            .await
+            .unwrap();
+        let err = approve_project(repo.clone(), policy, CREATOR.into(), id.clone())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::Forbidden(_)));
+
+        let round = approve_project(repo.clone(), policy, REVIEWER.into(), id.clone())
+            .await
+            .unwrap();
+        assert_eq!(round.approvals.len(), 1);
+        assert_eq!(round.required, 2);
+        let err = approve_project(repo.clone(), policy, REVIEWER.into(), id.clone())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::Conflict(_)));
+        let err = move_to(&repo, CREATOR, &id, ProjectStatus::Approved)
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("needs 2 reviewer approvals, got 1"));
+
+        // Withdrawing the review discards its approvals
+        move_to(&repo, CREATOR, &id, ProjectStatus::Proposal)
+            .await
+            .unwrap();
+        move_to(&repo, CREATOR, &id, ProjectStatus::UnderReview)
+            .await
+            .unwrap();
+        let round = get_project_approvals(repo.clone(), policy, id.clone())
+            .await
+            .unwrap();
+        assert!(round.approvals.is_empty());
+
+        for reviewer in [REVIEWER, SECOND_REVIEWER] {
+            approve_project(repo.clone(), policy, reviewer.into(), id.clone())
+                .await
+                .unwrap();
+        }
+        for status in [
+            ProjectStatus::Approved,
+            ProjectStatus::Ongoing,
+            ProjectStatus::Completed,
+            ProjectStatus::Archived,
+        ] {
+            assert_eq!(move_to(&repo, CREATOR, &id, status).await.unwrap(), status);
+        }
+
+        let history = get_project_history(repo.clone(), id.clone()).await.unwrap();
+        let steps: Vec<_> = history.iter().map(|h| (h.from, h.to)).collect();
+        use ProjectStatus::*;
+        assert_eq!(
+            steps,
+            vec![
+                (None, Proposal),
+                (Some(Proposal), UnderReview),
+                (Some(UnderReview), Proposal),
+                (Some(Proposal), UnderReview),
+                (Some(UnderReview), Approved),
+                (Some(Approved), Ongoing),
+                (Some(Ongoing), Completed),
+                (Some(Completed), Archived),
+            ]
+        );
+        assert_eq!(history[4].actor, CREATOR);
+        assert_eq!(history[4].note.as_deref(), Some("to approved"));
+    }
+
+    #[tokio::test]
+    async fn approvals_count_whatever_the_address_casing() {
+        let (repo, id) = setup().await;
+        let policy = ReviewPolicy {
+            required_approvals: 2,
+        };
+        join(&repo, &id, LOWERCASE, ProjectRole::Reviewer).await;
+        move_to(&repo, CREATOR, &id, ProjectStatus::UnderReview)
+            .await
+            .unwrap();
+
+        let round = approve_project(repo.clone(), policy, LOWERCASE.into(), id.clone())
+            .await
+            .unwrap();
+        assert_eq!(round.approvals.len(), 1);
+        assert_eq!(
+            round.approvals[0].reviewer,
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+
+        let uppercase = format!("0x{}", LOWERCASE[2..].to_uppercase());
+        let err = approve_project(repo.clone(), policy, uppercase, id.clone())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AppError::Conflict(_)));
+
+        // The lowercase approval counts towards the transition's quorum
+        let err = move_to(&repo, CREATOR, &id, ProjectStatus::Approved)
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("needs 2 reviewer approvals, got 1"));
+    }
+
+    #[tokio::test]
+    async fn reviewers_can_reject_and_departed_reviewers_do_not_count() {
+        let (repo, id) = setup().await;
+        let policy = ReviewPolicy {
+            required_approvals: 1,
+        };
+        join(&repo, &id, REVIEWER, ProjectRole::Reviewer).await;
+        join(&repo, &id, SECOND_REVIEWER, ProjectRole::Reviewer).await;
+        move_to(&repo, CREATOR, &id, ProjectStatus::UnderReview)
+            .await
+            .unwrap();
+
+        approve_project(repo.clone(), policy, REVIEWER.into(), id.clone())
+            .await
+            .unwrap();
+        leave_project(repo.clone(), REVIEWER.into(), id.clone())
+            .await
+            .unwrap();
+        let round = get_project_approvals(repo.clone(), policy, id.clone())
+            .await
+            .unwrap();
+        assert!(round.approvals.is_empty());
+
+        assert_eq!(
+            move_to(&repo, SECOND_REVIEWER, &id, ProjectStatus::Rejected)
+                .await
+                .unwrap(),
+            ProjectStatus::Rejected
+        );
+        let err = update_project(
+            repo.clone(),
+            CREATOR.into(),
+            id.clone(),
+            UpdateProjectRequest {
+                name: None,
+                description: None,
+                status: Some(ProjectStatus::Proposal),
+            },
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.details()[0].field, "status");
+        assert_eq!(
+            move_to(&repo, CREATOR, &id, ProjectStatus::Archived)
+                .await
+                .unwrap(),
+            ProjectStatus::Archived
+        );
+    }
+}
+
+mod project_github_tests {
+    use chrono::{Duration, Utc};
+    use guild_backend::application::commands::create_project::create_project;
+    use guild_backend::application::commands::set_project_repos::set_project_repos;
+    use guild_backend::application::dtos::project_dtos::{
+        CreateProjectRequest, ProjectIssuesQuery, ProjectRepos,
+    };
+    use guild_backend::application::queries::get_project_issues::get_project_issues;
+    use guild_backend::application::queries::get_project_stats::get_project_stats;
+    use guild_backend::domain::entities::github_issue::GithubIssue;
+    use guild_backend::domain::entities::profile::Profile;
+    use guild_backend::domain::errors::AppError;
+    use guild_backend::domain::repositories::{GithubIssueRepository, ProfileRepository};
+    use guild_backend::domain::value_objects::WalletAddress;
+    use guild_backend::infrastructure::repositories::{
+        InMemoryGithubIssueRepository, InMemoryProfileRepository, InMemoryProjectRepository,
+        InMemoryStore,
+    };
+    use serde_json::json;
+    use std::sync::Arc;
+
+    const CREATOR: &str = "0x1000000000000000000000000000000000000001";
+    const OUTSIDER: &str = "0x2000000000000000000000000000000000000002";
+
+    fn issue(repo: &str, id: i64, state: &str, points: i32, assignees: &[&str]) -> GithubIssue {
+        let now = Utc::now();
+        GithubIssue {
+            repo_id: repo.len() as i64,
+            github_issue_id: id,
+            repo: repo.to_string(),
+            issue_number: id as i32,
+            title: format!("Issue {id}"),
+            state: state.to_string(),
+            labels: json!([format!("{points}pts")]),
+            points,
+            assignee_logins: json!(assignees),
+            url: format!("https://github.com/TheSoftwareDevGuild/{repo}/issues/{id}"),
+            created_at: now - Duration::hours(id),
+            closed_at: (state == "closed").then_some(now),
+            rewarded_sepolia: false,
+            distribution_id: None,
+            updated_at: now,
+        }
+    }
+
+    async fn setup() -> (
+        Arc<InMemoryProjectRepository>,
+        Arc<InMemoryGithubIssueRepository>,
+        String,
+    ) {
+        let store = InMemoryStore::new();
+        InMemoryProfileRepository::new(store.clone())
+            .create(&Profile::new(WalletAddress(CREATOR.into())))
+            .await
+            .unwrap();
+        let issues = Arc::new(InMemoryGithubIssueRepository::new(store.clone()));
+        for issue in [
+            issue("backend", 1, "closed", 5, &["alice", "bob"]),
+            issue("backend", 2, "closed", 3, &["bob"]),
+            issue("backend", 3, "open", 8, &["alice"]),
+            issue("frontend", 4, "closed", 2, &["carol"]),
+            issue("unlinked", 5, "closed", 13, &["mallory"]),
+        ] {
+            issues.upsert(&issue).await.unwrap();
+        }
+
+        let projects = Arc::new(InMemoryProjectRepository::new(store));
+        let project = create_project(
+            projects.clone(),
+            CREATOR.into(),
+            CreateProjectRequest {
+                name: "Linked".into(),
+                description: "Tracks issues".into(),
+                status: None,
+            },
+        )
+        .await
+        .unwrap();
+        (projects, issues, project.id)
+    }
+
+    #[tokio::test]
+    async fn stats_aggregate_linked_repositories() {
+        let (projects, issues, id) = setup().await;
+
+        let linked = set_project_repos(
+            projects.clone(),
+            CREATOR.into(),
+            id.clone(),
+            ProjectRepos {
+                repos: vec!["frontend".into(), " backend ".into(), "backend".into()],
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(linked.repos, vec!["backend", "frontend"]);
+
+        let closed = get_project_issues(
+            projects.clone(),
+            issues.clone(),
+            id.clone(),
+            ProjectIssuesQuery {
+                state: Some("closed".into()),
+            },
+        )
+        .await
+        .unwrap();
+        let numbers: Vec<_> = closed.iter().map(|i| i.issue_number).collect();
+        assert_eq!(numbers, vec![1, 2, 4]);
+
+        let stats = get_project_stats(projects.clone(), issues.clone(), id.clone())
+            .await
+            .unwrap();
+        assert_eq!(stats.open_issues, 1);
+        assert_eq!(stats.closed_issues, 3);
+        assert_eq!(stats.points_delivered, 10);
+        let top: Vec<_> = stats
+            .top_contributors
+            .iter()
+            .map(|c| (c.login.as_str(), c.closed_issues, c.points))
+            .collect();
+        assert_eq!(top, vec![("bob", 2, 8), ("alice", 1, 5), ("carol", 1, 2)]);
+    }
+
+    #[tokio::test]
+    async fn only_maintainers_link_valid_repositories() {
+        let (projects, issues, id) = setup().await;
+        let repos = |names: &[&str]| ProjectRepos {
+            repos: names.iter().map(|n| n.to_string()).collect(),
+        };
+
+        let err = set_project_repos(
+            projects.clone(),
+            OUTSIDER.into(),
+            id.clone(),
+            repos(&["backend"]),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, AppError::Forbidden(_)));
+
+        let err = set_project_repos(
+            projects.clone(),
+            CREATOR.into(),
+            id.clone(),
+            repos(&["backend", "owner/frontend"]),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.details()[0].field, "repos[1]");
+
+        let err = get_project_issues(
+            projects.clone(),
+            issues.clone(),
+            id.clone(),
+            ProjectIssuesQuery {
+                state: Some("merged".into()),
+            },
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.details()[0].field, "state");
+
+        // Nothing linked yet
+        let stats = get_project_stats(projects, issues, id).await.unwrap();
+        assert!(stats.repos.is_empty());
+        assert_eq!(stats.closed_issues, 0);
     }
 }
@@ -0,0 +1,148 @@
+mod common;
+
+use std::sync::Arc;
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{Request, StatusCode},
+};
+use common::offline_app;
+use guild_backend::domain::entities::{Profile, Project, ProjectStatus, ReviewPolicy};
+use guild_backend::domain::repositories::{ProfileRepository, ProjectRepository};
+use guild_backend::domain::value_objects::WalletAddress;
+use guild_backend::infrastructure::repositories::{
+    InMemoryDistributionRepository, InMemoryGithubIssueRepository, InMemoryProfileRepository,
+    InMemoryProjectRepository, InMemoryStore,
+};
+use guild_backend::infrastructure::services::ethereum_address_verification_service::MockEthereumAddressVerificationService;
+use guild_backend::infrastructure::services::indexer_badge_registry_service::MockBadgeRegistryService;
+use guild_backend::infrastructure::services::rest_github_service::RestGithubService;
+use guild_backend::infrastructure::services::rpc_mint_event_service::MockMintEventService;
+use guild_backend::presentation::api::{build_app, AppState, AuthStrategy};
+use tower::ServiceExt;
+
+async fn get(uri: &str) -> axum::response::Response {
+    offline_app()
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn v1_routes_are_not_deprecated() {
+    let response = get("/v1/auth/nonce/0x742d35Cc6634C0532925a3b844Bc454e4438f44e").await;
+
+    assert_ne!(response.status(), StatusCode::NOT_FOUND);
+    assert!(response.headers().get("deprecation").is_none());
+    assert!(response.headers().get("sunset").is_none());
+}
+
+#[tokio::test]
+async fn legacy_routes_carry_deprecation_headers() {
+    let response = get("/auth/nonce/0x742d35Cc6634C0532925a3b844Bc454e4438f44e").await;
+    let headers = response.headers();
+
+    assert_ne!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(headers["deprecation"], "true");
+    assert!(headers.contains_key("sunset"));
+    assert_eq!(
+        headers["link"],
+        "</v1/auth/nonce/0x742d35Cc6634C0532925a3b844Bc454e4438f44e>; rel=\"successor-version\""
+    );
+}
+
+#[tokio::test]
+async fn docs_are_served_without_version_prefix() {
+    let response = get("/openapi.json").await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get("deprecation").is_none());
+}
+
+#[tokio::test]
+async fn unknown_versions_are_not_routed() {
+    let response = get("/v2/profiles").await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn trailing_slash_profile_alias_is_routed() {
+    let response = offline_app()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/v1/profiles/")
+                .header(
+                    "x-eth-address",
+                    "0x742d35Cc6634C0532925a3b844Bc454e4438f44e",
+                )
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_ne!(response.status(), StatusCode::NOT_FOUND);
+    assert_ne!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+}
+
+#[tokio::test]
+async fn legacy_lists_keep_the_bare_array() {
+    let store = InMemoryStore::new();
+    let profiles = InMemoryProfileRepository::new(store.clone());
+    for address in [
+        "0x742d35Cc6634C0532925a3b844Bc454e4438f44e",
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+    ] {
+        profiles
+            .create(&Profile::new(WalletAddress(address.to_string())))
+            .await
+            .unwrap();
+    }
+    let projects = InMemoryProjectRepository::new(store.clone());
+    for name in ["Alpha", "Beta"] {
+        projects
+            .create(&Project::new(
+                name.into(),
+                "Listed".into(),
+                ProjectStatus::Proposal,
+                WalletAddress("0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string()),
+            ))
+            .await
+            .unwrap();
+    }
+    let state = AppState {
+        profile_repository: Arc::new(profiles),
+        project_repository: Arc::new(projects),
+        distribution_repository: Arc::new(InMemoryDistributionRepository::new(store.clone())),
+        auth_service: Arc::new(MockEthereumAddressVerificationService::new()),
+        github_issue_repository: Arc::new(InMemoryGithubIssueRepository::new(store)),
+        github_service: Arc::new(RestGithubService::new()),
+        badge_registry: Arc::new(MockBadgeRegistryService::default()),
+        mint_event_service: Arc::new(MockMintEventService::new()),
+        review_policy: ReviewPolicy::default(),
+    };
+    let app = build_app(state, AuthStrategy::TestHeader);
+    let list = |uri: &str| {
+        let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let app = app.clone();
+        async move {
+            let response = app.oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+        }
+    };
+
+    for resource in ["profiles", "projects"] {
+        // Every row, even past the page size asked for
+        let legacy = list(&format!("/{resource}?limit=1")).await;
+        assert_eq!(legacy.as_array().unwrap().len(), 2);
+
+        let page = list(&format!("/v1/{resource}?limit=1")).await;
+        assert_eq!(page["items"].as_array().unwrap().len(), 1);
+        assert_eq!(page["total"], 2);
+        assert!(page["next_cursor"].is_string());
+    }
+}
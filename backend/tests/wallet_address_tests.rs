@@ -0,0 +1,40 @@
+use guild_backend::domain::value_objects::WalletAddress;
+
+// Test vectors from EIP-55
+const CHECKSUMMED: &[&str] = &[
+    "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+    "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+    "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+    "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+];
+
+#[test]
+fn to_checksum_matches_eip55_vectors() {
+    for expected in CHECKSUMMED {
+        let lower = WalletAddress(expected.to_lowercase());
+        assert_eq!(lower.to_checksum().as_deref(), Some(*expected));
+        assert!(!lower.is_checksummed());
+        assert!(WalletAddress(expected.to_string()).is_checksummed());
+    }
+}
+
+#[test]
+fn checksummed_keeps_non_addresses_unchanged() {
+    let lower = WalletAddress(CHECKSUMMED[0].to_lowercase());
+    assert_eq!(lower.checksummed().as_str(), CHECKSUMMED[0]);
+    let short = WalletAddress("0x1234".into());
+    assert_eq!(short.checksummed(), short);
+}
+
+#[test]
+fn to_checksum_rejects_non_addresses() {
+    assert_eq!(WalletAddress("0x1234".into()).to_checksum(), None);
+    assert_eq!(
+        WalletAddress("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".into()).to_checksum(),
+        None
+    );
+    assert_eq!(
+        WalletAddress("0xZZAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".into()).to_checksum(),
+        None
+    );
+}
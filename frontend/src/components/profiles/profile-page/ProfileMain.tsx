@@ -3,19 +3,11 @@ import ProfileActions from "@/components/profiles/profile-page/ProfileActions";
 import ProfileDescription from "@/components/profiles/profile-page/ProfileDescription";
 import ProfileAttestations from "@/components/profiles/profile-page/ProfileAttestations";
 import ProfileIssuedAttestations from "@/components/profiles/profile-page/ProfileIssuedAttestations";
-import { useGetProfiles } from "@/hooks/profiles/use-get-profiles";
-import { useMemo } from "react";
+import { useGetProfile } from "@/hooks/profiles/use-get-profile";
 
 export function ProfileMain({ address }: { address: string }) {
-  const profilesQuery = useGetProfiles();
-
-  const profile = useMemo(() => {
-    const list = profilesQuery.data ?? [];
-    const p = list.find(
-      (x) => x.address.toLowerCase() === address.toLowerCase()
-    );
-    return p;
-  }, [profilesQuery.data, address]);
+  const profileQuery = useGetProfile(address);
+  const profile = profileQuery.data ?? undefined;
 
   return (
     <div className="max-w-4xl mx-auto p-6">
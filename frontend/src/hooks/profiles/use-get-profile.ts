@@ -0,0 +1,35 @@
+import type { ProfileFromAPI } from "@/lib/types/profiles";
+import { useQuery, type UseQueryResult } from "@tanstack/react-query";
+import { API_BASE_URL } from "@/lib/constants/apiConstants";
+
+// Resolves to null when no profile exists for the address.
+async function fetchProfile(address: string): Promise<ProfileFromAPI | null> {
+  const response = await fetch(`${API_BASE_URL}/v1/profiles/${address}`, {
+    method: "GET",
+    headers: {
+      "Content-Type": "application/json",
+    },
+  });
+
+  if (response.status === 404) return null;
+  if (!response.ok) {
+    const text = await response.text().catch(() => "");
+    throw new Error(
+      `Failed to fetch profile: ${response.status} ${response.statusText}${
+        text ? ` - ${text}` : ""
+      }`
+    );
+  }
+
+  return (await response.json()) as ProfileFromAPI;
+}
+
+export function useGetProfile(
+  address: string
+): UseQueryResult<ProfileFromAPI | null, Error> {
+  return useQuery<ProfileFromAPI | null, Error>({
+    queryKey: ["profiles", address.toLowerCase()],
+    queryFn: () => fetchProfile(address),
+    enabled: !!address,
+  });
+}
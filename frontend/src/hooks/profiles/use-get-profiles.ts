@@ -1,9 +1,18 @@
-import type { ProfileFromAPI } from "@/lib/types/profiles";
+import type {
+  ProfileFromAPI,
+  ProfilePageFromAPI,
+} from "@/lib/types/profiles";
 import { useQuery, type UseQueryResult } from "@tanstack/react-query";
 import { API_BASE_URL } from "@/lib/constants/apiConstants";
 
-async function fetchProfiles(): Promise<ProfileFromAPI[]> {
-  const response = await fetch(`${API_BASE_URL}/profiles`, {
+// Largest page the API serves
+const PAGE_SIZE = 100;
+
+async function fetchProfilesPage(cursor?: string): Promise<ProfilePageFromAPI> {
+  const params = new URLSearchParams({ limit: String(PAGE_SIZE) });
+  if (cursor) params.set("cursor", cursor);
+
+  const response = await fetch(`${API_BASE_URL}/v1/profiles?${params}`, {
     method: "GET",
     headers: {
       "Content-Type": "application/json",
@@ -19,7 +28,19 @@ async function fetchProfiles(): Promise<ProfileFromAPI[]> {
     );
   }
 
-  return (await response.json()) as ProfileFromAPI[];
+  return (await response.json()) as ProfilePageFromAPI;
+}
+
+// Follows next_cursor until the last page, so callers get every profile.
+async function fetchProfiles(): Promise<ProfileFromAPI[]> {
+  const profiles: ProfileFromAPI[] = [];
+  let cursor: string | undefined;
+  do {
+    const page = await fetchProfilesPage(cursor);
+    profiles.push(...page.items);
+    cursor = page.next_cursor ?? undefined;
+  } while (cursor);
+  return profiles;
 }
 
 export function useGetProfiles(): UseQueryResult<ProfileFromAPI[], Error> {
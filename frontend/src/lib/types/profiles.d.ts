@@ -24,4 +24,10 @@ export type ProfileFromAPI = {
   twitter_handle?: string;
   created_at?: string;
   updated_at?: string;
-};
\ No newline at end of file
+};
+
+export type ProfilePageFromAPI = {
+  items: ProfileFromAPI[];
+  total: number;
+  next_cursor?: string | null;
+};
@@ -0,0 +1,5 @@
+CREATE TABLE block_cursors (
+    contract_address TEXT PRIMARY KEY,
+    last_indexed_block BIGINT NOT NULL CHECK (last_indexed_block >= 0),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
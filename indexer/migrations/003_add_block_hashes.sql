@@ -0,0 +1,13 @@
+-- Rows written so far carry no block hash and cannot be checked against
+-- reorgs; drop them so the next poll backfills them again.
+TRUNCATE ethereum_events, block_cursors;
+
+ALTER TABLE ethereum_events
+    ADD COLUMN contract_address TEXT NOT NULL,
+    ADD COLUMN block_number BIGINT NOT NULL,
+    ADD COLUMN block_hash TEXT NOT NULL;
+
+CREATE INDEX idx_ethereum_events_contract_block
+    ON ethereum_events (contract_address, block_number);
+
+ALTER TABLE block_cursors ADD COLUMN block_hash TEXT NOT NULL;
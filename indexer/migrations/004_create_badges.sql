@@ -0,0 +1,10 @@
+CREATE TABLE badges (
+    name TEXT PRIMARY KEY,
+    description TEXT NOT NULL,
+    creator TEXT NOT NULL,
+    registry_address TEXT NOT NULL,
+    block_number BIGINT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE INDEX idx_badges_registry_block ON badges (registry_address, block_number);
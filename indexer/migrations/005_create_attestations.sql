@@ -0,0 +1,17 @@
+CREATE TABLE attestations (
+    uid TEXT PRIMARY KEY,
+    recipient TEXT NOT NULL,
+    attester TEXT NOT NULL,
+    badge_name TEXT NOT NULL,
+    justification TEXT NOT NULL,
+    eas_address TEXT NOT NULL,
+    block_number BIGINT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    revoked_block BIGINT,
+    revoked_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_attestations_recipient ON attestations (recipient);
+CREATE INDEX idx_attestations_attester ON attestations (attester);
+CREATE INDEX idx_attestations_badge_name ON attestations (badge_name);
+CREATE INDEX idx_attestations_eas_block ON attestations (eas_address, block_number);
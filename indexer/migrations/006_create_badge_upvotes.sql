@@ -0,0 +1,11 @@
+CREATE TABLE badge_upvotes (
+    badge_name TEXT NOT NULL,
+    voter TEXT NOT NULL,
+    ranking_address TEXT NOT NULL,
+    block_number BIGINT NOT NULL,
+    voted_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    PRIMARY KEY (badge_name, voter)
+);
+
+CREATE INDEX idx_badge_upvotes_voter ON badge_upvotes (voter);
+CREATE INDEX idx_badge_upvotes_ranking_block ON badge_upvotes (ranking_address, block_number);
@@ -0,0 +1,16 @@
+CREATE TABLE contribution_mints (
+    id TEXT PRIMARY KEY,
+    tx_hash TEXT NOT NULL,
+    recipient TEXT NOT NULL,
+    amount NUMERIC(78, 0) NOT NULL,
+    reason_hash TEXT NOT NULL,
+    -- NULL when the reason could not be read back from the minting call
+    reason BYTEA,
+    token_address TEXT NOT NULL,
+    block_number BIGINT NOT NULL,
+    minted_at TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+CREATE INDEX idx_contribution_mints_tx_hash ON contribution_mints (tx_hash);
+CREATE INDEX idx_contribution_mints_recipient ON contribution_mints (recipient);
+CREATE INDEX idx_contribution_mints_token_block ON contribution_mints (token_address, block_number);
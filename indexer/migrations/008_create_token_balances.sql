@@ -0,0 +1,24 @@
+-- Ledger of TGA and TGC transfers the balances are folded from
+CREATE TABLE token_transfers (
+    id TEXT PRIMARY KEY,
+    symbol TEXT NOT NULL,
+    token_address TEXT NOT NULL,
+    from_address TEXT NOT NULL,
+    to_address TEXT NOT NULL,
+    amount NUMERIC(78, 0) NOT NULL,
+    block_number BIGINT NOT NULL
+);
+
+CREATE INDEX idx_token_transfers_token_block ON token_transfers (token_address, block_number);
+
+-- Holders with a non-zero balance only
+CREATE TABLE token_balances (
+    token_address TEXT NOT NULL,
+    holder TEXT NOT NULL,
+    symbol TEXT NOT NULL,
+    balance NUMERIC(78, 0) NOT NULL,
+    PRIMARY KEY (token_address, holder)
+);
+
+CREATE INDEX idx_token_balances_symbol_balance ON token_balances (symbol, balance DESC);
+CREATE INDEX idx_token_balances_holder ON token_balances (holder);
@@ -0,0 +1,25 @@
+-- Events were keyed by a `txhash-logindex` string and kept their payload as
+-- JSON text. Split the log coordinates into columns, store the payload as
+-- JSONB and key rows on (tx_hash, log_index).
+ALTER TABLE ethereum_events
+    ADD COLUMN tx_hash TEXT,
+    ADD COLUMN log_index BIGINT,
+    ADD COLUMN event_name TEXT;
+
+ALTER TABLE ethereum_events
+    ALTER COLUMN event_type TYPE JSONB USING event_type::jsonb;
+ALTER TABLE ethereum_events RENAME COLUMN event_type TO payload;
+
+UPDATE ethereum_events
+SET tx_hash = split_part(id, '-', 1),
+    log_index = split_part(id, '-', 2)::bigint,
+    event_name = (SELECT key FROM jsonb_object_keys(payload) AS key LIMIT 1);
+
+ALTER TABLE ethereum_events
+    DROP COLUMN id,
+    ALTER COLUMN tx_hash SET NOT NULL,
+    ALTER COLUMN log_index SET NOT NULL,
+    ALTER COLUMN event_name SET NOT NULL,
+    ADD PRIMARY KEY (tx_hash, log_index);
+
+CREATE INDEX idx_ethereum_events_event_name ON ethereum_events (event_name);
@@ -0,0 +1,11 @@
+-- Every saved cursor, so a reorg can be walked back to the newest indexed
+-- block that is still on the chain.
+CREATE TABLE block_cursor_history (
+    contract_address TEXT NOT NULL,
+    block_number BIGINT NOT NULL CHECK (block_number >= 0),
+    block_hash TEXT NOT NULL,
+    PRIMARY KEY (contract_address, block_number)
+);
+
+INSERT INTO block_cursor_history (contract_address, block_number, block_hash)
+SELECT contract_address, last_indexed_block, block_hash FROM block_cursors;
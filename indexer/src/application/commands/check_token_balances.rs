@@ -0,0 +1,91 @@
+use std::{error::Error, sync::Arc};
+
+use alloy::primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    entities::{event_source::EventSource, token_transfer::TokenSymbol},
+    repositories::{
+        block_cursor_repository::BlockCursorRepository,
+        token_balance_repository::TokenBalanceRepository,
+    },
+    services::ethereum_event_polling_service::EthereumEventPollingService,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceMismatch {
+    pub holder: Address,
+    pub indexed: U256,
+    pub on_chain: U256,
+}
+
+/// Indexed balances compared with the token contract, both as of the last
+/// block indexed for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceCheckReport {
+    pub symbol: TokenSymbol,
+    pub token_address: Address,
+    pub block_number: u64,
+    pub holders_checked: usize,
+    pub mismatches: Vec<BalanceMismatch>,
+    /// Sum of the indexed balances. A total supply above it points at holders
+    /// the indexer never saw.
+    pub indexed_supply: U256,
+    pub on_chain_supply: U256,
+}
+
+impl BalanceCheckReport {
+    pub fn is_consistent(&self) -> bool {
+        self.mismatches.is_empty() && self.indexed_supply == self.on_chain_supply
+    }
+}
+
+/// Calls `balanceOf` for every indexed holder of the token and `totalSupply`
+/// once, at the source's cursor block. None while nothing was indexed yet.
+pub async fn check_token_balances(
+    ethereum_event_polling_service: Arc<dyn EthereumEventPollingService>,
+    token_balance_repository: Arc<dyn TokenBalanceRepository>,
+    block_cursor_repository: Arc<dyn BlockCursorRepository>,
+    symbol: TokenSymbol,
+    source: &EventSource,
+) -> Result<Option<BalanceCheckReport>, Box<dyn Error>> {
+    let Some(cursor) = block_cursor_repository.find(&source.address).await? else {
+        return Ok(None);
+    };
+    let block_number = cursor.last_indexed_block;
+
+    let holders: Vec<_> = token_balance_repository
+        .holders(symbol)
+        .await?
+        .into_iter()
+        .filter(|h| h.token_address == source.address)
+        .collect();
+    let mut mismatches: Vec<BalanceMismatch> = Vec::new();
+    let mut indexed_supply = U256::ZERO;
+    for holder in &holders {
+        indexed_supply = indexed_supply.saturating_add(holder.balance);
+        let on_chain = ethereum_event_polling_service
+            .token_balance(&source.address, &holder.holder, block_number)
+            .await?;
+        if on_chain != holder.balance {
+            mismatches.push(BalanceMismatch {
+                holder: holder.holder,
+                indexed: holder.balance,
+                on_chain,
+            });
+        }
+    }
+    let on_chain_supply = ethereum_event_polling_service
+        .token_total_supply(&source.address, block_number)
+        .await?;
+
+    Ok(Some(BalanceCheckReport {
+        symbol,
+        token_address: source.address,
+        block_number,
+        holders_checked: holders.len(),
+        mismatches,
+        indexed_supply,
+        on_chain_supply,
+    }))
+}
@@ -1 +1,2 @@
+pub mod check_token_balances;
 pub mod poll;
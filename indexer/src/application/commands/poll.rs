@@ -1,14 +1,180 @@
 use std::{error::Error, sync::Arc};
 
+use alloy::primitives::B256;
+
 use crate::domain::{
-    repositories::ethereum_event_repository::EthereumEventRepository,
+    entities::{block_cursor::BlockCursor, event_source::EventSource},
+    repositories::{
+        block_cursor_repository::BlockCursorRepository,
+        ethereum_event_repository::EthereumEventRepository, event_projection::EventProjection,
+    },
     services::ethereum_event_polling_service::EthereumEventPollingService,
 };
 
+/// Largest block range requested from the RPC in a single `eth_getLogs` call.
+pub const DEFAULT_CHUNK_SIZE: u64 = 2_000;
+pub const DEFAULT_CONFIRMATIONS: u64 = 12;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PollConfig {
+    pub sources: Vec<EventSource>,
+    pub chunk_size: u64,
+    /// Blocks are indexed once they are this deep below the head, which keeps
+    /// most reorgs out of the index. Deeper ones are rolled back when noticed.
+    pub confirmations: u64,
+}
+
+/// Brings every configured source up to date, one after the other, and feeds
+/// the new events to the read model projections.
 pub async fn poll(
     ethereum_event_polling_service: Arc<dyn EthereumEventPollingService>,
     ethereum_event_repository: Arc<dyn EthereumEventRepository>,
+    block_cursor_repository: Arc<dyn BlockCursorRepository>,
+    projections: &[Arc<dyn EventProjection>],
+    config: &PollConfig,
+) -> Result<(), Box<dyn Error>> {
+    let confirmed_head = ethereum_event_polling_service
+        .latest_block()
+        .await?
+        .saturating_sub(config.confirmations);
+    for source in &config.sources {
+        poll_source(
+            ethereum_event_polling_service.as_ref(),
+            ethereum_event_repository.as_ref(),
+            block_cursor_repository.as_ref(),
+            projections,
+            config,
+            source,
+            confirmed_head,
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Indexes every block between the source's cursor (or its start block on the
+/// first run) and the confirmed head, `chunk_size` blocks at a time. The cursor
+/// is saved after each chunk so an interrupted backfill resumes where it
+/// stopped.
+///
+/// Before each chunk the parent hash of its first block is checked against the
+/// hash of the last indexed block. A mismatch means the chain reorganised under
+/// the indexed blocks: everything after the fork point is rolled back and
+/// replayed.
+async fn poll_source(
+    ethereum_event_polling_service: &dyn EthereumEventPollingService,
+    ethereum_event_repository: &dyn EthereumEventRepository,
+    block_cursor_repository: &dyn BlockCursorRepository,
+    projections: &[Arc<dyn EventProjection>],
+    config: &PollConfig,
+    source: &EventSource,
+    confirmed_head: u64,
 ) -> Result<(), Box<dyn Error>> {
-    let poll_result = ethereum_event_polling_service.poll().await?;
-    ethereum_event_repository.insert_many(poll_result).await
+    let cursor = block_cursor_repository.find(&source.address).await?;
+    let (mut from_block, mut expected_parent): (u64, Option<B256>) = match cursor {
+        Some(cursor) => (cursor.next_block(), Some(cursor.block_hash)),
+        None => (source.start_block, None),
+    };
+
+    while from_block <= confirmed_head {
+        if let Some(parent_hash) = expected_parent {
+            let header = ethereum_event_polling_service
+                .block_header(from_block)
+                .await?;
+            if header.parent_hash != parent_hash {
+                let fork_point = find_fork_point(
+                    ethereum_event_polling_service,
+                    block_cursor_repository,
+                    source,
+                    from_block,
+                )
+                .await?;
+                let replay_from = fork_point
+                    .as_ref()
+                    .map_or(source.start_block, BlockCursor::next_block);
+                tracing::warn!(
+                    source = %source.kind,
+                    block = from_block,
+                    replay_from,
+                    "parent hash mismatch, rolling back reorganised blocks"
+                );
+                ethereum_event_repository
+                    .delete_from_block(&source.address, replay_from)
+                    .await?;
+                for projection in projections {
+                    projection.rollback(&source.address, replay_from).await?;
+                }
+                block_cursor_repository
+                    .delete_from_block(&source.address, replay_from)
+                    .await?;
+                from_block = replay_from;
+                expected_parent = fork_point.map(|cursor| cursor.block_hash);
+                continue;
+            }
+        }
+
+        // The header is read first and the logs of its block must carry its
+        // hash; otherwise the chain moved between the two calls and the chunk
+        // is read again.
+        let to_block = confirmed_head.min(from_block + config.chunk_size - 1);
+        let last_header = ethereum_event_polling_service
+            .block_header(to_block)
+            .await?;
+        let events = ethereum_event_polling_service
+            .poll(source, from_block, to_block)
+            .await?;
+        if events
+            .iter()
+            .any(|e| e.block_number == to_block && e.block_hash != last_header.hash)
+        {
+            tracing::warn!(
+                source = %source.kind,
+                block = to_block,
+                "block hash changed while polling, reading the range again"
+            );
+            continue;
+        }
+        for projection in projections {
+            projection.apply(&events).await?;
+        }
+        ethereum_event_repository.insert_many(events).await?;
+        block_cursor_repository
+            .save(&BlockCursor::new(
+                source.address,
+                to_block,
+                last_header.hash,
+            ))
+            .await?;
+        tracing::debug!(source = %source.kind, from_block, to_block, "indexed block range");
+        from_block = to_block + 1;
+        expected_parent = Some(last_header.hash);
+    }
+
+    Ok(())
+}
+
+/// Walks back through the saved cursors below `block` until one whose hash is
+/// still on the chain. `None` when the reorg is deeper than the history.
+async fn find_fork_point(
+    ethereum_event_polling_service: &dyn EthereumEventPollingService,
+    block_cursor_repository: &dyn BlockCursorRepository,
+    source: &EventSource,
+    block: u64,
+) -> Result<Option<BlockCursor>, Box<dyn Error>> {
+    let mut before = block;
+    loop {
+        let Some(cursor) = block_cursor_repository
+            .find_before(&source.address, before)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let header = ethereum_event_polling_service
+            .block_header(cursor.last_indexed_block)
+            .await?;
+        if header.hash == cursor.block_hash {
+            return Ok(Some(cursor));
+        }
+        before = cursor.last_indexed_block;
+    }
 }
@@ -0,0 +1,14 @@
+use std::{error::Error, sync::Arc};
+
+use alloy::primitives::B256;
+
+use crate::domain::{
+    entities::attestation::Attestation, repositories::attestation_repository::AttestationRepository,
+};
+
+pub async fn get_attestation(
+    attestation_repository: Arc<dyn AttestationRepository>,
+    uid: &B256,
+) -> Result<Option<Attestation>, Box<dyn Error>> {
+    attestation_repository.find_by_uid(uid).await
+}
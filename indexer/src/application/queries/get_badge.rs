@@ -0,0 +1,10 @@
+use std::{error::Error, sync::Arc};
+
+use crate::domain::{entities::badge::Badge, repositories::badge_repository::BadgeRepository};
+
+pub async fn get_badge(
+    badge_repository: Arc<dyn BadgeRepository>,
+    name: &str,
+) -> Result<Option<Badge>, Box<dyn Error>> {
+    badge_repository.find_by_name(name).await
+}
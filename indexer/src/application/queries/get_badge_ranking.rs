@@ -0,0 +1,12 @@
+use std::{error::Error, sync::Arc};
+
+use crate::domain::{
+    entities::badge_upvote::BadgeVotes,
+    repositories::badge_upvote_repository::BadgeUpvoteRepository,
+};
+
+pub async fn get_badge_ranking(
+    badge_upvote_repository: Arc<dyn BadgeUpvoteRepository>,
+) -> Result<Vec<BadgeVotes>, Box<dyn Error>> {
+    badge_upvote_repository.ranking().await
+}
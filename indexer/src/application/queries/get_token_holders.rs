@@ -0,0 +1,18 @@
+use std::{error::Error, sync::Arc};
+
+use crate::domain::{
+    entities::{token_balance::TokenHolders, token_transfer::TokenSymbol},
+    repositories::token_balance_repository::TokenBalanceRepository,
+};
+
+pub async fn get_token_holders(
+    token_balance_repository: Arc<dyn TokenBalanceRepository>,
+    symbol: TokenSymbol,
+) -> Result<TokenHolders, Box<dyn Error>> {
+    let holders = token_balance_repository.holders(symbol).await?;
+    Ok(TokenHolders {
+        symbol,
+        holder_count: holders.len(),
+        holders,
+    })
+}
@@ -0,0 +1,15 @@
+use std::{error::Error, sync::Arc};
+
+use alloy::primitives::Address;
+
+use crate::domain::{
+    entities::token_balance::TokenBalance,
+    repositories::token_balance_repository::TokenBalanceRepository,
+};
+
+pub async fn list_account_balances(
+    token_balance_repository: Arc<dyn TokenBalanceRepository>,
+    address: &Address,
+) -> Result<Vec<TokenBalance>, Box<dyn Error>> {
+    token_balance_repository.balances_of(address).await
+}
@@ -0,0 +1,13 @@
+use std::{error::Error, sync::Arc};
+
+use crate::domain::{
+    entities::attestation::{Attestation, AttestationFilter},
+    repositories::attestation_repository::AttestationRepository,
+};
+
+pub async fn list_attestations(
+    attestation_repository: Arc<dyn AttestationRepository>,
+    filter: AttestationFilter,
+) -> Result<Vec<Attestation>, Box<dyn Error>> {
+    attestation_repository.list(&filter).await
+}
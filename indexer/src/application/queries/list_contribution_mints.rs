@@ -0,0 +1,13 @@
+use std::{error::Error, sync::Arc};
+
+use crate::domain::{
+    entities::contribution_mint::{ContributionMint, ContributionMintFilter},
+    repositories::contribution_mint_repository::ContributionMintRepository,
+};
+
+pub async fn list_contribution_mints(
+    contribution_mint_repository: Arc<dyn ContributionMintRepository>,
+    filter: ContributionMintFilter,
+) -> Result<Vec<ContributionMint>, Box<dyn Error>> {
+    contribution_mint_repository.list(&filter).await
+}
@@ -0,0 +1,15 @@
+use std::{error::Error, sync::Arc};
+
+use alloy::primitives::Address;
+
+use crate::domain::{
+    entities::badge_upvote::BadgeUpvote,
+    repositories::badge_upvote_repository::BadgeUpvoteRepository,
+};
+
+pub async fn list_voter_upvotes(
+    badge_upvote_repository: Arc<dyn BadgeUpvoteRepository>,
+    voter: &Address,
+) -> Result<Vec<BadgeUpvote>, Box<dyn Error>> {
+    badge_upvote_repository.list_by_voter(voter).await
+}
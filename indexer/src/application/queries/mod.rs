@@ -1 +1,10 @@
+pub mod get_attestation;
+pub mod get_badge;
+pub mod get_badge_ranking;
+pub mod get_token_holders;
+pub mod list_account_balances;
+pub mod list_attestations;
+pub mod list_badges;
+pub mod list_contribution_mints;
 pub mod list_events;
+pub mod list_voter_upvotes;
@@ -0,0 +1,237 @@
+use std::{collections::HashSet, env, fs, path::Path, str::FromStr, time::Duration};
+
+use alloy::{
+    primitives::{Address, B256},
+    transports::http::reqwest::Url,
+};
+use serde::Deserialize;
+
+use crate::{
+    application::commands::poll::{DEFAULT_CHUNK_SIZE, DEFAULT_CONFIRMATIONS, PollConfig},
+    domain::entities::event_source::{EventSource, EventSourceKind},
+};
+
+/// Read when `INDEXER_CONFIG` is not set, if it exists.
+pub const DEFAULT_CONFIG_PATH: &str = "indexer.toml";
+
+/// Time between two blocks, the default poll interval. Chains not listed are
+/// assumed to follow Ethereum's 12 second slots.
+pub fn block_time(chain_id: u64) -> Duration {
+    match chain_id {
+        137 | 80002 => Duration::from_secs(2),
+        31337 => Duration::from_secs(1),
+        _ => Duration::from_secs(12),
+    }
+}
+
+/// Everything the indexer needs to know about the chain it follows.
+///
+/// Settings come from a TOML file (`INDEXER_CONFIG`, or `indexer.toml`) and
+/// environment variables, which take precedence:
+///
+/// ```toml
+/// chain_id = 80002
+/// rpc_url = "https://rpc-amoy.polygon.technology"
+/// chunk_size = 2000
+/// confirmations = 12
+/// poll_interval_ms = 2000
+///
+/// [[sources]]
+/// kind = "activity_token"
+/// address = "0x5db978bc69e54250f577ed343273508baea136cd"
+/// start_block = 0
+///
+/// [[sources]]
+/// kind = "eas"
+/// address = "0xb101275a60d8bfb14529C421899aD7CA1Ae5B5Fc"
+/// schema_id = "0xb167f07504166f717f2a2710dbcfbfdf8fad6e8c6128c1a7fa80768f61b1d0b2"
+/// ```
+///
+/// The matching variables are `CHAIN_ID`, `RPC_URL`, `POLL_CHUNK_SIZE`,
+/// `CONFIRMATIONS`, `POLL_INTERVAL_MS`, and `<KIND>_ADDRESS` / `<KIND>_START_BLOCK` per source
+/// (e.g. `ACTIVITY_TOKEN_ADDRESS`), plus `EAS_SCHEMA_ID`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexerConfig {
+    pub chain_id: u64,
+    pub rpc_url: Url,
+    pub poll: PollConfig,
+    /// Pause between two background polls, one block time by default.
+    pub poll_interval: Duration,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    chain_id: Option<u64>,
+    rpc_url: Option<String>,
+    chunk_size: Option<u64>,
+    confirmations: Option<u64>,
+    poll_interval_ms: Option<u64>,
+    #[serde(default)]
+    sources: Vec<SourceEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SourceEntry {
+    kind: EventSourceKind,
+    address: Address,
+    #[serde(default)]
+    start_block: u64,
+    schema_id: Option<B256>,
+}
+
+impl IndexerConfig {
+    /// Loads and validates the configuration from the process environment.
+    pub fn load() -> Result<Self, String> {
+        let file = match env::var("INDEXER_CONFIG") {
+            Ok(path) => Some(read_file(Path::new(&path))?),
+            Err(_) if Path::new(DEFAULT_CONFIG_PATH).exists() => {
+                Some(read_file(Path::new(DEFAULT_CONFIG_PATH))?)
+            }
+            Err(_) => None,
+        };
+        Self::parse(file.as_deref(), |name| env::var(name).ok())
+    }
+
+    /// Merges `file` (TOML contents) with the variables returned by `env`.
+    pub fn parse(file: Option<&str>, env: impl Fn(&str) -> Option<String>) -> Result<Self, String> {
+        let file: ConfigFile = match file {
+            Some(contents) => {
+                toml::from_str(contents).map_err(|e| format!("Invalid indexer config: {e}"))?
+            }
+            None => ConfigFile::default(),
+        };
+
+        let chain_id = env_override(&env, "CHAIN_ID", file.chain_id)?
+            .ok_or("chain_id (CHAIN_ID) must be set")?;
+        if chain_id == 0 {
+            return Err("chain_id must be greater than 0".to_string());
+        }
+
+        let rpc_url = env("RPC_URL")
+            .or(file.rpc_url)
+            .ok_or("rpc_url (RPC_URL) must be set")?;
+        let rpc_url: Url = rpc_url
+            .parse()
+            .map_err(|e| format!("Invalid rpc_url {rpc_url}: {e}"))?;
+        if !matches!(rpc_url.scheme(), "http" | "https") {
+            return Err(format!("rpc_url must be an http(s) URL, got {rpc_url}"));
+        }
+
+        let chunk_size =
+            env_override(&env, "POLL_CHUNK_SIZE", file.chunk_size)?.unwrap_or(DEFAULT_CHUNK_SIZE);
+        if chunk_size == 0 {
+            return Err("chunk_size must be greater than 0".to_string());
+        }
+        let confirmations = env_override(&env, "CONFIRMATIONS", file.confirmations)?
+            .unwrap_or(DEFAULT_CONFIRMATIONS);
+        if confirmations == 0 {
+            return Err("confirmations must be at least 1".to_string());
+        }
+        let poll_interval = match env_override(&env, "POLL_INTERVAL_MS", file.poll_interval_ms)? {
+            Some(0) => return Err("poll_interval_ms must be greater than 0".to_string()),
+            Some(ms) => Duration::from_millis(ms),
+            None => block_time(chain_id),
+        };
+
+        let mut sources: Vec<EventSource> = Vec::new();
+        for entry in file.sources {
+            if sources.iter().any(|s| s.kind == entry.kind) {
+                return Err(format!(
+                    "Source {} is configured more than once",
+                    entry.kind
+                ));
+            }
+            sources.push(EventSource {
+                kind: entry.kind,
+                address: entry.address,
+                start_block: entry.start_block,
+                schema_id: entry.schema_id,
+            });
+        }
+        for kind in EventSourceKind::ALL {
+            let prefix = kind.as_str().to_uppercase();
+            let address: Option<Address> = env_override(&env, &format!("{prefix}_ADDRESS"), None)?;
+            let start_block: Option<u64> =
+                env_override(&env, &format!("{prefix}_START_BLOCK"), None)?;
+            let schema_id: Option<B256> = env_override(&env, &format!("{prefix}_SCHEMA_ID"), None)?;
+            match sources.iter_mut().find(|s| s.kind == kind) {
+                Some(source) => {
+                    source.address = address.unwrap_or(source.address);
+                    source.start_block = start_block.unwrap_or(source.start_block);
+                    source.schema_id = schema_id.or(source.schema_id);
+                }
+                None => {
+                    if let Some(address) = address {
+                        sources.push(EventSource {
+                            kind,
+                            address,
+                            start_block: start_block.unwrap_or_default(),
+                            schema_id,
+                        });
+                    } else if start_block.is_some() {
+                        return Err(format!(
+                            "{prefix}_START_BLOCK is set without {prefix}_ADDRESS"
+                        ));
+                    }
+                }
+            }
+        }
+
+        if sources.is_empty() {
+            return Err("At least one event source must be configured".to_string());
+        }
+        let mut addresses = HashSet::new();
+        for source in &sources {
+            if source.address == Address::ZERO {
+                return Err(format!("Source {} has a zero address", source.kind));
+            }
+            match (source.kind, source.schema_id) {
+                (EventSourceKind::Eas, None) => {
+                    return Err("Source eas needs a schema_id (EAS_SCHEMA_ID)".to_string());
+                }
+                (EventSourceKind::Eas, Some(_)) | (_, None) => {}
+                (kind, Some(_)) => {
+                    return Err(format!("Source {kind} does not take a schema_id"));
+                }
+            }
+            if !addresses.insert(source.address) {
+                return Err(format!(
+                    "Address {} is used by more than one source",
+                    source.address
+                ));
+            }
+        }
+
+        Ok(Self {
+            chain_id,
+            rpc_url,
+            poll: PollConfig {
+                sources,
+                chunk_size,
+                confirmations,
+            },
+            poll_interval,
+        })
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {e}", path.display()))
+}
+
+/// The variable `name` parsed as `T` if set, `fallback` otherwise.
+fn env_override<T: FromStr>(
+    env: &impl Fn(&str) -> Option<String>,
+    name: &str,
+    fallback: Option<T>,
+) -> Result<Option<T>, String> {
+    match env(name) {
+        Some(value) => value
+            .parse()
+            .map(Some)
+            .map_err(|_| format!("Invalid {name}: {value}")),
+        None => Ok(fallback),
+    }
+}
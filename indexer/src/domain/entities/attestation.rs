@@ -0,0 +1,73 @@
+use alloy::primitives::{Address, B256};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::ethereum_event::{EthereumEvent, EthereumEventType};
+
+/// An attestation of the guild schema: `attester` certifies that `recipient`
+/// earned `badge_name`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attestation {
+    pub uid: B256,
+    pub recipient: Address,
+    pub attester: Address,
+    pub badge_name: String,
+    pub justification: String,
+    pub eas_address: Address,
+    pub block_number: u64,
+    pub created_at: DateTime<Utc>,
+    /// Block of the revocation, None while the attestation stands.
+    pub revoked_block: Option<u64>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl Attestation {
+    /// The attestation an `AttestationCreated` event records, None for other
+    /// events.
+    pub fn from_event(event: &EthereumEvent) -> Option<Self> {
+        match &event.event_type {
+            EthereumEventType::AttestationCreated {
+                uid,
+                recipient,
+                attester,
+                badge_name,
+                justification,
+            } => Some(Self {
+                uid: *uid,
+                recipient: *recipient,
+                attester: *attester,
+                badge_name: badge_name.clone(),
+                justification: justification.clone(),
+                eas_address: event.contract_address,
+                block_number: event.block_number,
+                created_at: event.timestamp,
+                revoked_block: None,
+                revoked_at: None,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_block.is_some()
+    }
+
+    pub fn matches(&self, filter: &AttestationFilter) -> bool {
+        filter.recipient.is_none_or(|r| r == self.recipient)
+            && filter.attester.is_none_or(|a| a == self.attester)
+            && filter
+                .badge_name
+                .as_ref()
+                .is_none_or(|b| *b == self.badge_name)
+            && (filter.include_revoked || !self.is_revoked())
+    }
+}
+
+/// Narrows an attestation listing; unset fields match everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AttestationFilter {
+    pub recipient: Option<Address>,
+    pub attester: Option<Address>,
+    pub badge_name: Option<String>,
+    pub include_revoked: bool,
+}
@@ -0,0 +1,37 @@
+use alloy::primitives::Address;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::ethereum_event::{EthereumEvent, EthereumEventType};
+
+/// A badge created in TheGuildBadgeRegistry. Names are unique on-chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Badge {
+    pub name: String,
+    pub description: String,
+    pub creator: Address,
+    pub registry_address: Address,
+    pub block_number: u64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Badge {
+    /// The badge a `BadgeCreated` event describes, None for other events.
+    pub fn from_event(event: &EthereumEvent) -> Option<Self> {
+        match &event.event_type {
+            EthereumEventType::BadgeCreated {
+                name,
+                description,
+                creator,
+            } => Some(Self {
+                name: name.clone(),
+                description: description.clone(),
+                creator: *creator,
+                registry_address: event.contract_address,
+                block_number: event.block_number,
+                created_at: event.timestamp,
+            }),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use alloy::primitives::Address;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::ethereum_event::{EthereumEvent, EthereumEventType};
+
+/// One vote cast in TheGuildBadgeRanking. The contract allows a single vote
+/// per voter and badge.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BadgeUpvote {
+    pub badge_name: String,
+    pub voter: Address,
+    pub ranking_address: Address,
+    pub block_number: u64,
+    pub voted_at: DateTime<Utc>,
+}
+
+impl BadgeUpvote {
+    /// The vote a `BadgeUpvoted` event records, None for other events.
+    pub fn from_event(event: &EthereumEvent) -> Option<Self> {
+        match &event.event_type {
+            EthereumEventType::BadgeUpvoted { badge_name, voter } => Some(Self {
+                badge_name: badge_name.clone(),
+                voter: *voter,
+                ranking_address: event.contract_address,
+                block_number: event.block_number,
+                voted_at: event.timestamp,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Number of upvotes a badge received, as `getUpvotes` would return it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BadgeVotes {
+    pub badge_name: String,
+    pub upvotes: u64,
+}
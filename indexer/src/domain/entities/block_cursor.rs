@@ -0,0 +1,34 @@
+use alloy::primitives::{Address, B256};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Saved cursors kept per contract to find the fork point of a reorg. Older
+/// ones are dropped; a reorg deeper than all of them replays from the start
+/// block.
+pub const CURSOR_HISTORY_DEPTH: u64 = 128;
+
+/// Last block whose logs have been stored for a contract. Polling resumes from
+/// the block after it, so a restart never re-reads the whole chain. The block
+/// hash is compared with the next block's parent hash to detect reorgs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockCursor {
+    pub contract_address: Address,
+    pub last_indexed_block: u64,
+    pub block_hash: B256,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl BlockCursor {
+    pub fn new(contract_address: Address, last_indexed_block: u64, block_hash: B256) -> Self {
+        Self {
+            contract_address,
+            last_indexed_block,
+            block_hash,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn next_block(&self) -> u64 {
+        self.last_indexed_block + 1
+    }
+}
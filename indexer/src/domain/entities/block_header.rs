@@ -0,0 +1,10 @@
+use alloy::primitives::B256;
+use serde::{Deserialize, Serialize};
+
+/// The part of a block needed to follow the chain and notice reorgs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockHeader {
+    pub number: u64,
+    pub hash: B256,
+    pub parent_hash: B256,
+}
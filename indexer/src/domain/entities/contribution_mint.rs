@@ -0,0 +1,60 @@
+use alloy::primitives::{Address, B256, Bytes, U256};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::ethereum_event::{EthereumEvent, EthereumEventType};
+
+/// A TGC `mintWithReason`. Distributions are matched on `reason_hash`, the
+/// keccak256 of the reason bytes, so it is kept even when `reason` could not
+/// be recovered (e.g. a mint relayed through a multisig).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContributionMint {
+    /// Id of the `MintedWithReason` event, `txhash-logindex`.
+    pub id: String,
+    pub tx_hash: B256,
+    pub recipient: Address,
+    pub amount: U256,
+    pub reason_hash: B256,
+    pub reason: Option<Bytes>,
+    pub token_address: Address,
+    pub block_number: u64,
+    pub minted_at: DateTime<Utc>,
+}
+
+impl ContributionMint {
+    /// The mint a `ContributionTokenMinted` event records, None for other
+    /// events.
+    pub fn from_event(event: &EthereumEvent) -> Option<Self> {
+        match &event.event_type {
+            EthereumEventType::ContributionTokenMinted {
+                recipient,
+                amount,
+                reason_hash,
+                reason,
+            } => Some(Self {
+                id: event.id.clone(),
+                tx_hash: event.tx_hash,
+                recipient: *recipient,
+                amount: *amount,
+                reason_hash: *reason_hash,
+                reason: reason.clone(),
+                token_address: event.contract_address,
+                block_number: event.block_number,
+                minted_at: event.timestamp,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn matches(&self, filter: &ContributionMintFilter) -> bool {
+        filter.recipient.is_none_or(|r| r == self.recipient)
+            && filter.tx_hash.is_none_or(|t| t == self.tx_hash)
+    }
+}
+
+/// Narrows a mint listing; unset fields match everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContributionMintFilter {
+    pub recipient: Option<Address>,
+    pub tx_hash: Option<B256>,
+}
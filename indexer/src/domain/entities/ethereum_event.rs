@@ -1,47 +1,247 @@
-use alloy::primitives::{Address, U256};
+use alloy::primitives::{Address, B256, Bytes, U256};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum EthereumEventType {
-    ActivityTokenMinted { recipient: Address, amount: U256 },
+    ActivityTokenMinted {
+        recipient: Address,
+        amount: U256,
+    },
+    /// A TGA transfer between holders, or a burn when `to` is zero. Mints
+    /// are recorded as `ActivityTokenMinted`.
+    ActivityTokenTransferred {
+        from: Address,
+        to: Address,
+        amount: U256,
+    },
+    BadgeCreated {
+        name: String,
+        description: String,
+        creator: Address,
+    },
+    /// An EAS attestation of the guild schema, certifying a badge.
+    AttestationCreated {
+        uid: B256,
+        recipient: Address,
+        attester: Address,
+        badge_name: String,
+        justification: String,
+    },
+    AttestationRevoked {
+        uid: B256,
+        recipient: Address,
+        attester: Address,
+    },
+    BadgeUpvoted {
+        badge_name: String,
+        voter: Address,
+    },
+    /// A TGC transfer; mints come from and burns go to the zero address.
+    ContributionTokenTransferred {
+        from: Address,
+        to: Address,
+        amount: U256,
+    },
+    /// TGC `MintedWithReason`. The log only carries the keccak256 of the
+    /// reason; the bytes are recovered from the minting call when possible.
+    ContributionTokenMinted {
+        recipient: Address,
+        amount: U256,
+        reason_hash: B256,
+        reason: Option<Bytes>,
+    },
+}
+
+impl EthereumEventType {
+    /// The variant name, which is also the key of its JSON payload.
+    pub fn name(&self) -> &'static str {
+        match self {
+            EthereumEventType::ActivityTokenMinted { .. } => "ActivityTokenMinted",
+            EthereumEventType::ActivityTokenTransferred { .. } => "ActivityTokenTransferred",
+            EthereumEventType::BadgeCreated { .. } => "BadgeCreated",
+            EthereumEventType::AttestationCreated { .. } => "AttestationCreated",
+            EthereumEventType::AttestationRevoked { .. } => "AttestationRevoked",
+            EthereumEventType::BadgeUpvoted { .. } => "BadgeUpvoted",
+            EthereumEventType::ContributionTokenTransferred { .. } => {
+                "ContributionTokenTransferred"
+            }
+            EthereumEventType::ContributionTokenMinted { .. } => "ContributionTokenMinted",
+        }
+    }
+}
+
+/// Where and when a log was emitted. The block hash tells whether the event is
+/// still part of the canonical chain after a reorg.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogLocation {
+    pub contract_address: Address,
+    pub block_number: u64,
+    pub block_hash: B256,
+    pub block_timestamp: DateTime<Utc>,
+    pub tx_hash: B256,
+    pub log_index: u64,
+}
+
+impl LogLocation {
+    /// Id of the event emitted here, `txhash-logindex`.
+    pub fn event_id(&self) -> String {
+        format!("{}-{}", self.tx_hash, self.log_index)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EthereumEvent {
     pub id: String,
     pub event_type: EthereumEventType,
+    pub contract_address: Address,
+    pub block_number: u64,
+    pub block_hash: B256,
+    pub tx_hash: B256,
+    pub log_index: u64,
+    /// Timestamp of the block the log was emitted in.
     pub timestamp: DateTime<Utc>,
+    /// When the indexer first stored the event.
     pub created_at: DateTime<Utc>,
 }
 
 impl EthereumEvent {
-    fn new(
-        id: String,
+    /// Rebuilds a stored event around its log coordinates.
+    pub fn new(
+        location: LogLocation,
         event_type: EthereumEventType,
-        timestamp: DateTime<Utc>,
         created_at: DateTime<Utc>,
     ) -> Self {
         Self {
-            id,
+            id: location.event_id(),
             event_type,
-            timestamp,
+            contract_address: location.contract_address,
+            block_number: location.block_number,
+            block_hash: location.block_hash,
+            tx_hash: location.tx_hash,
+            log_index: location.log_index,
+            timestamp: location.block_timestamp,
             created_at,
         }
     }
 
     pub fn new_activity_token_minted_from_transfer_data(
-        tx_hash: String,
-        log_index: String,
+        location: LogLocation,
         recipient: Address,
         amount: U256,
     ) -> Self {
-        let now = Utc::now();
         Self::new(
-            format!("{tx_hash}-{log_index}"),
+            location,
             EthereumEventType::ActivityTokenMinted { recipient, amount },
-            now,
-            now,
+            Utc::now(),
+        )
+    }
+
+    pub fn new_activity_token_transferred(
+        location: LogLocation,
+        from: Address,
+        to: Address,
+        amount: U256,
+    ) -> Self {
+        Self::new(
+            location,
+            EthereumEventType::ActivityTokenTransferred { from, to, amount },
+            Utc::now(),
+        )
+    }
+
+    pub fn new_badge_created(
+        location: LogLocation,
+        name: String,
+        description: String,
+        creator: Address,
+    ) -> Self {
+        Self::new(
+            location,
+            EthereumEventType::BadgeCreated {
+                name,
+                description,
+                creator,
+            },
+            Utc::now(),
+        )
+    }
+
+    pub fn new_attestation_created(
+        location: LogLocation,
+        uid: B256,
+        recipient: Address,
+        attester: Address,
+        badge_name: String,
+        justification: String,
+    ) -> Self {
+        Self::new(
+            location,
+            EthereumEventType::AttestationCreated {
+                uid,
+                recipient,
+                attester,
+                badge_name,
+                justification,
+            },
+            Utc::now(),
+        )
+    }
+
+    pub fn new_attestation_revoked(
+        location: LogLocation,
+        uid: B256,
+        recipient: Address,
+        attester: Address,
+    ) -> Self {
+        Self::new(
+            location,
+            EthereumEventType::AttestationRevoked {
+                uid,
+                recipient,
+                attester,
+            },
+            Utc::now(),
+        )
+    }
+
+    pub fn new_badge_upvoted(location: LogLocation, badge_name: String, voter: Address) -> Self {
+        Self::new(
+            location,
+            EthereumEventType::BadgeUpvoted { badge_name, voter },
+            Utc::now(),
+        )
+    }
+
+    pub fn new_contribution_token_transferred(
+        location: LogLocation,
+        from: Address,
+        to: Address,
+        amount: U256,
+    ) -> Self {
+        Self::new(
+            location,
+            EthereumEventType::ContributionTokenTransferred { from, to, amount },
+            Utc::now(),
+        )
+    }
+
+    pub fn new_contribution_token_minted(
+        location: LogLocation,
+        recipient: Address,
+        amount: U256,
+        reason_hash: B256,
+        reason: Option<Bytes>,
+    ) -> Self {
+        Self::new(
+            location,
+            EthereumEventType::ContributionTokenMinted {
+                recipient,
+                amount,
+                reason_hash,
+                reason,
+            },
+            Utc::now(),
         )
     }
 }
@@ -0,0 +1,55 @@
+use alloy::primitives::{Address, B256};
+use serde::{Deserialize, Serialize};
+
+/// Which contract ABI a source is decoded with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventSourceKind {
+    ActivityToken,
+    /// TheGuildBadgeRegistry, the V2 contract with `bytes` descriptions.
+    BadgeRegistry,
+    /// The EAS contract, narrowed to the guild's attestation schema.
+    Eas,
+    /// TheGuildBadgeRanking, one upvote per voter and badge.
+    BadgeRanking,
+    /// TheGuildContributionToken (TGC), followed at its UUPS proxy address:
+    /// the proxy emits the logs and keeps its address across upgrades.
+    ContributionToken,
+}
+
+impl EventSourceKind {
+    pub const ALL: [EventSourceKind; 5] = [
+        EventSourceKind::ActivityToken,
+        EventSourceKind::BadgeRegistry,
+        EventSourceKind::Eas,
+        EventSourceKind::BadgeRanking,
+        EventSourceKind::ContributionToken,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventSourceKind::ActivityToken => "activity_token",
+            EventSourceKind::BadgeRegistry => "badge_registry",
+            EventSourceKind::Eas => "eas",
+            EventSourceKind::BadgeRanking => "badge_ranking",
+            EventSourceKind::ContributionToken => "contribution_token",
+        }
+    }
+}
+
+impl std::fmt::Display for EventSourceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A contract the indexer follows, from `start_block` (usually its deployment
+/// block) onward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventSource {
+    pub kind: EventSourceKind,
+    pub address: Address,
+    pub start_block: u64,
+    /// Only set for `Eas`: the schema whose attestations are indexed.
+    pub schema_id: Option<B256>,
+}
@@ -1 +1,10 @@
+pub mod attestation;
+pub mod badge;
+pub mod badge_upvote;
+pub mod block_cursor;
+pub mod block_header;
+pub mod contribution_mint;
 pub mod ethereum_event;
+pub mod event_source;
+pub mod token_balance;
+pub mod token_transfer;
@@ -0,0 +1,22 @@
+use alloy::primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::token_transfer::TokenSymbol;
+
+/// What `holder` owns of a token, as folded from its indexed transfers. Only
+/// non-zero balances are kept.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenBalance {
+    pub symbol: TokenSymbol,
+    pub token_address: Address,
+    pub holder: Address,
+    pub balance: U256,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenHolders {
+    pub symbol: TokenSymbol,
+    pub holder_count: usize,
+    /// Largest balance first.
+    pub holders: Vec<TokenBalance>,
+}
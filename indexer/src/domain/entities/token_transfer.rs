@@ -0,0 +1,94 @@
+use alloy::primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::entities::{
+    ethereum_event::{EthereumEvent, EthereumEventType},
+    event_source::EventSourceKind,
+};
+
+/// The guild's ERC-20 tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TokenSymbol {
+    /// TheGuildActivityToken, minted on attestations.
+    #[serde(rename = "TGA", alias = "tga")]
+    Tga,
+    /// TheGuildContributionToken, the reward token.
+    #[serde(rename = "TGC", alias = "tgc")]
+    Tgc,
+}
+
+impl TokenSymbol {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenSymbol::Tga => "TGA",
+            TokenSymbol::Tgc => "TGC",
+        }
+    }
+
+    /// The kind of source the token's transfers are indexed from.
+    pub fn source_kind(&self) -> EventSourceKind {
+        match self {
+            TokenSymbol::Tga => EventSourceKind::ActivityToken,
+            TokenSymbol::Tgc => EventSourceKind::ContributionToken,
+        }
+    }
+}
+
+impl std::fmt::Display for TokenSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for TokenSymbol {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "TGA" => Ok(TokenSymbol::Tga),
+            "TGC" => Ok(TokenSymbol::Tgc),
+            _ => Err(format!("Invalid token symbol: {}", s)),
+        }
+    }
+}
+
+/// An ERC-20 `Transfer`. Mints come from and burns go to the zero address.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenTransfer {
+    /// Id of the event, `txhash-logindex`.
+    pub id: String,
+    pub symbol: TokenSymbol,
+    pub token_address: Address,
+    pub from: Address,
+    pub to: Address,
+    pub amount: U256,
+    pub block_number: u64,
+}
+
+impl TokenTransfer {
+    /// The transfer a TGA or TGC transfer event records, None for other
+    /// events.
+    pub fn from_event(event: &EthereumEvent) -> Option<Self> {
+        let (symbol, from, to, amount) = match &event.event_type {
+            EthereumEventType::ActivityTokenMinted { recipient, amount } => {
+                (TokenSymbol::Tga, Address::ZERO, *recipient, *amount)
+            }
+            EthereumEventType::ActivityTokenTransferred { from, to, amount } => {
+                (TokenSymbol::Tga, *from, *to, *amount)
+            }
+            EthereumEventType::ContributionTokenTransferred { from, to, amount } => {
+                (TokenSymbol::Tgc, *from, *to, *amount)
+            }
+            _ => return None,
+        };
+        Some(Self {
+            id: event.id.clone(),
+            symbol,
+            token_address: event.contract_address,
+            from,
+            to,
+            amount,
+            block_number: event.block_number,
+        })
+    }
+}
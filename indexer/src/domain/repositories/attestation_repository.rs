@@ -0,0 +1,13 @@
+use std::error::Error;
+
+use alloy::primitives::B256;
+use async_trait::async_trait;
+
+use crate::domain::entities::attestation::{Attestation, AttestationFilter};
+
+#[async_trait]
+pub trait AttestationRepository: Send + Sync {
+    /// Matching attestations, oldest first.
+    async fn list(&self, filter: &AttestationFilter) -> Result<Vec<Attestation>, Box<dyn Error>>;
+    async fn find_by_uid(&self, uid: &B256) -> Result<Option<Attestation>, Box<dyn Error>>;
+}
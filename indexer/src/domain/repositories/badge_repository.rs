@@ -0,0 +1,11 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use crate::domain::entities::badge::Badge;
+
+#[async_trait]
+pub trait BadgeRepository: Send + Sync {
+    async fn list(&self) -> Result<Vec<Badge>, Box<dyn Error>>;
+    async fn find_by_name(&self, name: &str) -> Result<Option<Badge>, Box<dyn Error>>;
+}
@@ -0,0 +1,14 @@
+use std::error::Error;
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+
+use crate::domain::entities::badge_upvote::{BadgeUpvote, BadgeVotes};
+
+#[async_trait]
+pub trait BadgeUpvoteRepository: Send + Sync {
+    /// Upvoted badges, most upvoted first, ties broken by name.
+    async fn ranking(&self) -> Result<Vec<BadgeVotes>, Box<dyn Error>>;
+    /// The voter's upvotes, oldest first.
+    async fn list_by_voter(&self, voter: &Address) -> Result<Vec<BadgeUpvote>, Box<dyn Error>>;
+}
@@ -0,0 +1,28 @@
+use std::error::Error;
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+
+use crate::domain::entities::block_cursor::BlockCursor;
+
+#[async_trait]
+pub trait BlockCursorRepository: Send + Sync {
+    async fn find(&self, contract_address: &Address)
+    -> Result<Option<BlockCursor>, Box<dyn Error>>;
+    /// Stores the cursor and records it in the contract's history, which keeps
+    /// the last `CURSOR_HISTORY_DEPTH` saves.
+    async fn save(&self, cursor: &BlockCursor) -> Result<(), Box<dyn Error>>;
+    /// Newest saved cursor below `block`, read from the history.
+    async fn find_before(
+        &self,
+        contract_address: &Address,
+        block: u64,
+    ) -> Result<Option<BlockCursor>, Box<dyn Error>>;
+    /// Forgets the saved cursors at or after `from_block`, which a reorg has
+    /// made stale. The cursor falls back to the newest one left, if any.
+    async fn delete_from_block(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>>;
+}
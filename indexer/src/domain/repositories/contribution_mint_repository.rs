@@ -0,0 +1,14 @@
+use std::error::Error;
+
+use async_trait::async_trait;
+
+use crate::domain::entities::contribution_mint::{ContributionMint, ContributionMintFilter};
+
+#[async_trait]
+pub trait ContributionMintRepository: Send + Sync {
+    /// Matching mints, oldest first.
+    async fn list(
+        &self,
+        filter: &ContributionMintFilter,
+    ) -> Result<Vec<ContributionMint>, Box<dyn Error>>;
+}
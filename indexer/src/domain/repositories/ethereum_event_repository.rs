@@ -1,5 +1,6 @@
 use std::error::Error;
 
+use alloy::primitives::Address;
 use async_trait::async_trait;
 
 use crate::domain::entities::ethereum_event::EthereumEvent;
@@ -8,4 +9,11 @@ use crate::domain::entities::ethereum_event::EthereumEvent;
 pub trait EthereumEventRepository: Send + Sync {
     async fn list(&self) -> Result<Vec<EthereumEvent>, Box<dyn Error>>;
     async fn insert_many(&self, ethereum_events: Vec<EthereumEvent>) -> Result<(), Box<dyn Error>>;
+    /// Removes the contract's events at or after `from_block`, which a reorg
+    /// has made stale.
+    async fn delete_from_block(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>>;
 }
@@ -0,0 +1,24 @@
+use std::error::Error;
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+
+use crate::domain::entities::ethereum_event::EthereumEvent;
+
+/// A read model derived from indexed events.
+#[async_trait]
+pub trait EventProjection: Send + Sync {
+    /// Folds newly indexed events into the read model. A chunk interrupted
+    /// before its cursor was saved is applied again, so this must be
+    /// idempotent: where the contract already forbids duplicates, a
+    /// unique-key conflict can only be such a replay and is skipped. Events
+    /// the projection does not care about are ignored.
+    async fn apply(&self, ethereum_events: &[EthereumEvent]) -> Result<(), Box<dyn Error>>;
+    /// Forgets what was derived from the contract's events at or after
+    /// `from_block`, after a reorg.
+    async fn rollback(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>>;
+}
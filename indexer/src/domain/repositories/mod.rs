@@ -1 +1,8 @@
+pub mod attestation_repository;
+pub mod badge_repository;
+pub mod badge_upvote_repository;
+pub mod block_cursor_repository;
+pub mod contribution_mint_repository;
 pub mod ethereum_event_repository;
+pub mod event_projection;
+pub mod token_balance_repository;
@@ -0,0 +1,14 @@
+use std::error::Error;
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+
+use crate::domain::entities::{token_balance::TokenBalance, token_transfer::TokenSymbol};
+
+#[async_trait]
+pub trait TokenBalanceRepository: Send + Sync {
+    /// The token's holders, largest balance first.
+    async fn holders(&self, symbol: TokenSymbol) -> Result<Vec<TokenBalance>, Box<dyn Error>>;
+    /// Every token `holder` owns, by symbol.
+    async fn balances_of(&self, holder: &Address) -> Result<Vec<TokenBalance>, Box<dyn Error>>;
+}
@@ -1,9 +1,35 @@
+use alloy::primitives::{Address, U256};
 use async_trait::async_trait;
 use std::error::Error;
 
-use crate::domain::entities::ethereum_event::EthereumEvent;
+use crate::domain::entities::{
+    block_header::BlockHeader, ethereum_event::EthereumEvent, event_source::EventSource,
+};
 
 #[async_trait]
 pub trait EthereumEventPollingService: Send + Sync {
-    async fn poll(&self) -> Result<Vec<EthereumEvent>, Box<dyn Error>>;
+    async fn chain_id(&self) -> Result<u64, Box<dyn Error>>;
+    async fn latest_block(&self) -> Result<u64, Box<dyn Error>>;
+    async fn block_header(&self, number: u64) -> Result<BlockHeader, Box<dyn Error>>;
+    /// Events emitted by `source` between `from_block` and `to_block`, both
+    /// inclusive.
+    async fn poll(
+        &self,
+        source: &EventSource,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<EthereumEvent>, Box<dyn Error>>;
+    /// ERC-20 `balanceOf(holder)` as of `block_number`.
+    async fn token_balance(
+        &self,
+        token: &Address,
+        holder: &Address,
+        block_number: u64,
+    ) -> Result<U256, Box<dyn Error>>;
+    /// ERC-20 `totalSupply()` as of `block_number`.
+    async fn token_total_supply(
+        &self,
+        token: &Address,
+        block_number: u64,
+    ) -> Result<U256, Box<dyn Error>>;
 }
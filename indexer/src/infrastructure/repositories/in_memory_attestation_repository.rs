@@ -0,0 +1,98 @@
+use std::{
+    error::Error,
+    sync::{Arc, RwLock},
+};
+
+use alloy::primitives::{Address, B256};
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::{
+        attestation::{Attestation, AttestationFilter},
+        ethereum_event::{EthereumEvent, EthereumEventType},
+    },
+    repositories::{
+        attestation_repository::AttestationRepository, event_projection::EventProjection,
+    },
+};
+
+/// Attestation read model kept in memory, for tests and local runs.
+#[derive(Clone, Default)]
+pub struct InMemoryAttestationRepository {
+    attestations: Arc<RwLock<Vec<Attestation>>>,
+}
+
+impl InMemoryAttestationRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AttestationRepository for InMemoryAttestationRepository {
+    async fn list(&self, filter: &AttestationFilter) -> Result<Vec<Attestation>, Box<dyn Error>> {
+        let mut attestations: Vec<Attestation> = self
+            .attestations
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|a| a.matches(filter))
+            .cloned()
+            .collect();
+        attestations.sort_by_key(|a| (a.block_number, a.uid.to_string()));
+        Ok(attestations)
+    }
+
+    async fn find_by_uid(&self, uid: &B256) -> Result<Option<Attestation>, Box<dyn Error>> {
+        Ok(self
+            .attestations
+            .read()
+            .unwrap()
+            .iter()
+            .find(|a| a.uid == *uid)
+            .cloned())
+    }
+}
+
+#[async_trait]
+impl EventProjection for InMemoryAttestationRepository {
+    async fn apply(&self, ethereum_events: &[EthereumEvent]) -> Result<(), Box<dyn Error>> {
+        let mut attestations = self.attestations.write().unwrap();
+        for attestation in ethereum_events.iter().filter_map(Attestation::from_event) {
+            if !attestations.iter().any(|a| a.uid == attestation.uid) {
+                attestations.push(attestation);
+            }
+        }
+        for event in ethereum_events {
+            if let EthereumEventType::AttestationRevoked { uid, .. } = &event.event_type
+                && let Some(attestation) = attestations
+                    .iter_mut()
+                    .find(|a| a.uid == *uid && !a.is_revoked())
+            {
+                attestation.revoked_block = Some(event.block_number);
+                attestation.revoked_at = Some(event.timestamp);
+            }
+        }
+        Ok(())
+    }
+
+    async fn rollback(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut attestations = self.attestations.write().unwrap();
+        attestations.retain(|a| a.eas_address != *contract_address || a.block_number < from_block);
+        for attestation in attestations.iter_mut() {
+            if attestation.eas_address == *contract_address
+                && attestation
+                    .revoked_block
+                    .is_some_and(|block| block >= from_block)
+            {
+                attestation.revoked_block = None;
+                attestation.revoked_at = None;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use std::{
+    error::Error,
+    sync::{Arc, RwLock},
+};
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::{badge::Badge, ethereum_event::EthereumEvent},
+    repositories::{badge_repository::BadgeRepository, event_projection::EventProjection},
+};
+
+/// Badge read model kept in memory, for tests and local runs.
+#[derive(Clone, Default)]
+pub struct InMemoryBadgeRepository {
+    badges: Arc<RwLock<Vec<Badge>>>,
+}
+
+impl InMemoryBadgeRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BadgeRepository for InMemoryBadgeRepository {
+    async fn list(&self) -> Result<Vec<Badge>, Box<dyn Error>> {
+        let mut badges = self.badges.read().unwrap().clone();
+        badges.sort_by(|a, b| (a.block_number, &a.name).cmp(&(b.block_number, &b.name)));
+        Ok(badges)
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<Badge>, Box<dyn Error>> {
+        Ok(self
+            .badges
+            .read()
+            .unwrap()
+            .iter()
+            .find(|b| b.name == name)
+            .cloned())
+    }
+}
+
+#[async_trait]
+impl EventProjection for InMemoryBadgeRepository {
+    async fn apply(&self, ethereum_events: &[EthereumEvent]) -> Result<(), Box<dyn Error>> {
+        let mut badges = self.badges.write().unwrap();
+        for badge in ethereum_events.iter().filter_map(Badge::from_event) {
+            if !badges.iter().any(|b| b.name == badge.name) {
+                badges.push(badge);
+            }
+        }
+        Ok(())
+    }
+
+    async fn rollback(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>> {
+        self.badges
+            .write()
+            .unwrap()
+            .retain(|b| b.registry_address != *contract_address || b.block_number < from_block);
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    sync::{Arc, RwLock},
+};
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::{
+        badge_upvote::{BadgeUpvote, BadgeVotes},
+        ethereum_event::EthereumEvent,
+    },
+    repositories::{
+        badge_upvote_repository::BadgeUpvoteRepository, event_projection::EventProjection,
+    },
+};
+
+/// Upvote read model kept in memory, for tests and local runs.
+#[derive(Clone, Default)]
+pub struct InMemoryBadgeUpvoteRepository {
+    upvotes: Arc<RwLock<Vec<BadgeUpvote>>>,
+}
+
+impl InMemoryBadgeUpvoteRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BadgeUpvoteRepository for InMemoryBadgeUpvoteRepository {
+    async fn ranking(&self) -> Result<Vec<BadgeVotes>, Box<dyn Error>> {
+        let mut counts: BTreeMap<String, u64> = BTreeMap::new();
+        for upvote in self.upvotes.read().unwrap().iter() {
+            *counts.entry(upvote.badge_name.clone()).or_default() += 1;
+        }
+        let mut ranking: Vec<BadgeVotes> = counts
+            .into_iter()
+            .map(|(badge_name, upvotes)| BadgeVotes {
+                badge_name,
+                upvotes,
+            })
+            .collect();
+        ranking.sort_by(|a, b| {
+            b.upvotes
+                .cmp(&a.upvotes)
+                .then(a.badge_name.cmp(&b.badge_name))
+        });
+        Ok(ranking)
+    }
+
+    async fn list_by_voter(&self, voter: &Address) -> Result<Vec<BadgeUpvote>, Box<dyn Error>> {
+        let mut upvotes: Vec<BadgeUpvote> = self
+            .upvotes
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|u| u.voter == *voter)
+            .cloned()
+            .collect();
+        upvotes
+            .sort_by(|a, b| (a.block_number, &a.badge_name).cmp(&(b.block_number, &b.badge_name)));
+        Ok(upvotes)
+    }
+}
+
+#[async_trait]
+impl EventProjection for InMemoryBadgeUpvoteRepository {
+    async fn apply(&self, ethereum_events: &[EthereumEvent]) -> Result<(), Box<dyn Error>> {
+        let mut upvotes = self.upvotes.write().unwrap();
+        for upvote in ethereum_events.iter().filter_map(BadgeUpvote::from_event) {
+            if !upvotes
+                .iter()
+                .any(|u| u.badge_name == upvote.badge_name && u.voter == upvote.voter)
+            {
+                upvotes.push(upvote);
+            }
+        }
+        Ok(())
+    }
+
+    async fn rollback(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>> {
+        self.upvotes
+            .write()
+            .unwrap()
+            .retain(|u| u.ranking_address != *contract_address || u.block_number < from_block);
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    error::Error,
+    sync::{Arc, RwLock},
+};
+
+use alloy::primitives::{Address, B256};
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::block_cursor::{BlockCursor, CURSOR_HISTORY_DEPTH},
+    repositories::block_cursor_repository::BlockCursorRepository,
+};
+
+#[derive(Default)]
+struct Cursors {
+    current: HashMap<Address, BlockCursor>,
+    history: HashMap<Address, BTreeMap<u64, B256>>,
+}
+
+/// Block cursors kept in memory, for tests and local runs.
+#[derive(Clone, Default)]
+pub struct InMemoryBlockCursorRepository {
+    cursors: Arc<RwLock<Cursors>>,
+}
+
+impl InMemoryBlockCursorRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BlockCursorRepository for InMemoryBlockCursorRepository {
+    async fn find(
+        &self,
+        contract_address: &Address,
+    ) -> Result<Option<BlockCursor>, Box<dyn Error>> {
+        Ok(self
+            .cursors
+            .read()
+            .unwrap()
+            .current
+            .get(contract_address)
+            .cloned())
+    }
+
+    async fn save(&self, cursor: &BlockCursor) -> Result<(), Box<dyn Error>> {
+        let mut cursors = self.cursors.write().unwrap();
+        cursors
+            .current
+            .insert(cursor.contract_address, cursor.clone());
+        let history = cursors.history.entry(cursor.contract_address).or_default();
+        history.insert(cursor.last_indexed_block, cursor.block_hash);
+        while history.len() as u64 > CURSOR_HISTORY_DEPTH {
+            history.pop_first();
+        }
+        Ok(())
+    }
+
+    async fn find_before(
+        &self,
+        contract_address: &Address,
+        block: u64,
+    ) -> Result<Option<BlockCursor>, Box<dyn Error>> {
+        Ok(self
+            .cursors
+            .read()
+            .unwrap()
+            .history
+            .get(contract_address)
+            .and_then(|history| history.range(..block).next_back())
+            .map(|(number, hash)| BlockCursor::new(*contract_address, *number, *hash)))
+    }
+
+    async fn delete_from_block(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut cursors = self.cursors.write().unwrap();
+        let remaining = cursors
+            .history
+            .get_mut(contract_address)
+            .and_then(|history| {
+                history.split_off(&from_block);
+                history
+                    .last_key_value()
+                    .map(|(number, hash)| (*number, *hash))
+            });
+        if cursors
+            .current
+            .get(contract_address)
+            .is_some_and(|cursor| cursor.last_indexed_block >= from_block)
+        {
+            match remaining {
+                Some((number, hash)) => {
+                    cursors.current.insert(
+                        *contract_address,
+                        BlockCursor::new(*contract_address, number, hash),
+                    );
+                }
+                None => {
+                    cursors.current.remove(contract_address);
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use std::{
+    error::Error,
+    sync::{Arc, RwLock},
+};
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::{
+        contribution_mint::{ContributionMint, ContributionMintFilter},
+        ethereum_event::EthereumEvent,
+    },
+    repositories::{
+        contribution_mint_repository::ContributionMintRepository, event_projection::EventProjection,
+    },
+};
+
+/// TGC mint read model kept in memory, for tests and local runs.
+#[derive(Clone, Default)]
+pub struct InMemoryContributionMintRepository {
+    mints: Arc<RwLock<Vec<ContributionMint>>>,
+}
+
+impl InMemoryContributionMintRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ContributionMintRepository for InMemoryContributionMintRepository {
+    async fn list(
+        &self,
+        filter: &ContributionMintFilter,
+    ) -> Result<Vec<ContributionMint>, Box<dyn Error>> {
+        let mut mints: Vec<ContributionMint> = self
+            .mints
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|m| m.matches(filter))
+            .cloned()
+            .collect();
+        mints.sort_by(|a, b| (a.block_number, &a.id).cmp(&(b.block_number, &b.id)));
+        Ok(mints)
+    }
+}
+
+#[async_trait]
+impl EventProjection for InMemoryContributionMintRepository {
+    async fn apply(&self, ethereum_events: &[EthereumEvent]) -> Result<(), Box<dyn Error>> {
+        let mut mints = self.mints.write().unwrap();
+        for mint in ethereum_events
+            .iter()
+            .filter_map(ContributionMint::from_event)
+        {
+            if !mints.iter().any(|m| m.id == mint.id) {
+                mints.push(mint);
+            }
+        }
+        Ok(())
+    }
+
+    async fn rollback(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>> {
+        self.mints
+            .write()
+            .unwrap()
+            .retain(|m| m.token_address != *contract_address || m.block_number < from_block);
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use std::{
+    error::Error,
+    sync::{Arc, RwLock},
+};
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::ethereum_event::EthereumEvent,
+    repositories::ethereum_event_repository::EthereumEventRepository,
+};
+
+/// Event log kept in memory, for tests and local runs.
+#[derive(Clone, Default)]
+pub struct InMemoryEthereumEventRepository {
+    events: Arc<RwLock<Vec<EthereumEvent>>>,
+}
+
+impl InMemoryEthereumEventRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl EthereumEventRepository for InMemoryEthereumEventRepository {
+    async fn list(&self) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
+        let mut events = self.events.read().unwrap().clone();
+        events.sort_by_key(|e| (e.block_number, e.log_index));
+        Ok(events)
+    }
+
+    async fn insert_many(&self, ethereum_events: Vec<EthereumEvent>) -> Result<(), Box<dyn Error>> {
+        let mut events = self.events.write().unwrap();
+        for event in ethereum_events {
+            match events
+                .iter_mut()
+                .find(|e| e.tx_hash == event.tx_hash && e.log_index == event.log_index)
+            {
+                Some(existing) => {
+                    *existing = EthereumEvent {
+                        created_at: existing.created_at,
+                        ..event
+                    }
+                }
+                None => events.push(event),
+            }
+        }
+        Ok(())
+    }
+
+    async fn delete_from_block(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>> {
+        self.events
+            .write()
+            .unwrap()
+            .retain(|e| e.contract_address != *contract_address || e.block_number < from_block);
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    sync::{Arc, RwLock},
+};
+
+use alloy::primitives::{Address, U256};
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::{
+        ethereum_event::EthereumEvent,
+        token_balance::TokenBalance,
+        token_transfer::{TokenSymbol, TokenTransfer},
+    },
+    repositories::{
+        event_projection::EventProjection, token_balance_repository::TokenBalanceRepository,
+    },
+};
+
+/// Token balance read model kept in memory, for tests and local runs. Balances
+/// are folded from the stored transfers on every read.
+#[derive(Clone, Default)]
+pub struct InMemoryTokenBalanceRepository {
+    transfers: Arc<RwLock<Vec<TokenTransfer>>>,
+}
+
+impl InMemoryTokenBalanceRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn balances(&self) -> Vec<TokenBalance> {
+        let mut transfers = self.transfers.read().unwrap().clone();
+        transfers.sort_by(|a, b| (a.block_number, &a.id).cmp(&(b.block_number, &b.id)));
+
+        let mut balances: BTreeMap<(Address, Address), TokenBalance> = BTreeMap::new();
+        for transfer in &transfers {
+            if transfer.from != Address::ZERO {
+                let balance = balance_entry(&mut balances, transfer, transfer.from);
+                *balance = balance.saturating_sub(transfer.amount);
+            }
+            if transfer.to != Address::ZERO {
+                let balance = balance_entry(&mut balances, transfer, transfer.to);
+                *balance = balance.saturating_add(transfer.amount);
+            }
+        }
+        balances
+            .into_values()
+            .filter(|b| !b.balance.is_zero())
+            .collect()
+    }
+}
+
+fn balance_entry<'a>(
+    balances: &'a mut BTreeMap<(Address, Address), TokenBalance>,
+    transfer: &TokenTransfer,
+    holder: Address,
+) -> &'a mut U256 {
+    &mut balances
+        .entry((transfer.token_address, holder))
+        .or_insert(TokenBalance {
+            symbol: transfer.symbol,
+            token_address: transfer.token_address,
+            holder,
+            balance: U256::ZERO,
+        })
+        .balance
+}
+
+#[async_trait]
+impl TokenBalanceRepository for InMemoryTokenBalanceRepository {
+    async fn holders(&self, symbol: TokenSymbol) -> Result<Vec<TokenBalance>, Box<dyn Error>> {
+        let mut holders: Vec<TokenBalance> = self
+            .balances()
+            .into_iter()
+            .filter(|b| b.symbol == symbol)
+            .collect();
+        holders.sort_by(|a, b| b.balance.cmp(&a.balance).then(a.holder.cmp(&b.holder)));
+        Ok(holders)
+    }
+
+    async fn balances_of(&self, holder: &Address) -> Result<Vec<TokenBalance>, Box<dyn Error>> {
+        let mut balances: Vec<TokenBalance> = self
+            .balances()
+            .into_iter()
+            .filter(|b| b.holder == *holder)
+            .collect();
+        balances.sort_by_key(|b| b.symbol.as_str());
+        Ok(balances)
+    }
+}
+
+#[async_trait]
+impl EventProjection for InMemoryTokenBalanceRepository {
+    async fn apply(&self, ethereum_events: &[EthereumEvent]) -> Result<(), Box<dyn Error>> {
+        let mut transfers = self.transfers.write().unwrap();
+        for transfer in ethereum_events.iter().filter_map(TokenTransfer::from_event) {
+            if !transfers.iter().any(|t| t.id == transfer.id) {
+                transfers.push(transfer);
+            }
+        }
+        Ok(())
+    }
+
+    async fn rollback(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>> {
+        self.transfers
+            .write()
+            .unwrap()
+            .retain(|t| t.token_address != *contract_address || t.block_number < from_block);
+        Ok(())
+    }
+}
@@ -1 +1,21 @@
+#[cfg(any(test, feature = "test-support"))]
+pub mod in_memory_attestation_repository;
+#[cfg(any(test, feature = "test-support"))]
+pub mod in_memory_badge_repository;
+#[cfg(any(test, feature = "test-support"))]
+pub mod in_memory_badge_upvote_repository;
+#[cfg(any(test, feature = "test-support"))]
+pub mod in_memory_block_cursor_repository;
+#[cfg(any(test, feature = "test-support"))]
+pub mod in_memory_contribution_mint_repository;
+#[cfg(any(test, feature = "test-support"))]
+pub mod in_memory_ethereum_event_repository;
+#[cfg(any(test, feature = "test-support"))]
+pub mod in_memory_token_balance_repository;
+pub mod postgres_attestation_repository;
+pub mod postgres_badge_repository;
+pub mod postgres_badge_upvote_repository;
+pub mod postgres_block_cursor_repository;
+pub mod postgres_contribution_mint_repository;
 pub mod postgres_ethereum_event_repository;
+pub mod postgres_token_balance_repository;
@@ -0,0 +1,200 @@
+use std::error::Error;
+
+use alloy::primitives::{Address, B256};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, QueryBuilder, Row, postgres::PgRow};
+
+use crate::domain::{
+    entities::{
+        attestation::{Attestation, AttestationFilter},
+        ethereum_event::{EthereumEvent, EthereumEventType},
+    },
+    repositories::{
+        attestation_repository::AttestationRepository, event_projection::EventProjection,
+    },
+};
+
+const ATTESTATION_COLUMNS: &str = "uid, recipient, attester, badge_name, justification, \
+     eas_address, block_number, created_at, revoked_block, revoked_at";
+
+#[derive(Clone)]
+pub struct PostgresAttestationRepository {
+    pool: PgPool,
+}
+
+impl PostgresAttestationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn attestation_from_row(row: &PgRow) -> Result<Attestation, Box<dyn Error>> {
+    Ok(Attestation {
+        uid: row.get::<&str, _>("uid").parse()?,
+        recipient: row.get::<&str, _>("recipient").parse()?,
+        attester: row.get::<&str, _>("attester").parse()?,
+        badge_name: row.get("badge_name"),
+        justification: row.get("justification"),
+        eas_address: row.get::<&str, _>("eas_address").parse()?,
+        block_number: row.get::<i64, _>("block_number") as u64,
+        created_at: row.get::<DateTime<Utc>, _>("created_at"),
+        revoked_block: row
+            .get::<Option<i64>, _>("revoked_block")
+            .map(|block| block as u64),
+        revoked_at: row.get::<Option<DateTime<Utc>>, _>("revoked_at"),
+    })
+}
+
+#[async_trait]
+impl AttestationRepository for PostgresAttestationRepository {
+    async fn list(&self, filter: &AttestationFilter) -> Result<Vec<Attestation>, Box<dyn Error>> {
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(format!(
+            "SELECT {ATTESTATION_COLUMNS} FROM attestations WHERE TRUE"
+        ));
+        if let Some(recipient) = &filter.recipient {
+            query
+                .push(" AND recipient = ")
+                .push_bind(recipient.to_string());
+        }
+        if let Some(attester) = &filter.attester {
+            query
+                .push(" AND attester = ")
+                .push_bind(attester.to_string());
+        }
+        if let Some(badge_name) = &filter.badge_name {
+            query
+                .push(" AND badge_name = ")
+                .push_bind(badge_name.clone());
+        }
+        if !filter.include_revoked {
+            query.push(" AND revoked_block IS NULL");
+        }
+        query.push(" ORDER BY block_number, uid");
+
+        let rows = query.build().fetch_all(&self.pool).await?;
+        rows.iter().map(attestation_from_row).collect()
+    }
+
+    async fn find_by_uid(&self, uid: &B256) -> Result<Option<Attestation>, Box<dyn Error>> {
+        let row = sqlx::query(&format!(
+            "SELECT {ATTESTATION_COLUMNS} FROM attestations WHERE uid = $1"
+        ))
+        .bind(uid.to_string())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(attestation_from_row).transpose()
+    }
+}
+
+#[async_trait]
+impl EventProjection for PostgresAttestationRepository {
+    async fn apply(&self, ethereum_events: &[EthereumEvent]) -> Result<(), Box<dyn Error>> {
+        let created: Vec<Attestation> = ethereum_events
+            .iter()
+            .filter_map(Attestation::from_event)
+            .collect();
+        let revoked: Vec<(&B256, &EthereumEvent)> = ethereum_events
+            .iter()
+            .filter_map(|event| match &event.event_type {
+                EthereumEventType::AttestationRevoked { uid, .. } => Some((uid, event)),
+                _ => None,
+            })
+            .collect();
+        if created.is_empty() && revoked.is_empty() {
+            return Ok(());
+        }
+
+        let mut tx = self.pool.begin().await?;
+
+        if !created.is_empty() {
+            let uids: Vec<String> = created.iter().map(|a| a.uid.to_string()).collect();
+            let recipients: Vec<String> = created.iter().map(|a| a.recipient.to_string()).collect();
+            let attesters: Vec<String> = created.iter().map(|a| a.attester.to_string()).collect();
+            let badge_names: Vec<String> = created.iter().map(|a| a.badge_name.clone()).collect();
+            let justifications: Vec<String> =
+                created.iter().map(|a| a.justification.clone()).collect();
+            let eas_addresses: Vec<String> =
+                created.iter().map(|a| a.eas_address.to_string()).collect();
+            let block_numbers: Vec<i64> = created
+                .iter()
+                .map(|a| i64::try_from(a.block_number))
+                .collect::<Result<_, _>>()?;
+            let created_ats: Vec<DateTime<Utc>> = created.iter().map(|a| a.created_at).collect();
+
+            // A replayed chunk inserts the same uids again.
+            sqlx::query(
+                r#"
+                INSERT INTO attestations
+                    (uid, recipient, attester, badge_name, justification,
+                     eas_address, block_number, created_at)
+                SELECT * FROM UNNEST(
+                    $1::text[], $2::text[], $3::text[], $4::text[], $5::text[],
+                    $6::text[], $7::bigint[], $8::timestamptz[]
+                )
+                ON CONFLICT (uid) DO NOTHING
+                "#,
+            )
+            .bind(&uids)
+            .bind(&recipients)
+            .bind(&attesters)
+            .bind(&badge_names)
+            .bind(&justifications)
+            .bind(&eas_addresses)
+            .bind(&block_numbers)
+            .bind(&created_ats)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        // Revocations come after the attestations they revoke, possibly in
+        // the same chunk, so they are applied once the inserts are done.
+        for (uid, event) in revoked {
+            sqlx::query(
+                r#"
+                UPDATE attestations
+                SET revoked_block = $2, revoked_at = $3
+                WHERE uid = $1 AND revoked_block IS NULL
+                "#,
+            )
+            .bind(uid.to_string())
+            .bind(i64::try_from(event.block_number)?)
+            .bind(event.timestamp)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn rollback(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>> {
+        let from_block = i64::try_from(from_block)?;
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("DELETE FROM attestations WHERE eas_address = $1 AND block_number >= $2")
+            .bind(contract_address.to_string())
+            .bind(from_block)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query(
+            r#"
+            UPDATE attestations
+            SET revoked_block = NULL, revoked_at = NULL
+            WHERE eas_address = $1 AND revoked_block >= $2
+            "#,
+        )
+        .bind(contract_address.to_string())
+        .bind(from_block)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+use std::error::Error;
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row, postgres::PgRow};
+
+use crate::domain::{
+    entities::{badge::Badge, ethereum_event::EthereumEvent},
+    repositories::{badge_repository::BadgeRepository, event_projection::EventProjection},
+};
+
+#[derive(Clone)]
+pub struct PostgresBadgeRepository {
+    pool: PgPool,
+}
+
+impl PostgresBadgeRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn badge_from_row(row: &PgRow) -> Result<Badge, Box<dyn Error>> {
+    Ok(Badge {
+        name: row.get("name"),
+        description: row.get("description"),
+        creator: row.get::<&str, _>("creator").parse()?,
+        registry_address: row.get::<&str, _>("registry_address").parse()?,
+        block_number: row.get::<i64, _>("block_number") as u64,
+        created_at: row.get::<DateTime<Utc>, _>("created_at"),
+    })
+}
+
+#[async_trait]
+impl BadgeRepository for PostgresBadgeRepository {
+    async fn list(&self) -> Result<Vec<Badge>, Box<dyn Error>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT name, description, creator, registry_address, block_number, created_at
+            FROM badges
+            ORDER BY block_number, name
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(badge_from_row).collect()
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<Badge>, Box<dyn Error>> {
+        let row = sqlx::query(
+            r#"
+            SELECT name, description, creator, registry_address, block_number, created_at
+            FROM badges
+            WHERE name = $1
+            "#,
+        )
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(badge_from_row).transpose()
+    }
+}
+
+#[async_trait]
+impl EventProjection for PostgresBadgeRepository {
+    async fn apply(&self, ethereum_events: &[EthereumEvent]) -> Result<(), Box<dyn Error>> {
+        let badges: Vec<Badge> = ethereum_events
+            .iter()
+            .filter_map(Badge::from_event)
+            .collect();
+        if badges.is_empty() {
+            return Ok(());
+        }
+
+        let names: Vec<String> = badges.iter().map(|b| b.name.clone()).collect();
+        let descriptions: Vec<String> = badges.iter().map(|b| b.description.clone()).collect();
+        let creators: Vec<String> = badges.iter().map(|b| b.creator.to_string()).collect();
+        let registries: Vec<String> = badges
+            .iter()
+            .map(|b| b.registry_address.to_string())
+            .collect();
+        let block_numbers: Vec<i64> = badges
+            .iter()
+            .map(|b| i64::try_from(b.block_number))
+            .collect::<Result<_, _>>()?;
+        let created_ats: Vec<DateTime<Utc>> = badges.iter().map(|b| b.created_at).collect();
+
+        // The registry rejects duplicate names (see EventProjection::apply).
+        sqlx::query(
+            r#"
+            INSERT INTO badges
+                (name, description, creator, registry_address, block_number, created_at)
+            SELECT * FROM UNNEST(
+                $1::text[], $2::text[], $3::text[], $4::text[], $5::bigint[], $6::timestamptz[]
+            )
+            ON CONFLICT (name) DO NOTHING
+            "#,
+        )
+        .bind(&names)
+        .bind(&descriptions)
+        .bind(&creators)
+        .bind(&registries)
+        .bind(&block_numbers)
+        .bind(&created_ats)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn rollback(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>> {
+        sqlx::query(
+            r#"
+            DELETE FROM badges
+            WHERE registry_address = $1 AND block_number >= $2
+            "#,
+        )
+        .bind(contract_address.to_string())
+        .bind(i64::try_from(from_block)?)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
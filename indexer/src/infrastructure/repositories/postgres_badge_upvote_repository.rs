@@ -0,0 +1,143 @@
+use std::error::Error;
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row, postgres::PgRow};
+
+use crate::domain::{
+    entities::{
+        badge_upvote::{BadgeUpvote, BadgeVotes},
+        ethereum_event::EthereumEvent,
+    },
+    repositories::{
+        badge_upvote_repository::BadgeUpvoteRepository, event_projection::EventProjection,
+    },
+};
+
+#[derive(Clone)]
+pub struct PostgresBadgeUpvoteRepository {
+    pool: PgPool,
+}
+
+impl PostgresBadgeUpvoteRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn upvote_from_row(row: &PgRow) -> Result<BadgeUpvote, Box<dyn Error>> {
+    Ok(BadgeUpvote {
+        badge_name: row.get("badge_name"),
+        voter: row.get::<&str, _>("voter").parse()?,
+        ranking_address: row.get::<&str, _>("ranking_address").parse()?,
+        block_number: row.get::<i64, _>("block_number") as u64,
+        voted_at: row.get::<DateTime<Utc>, _>("voted_at"),
+    })
+}
+
+#[async_trait]
+impl BadgeUpvoteRepository for PostgresBadgeUpvoteRepository {
+    async fn ranking(&self) -> Result<Vec<BadgeVotes>, Box<dyn Error>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT badge_name, COUNT(*) AS upvotes
+            FROM badge_upvotes
+            GROUP BY badge_name
+            ORDER BY upvotes DESC, badge_name
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .iter()
+            .map(|row| BadgeVotes {
+                badge_name: row.get("badge_name"),
+                upvotes: row.get::<i64, _>("upvotes") as u64,
+            })
+            .collect())
+    }
+
+    async fn list_by_voter(&self, voter: &Address) -> Result<Vec<BadgeUpvote>, Box<dyn Error>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT badge_name, voter, ranking_address, block_number, voted_at
+            FROM badge_upvotes
+            WHERE voter = $1
+            ORDER BY block_number, badge_name
+            "#,
+        )
+        .bind(voter.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(upvote_from_row).collect()
+    }
+}
+
+#[async_trait]
+impl EventProjection for PostgresBadgeUpvoteRepository {
+    async fn apply(&self, ethereum_events: &[EthereumEvent]) -> Result<(), Box<dyn Error>> {
+        let upvotes: Vec<BadgeUpvote> = ethereum_events
+            .iter()
+            .filter_map(BadgeUpvote::from_event)
+            .collect();
+        if upvotes.is_empty() {
+            return Ok(());
+        }
+
+        let badge_names: Vec<String> = upvotes.iter().map(|u| u.badge_name.clone()).collect();
+        let voters: Vec<String> = upvotes.iter().map(|u| u.voter.to_string()).collect();
+        let rankings: Vec<String> = upvotes
+            .iter()
+            .map(|u| u.ranking_address.to_string())
+            .collect();
+        let block_numbers: Vec<i64> = upvotes
+            .iter()
+            .map(|u| i64::try_from(u.block_number))
+            .collect::<Result<_, _>>()?;
+        let voted_ats: Vec<DateTime<Utc>> = upvotes.iter().map(|u| u.voted_at).collect();
+
+        // The ranking contract allows one vote per voter and badge (see
+        // EventProjection::apply).
+        sqlx::query(
+            r#"
+            INSERT INTO badge_upvotes
+                (badge_name, voter, ranking_address, block_number, voted_at)
+            SELECT * FROM UNNEST(
+                $1::text[], $2::text[], $3::text[], $4::bigint[], $5::timestamptz[]
+            )
+            ON CONFLICT (badge_name, voter) DO NOTHING
+            "#,
+        )
+        .bind(&badge_names)
+        .bind(&voters)
+        .bind(&rankings)
+        .bind(&block_numbers)
+        .bind(&voted_ats)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn rollback(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>> {
+        sqlx::query(
+            r#"
+            DELETE FROM badge_upvotes
+            WHERE ranking_address = $1 AND block_number >= $2
+            "#,
+        )
+        .bind(contract_address.to_string())
+        .bind(i64::try_from(from_block)?)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use std::error::Error;
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
+
+use crate::domain::{
+    entities::block_cursor::{BlockCursor, CURSOR_HISTORY_DEPTH},
+    repositories::block_cursor_repository::BlockCursorRepository,
+};
+
+#[derive(Clone)]
+pub struct PostgresBlockCursorRepository {
+    pool: PgPool,
+}
+
+impl PostgresBlockCursorRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BlockCursorRepository for PostgresBlockCursorRepository {
+    async fn find(
+        &self,
+        contract_address: &Address,
+    ) -> Result<Option<BlockCursor>, Box<dyn Error>> {
+        let row = sqlx::query(
+            r#"
+            SELECT last_indexed_block, block_hash, updated_at
+            FROM block_cursors
+            WHERE contract_address = $1
+            "#,
+        )
+        .bind(contract_address.to_string())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|row| {
+            Ok(BlockCursor {
+                contract_address: *contract_address,
+                last_indexed_block: row.get::<i64, _>("last_indexed_block") as u64,
+                block_hash: row.get::<&str, _>("block_hash").parse()?,
+                updated_at: row.get::<DateTime<Utc>, _>("updated_at"),
+            })
+        })
+        .transpose()
+    }
+
+    async fn save(&self, cursor: &BlockCursor) -> Result<(), Box<dyn Error>> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            r#"
+            INSERT INTO block_cursors (contract_address, last_indexed_block, block_hash, updated_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (contract_address)
+            DO UPDATE SET last_indexed_block = EXCLUDED.last_indexed_block,
+                          block_hash = EXCLUDED.block_hash,
+                          updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(cursor.contract_address.to_string())
+        .bind(i64::try_from(cursor.last_indexed_block)?)
+        .bind(cursor.block_hash.to_string())
+        .bind(cursor.updated_at)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO block_cursor_history (contract_address, block_number, block_hash)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (contract_address, block_number)
+            DO UPDATE SET block_hash = EXCLUDED.block_hash
+            "#,
+        )
+        .bind(cursor.contract_address.to_string())
+        .bind(i64::try_from(cursor.last_indexed_block)?)
+        .bind(cursor.block_hash.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            DELETE FROM block_cursor_history
+            WHERE contract_address = $1
+              AND block_number < (
+                  SELECT block_number FROM block_cursor_history
+                  WHERE contract_address = $1
+                  ORDER BY block_number DESC
+                  OFFSET $2 - 1 LIMIT 1
+              )
+            "#,
+        )
+        .bind(cursor.contract_address.to_string())
+        .bind(i64::try_from(CURSOR_HISTORY_DEPTH)?)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_before(
+        &self,
+        contract_address: &Address,
+        block: u64,
+    ) -> Result<Option<BlockCursor>, Box<dyn Error>> {
+        let row = sqlx::query(
+            r#"
+            SELECT block_number, block_hash
+            FROM block_cursor_history
+            WHERE contract_address = $1 AND block_number < $2
+            ORDER BY block_number DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(contract_address.to_string())
+        .bind(i64::try_from(block)?)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|row| {
+            Ok(BlockCursor::new(
+                *contract_address,
+                u64::try_from(row.get::<i64, _>("block_number"))?,
+                row.get::<&str, _>("block_hash").parse()?,
+            ))
+        })
+        .transpose()
+    }
+
+    async fn delete_from_block(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut tx = self.pool.begin().await?;
+        for statement in [
+            "DELETE FROM block_cursor_history WHERE contract_address = $1 AND block_number >= $2",
+            "DELETE FROM block_cursors WHERE contract_address = $1 AND last_indexed_block >= $2",
+        ] {
+            sqlx::query(statement)
+                .bind(contract_address.to_string())
+                .bind(i64::try_from(from_block)?)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO block_cursors (contract_address, last_indexed_block, block_hash, updated_at)
+            SELECT contract_address, block_number, block_hash, NOW()
+            FROM block_cursor_history
+            WHERE contract_address = $1
+            ORDER BY block_number DESC
+            LIMIT 1
+            ON CONFLICT (contract_address) DO NOTHING
+            "#,
+        )
+        .bind(contract_address.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+}
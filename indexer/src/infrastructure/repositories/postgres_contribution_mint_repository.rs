@@ -0,0 +1,141 @@
+use std::error::Error;
+
+use alloy::primitives::{Address, Bytes};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, QueryBuilder, Row, postgres::PgRow};
+
+use crate::domain::{
+    entities::{
+        contribution_mint::{ContributionMint, ContributionMintFilter},
+        ethereum_event::EthereumEvent,
+    },
+    repositories::{
+        contribution_mint_repository::ContributionMintRepository, event_projection::EventProjection,
+    },
+};
+
+#[derive(Clone)]
+pub struct PostgresContributionMintRepository {
+    pool: PgPool,
+}
+
+impl PostgresContributionMintRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn mint_from_row(row: &PgRow) -> Result<ContributionMint, Box<dyn Error>> {
+    Ok(ContributionMint {
+        id: row.get("id"),
+        tx_hash: row.get::<&str, _>("tx_hash").parse()?,
+        recipient: row.get::<&str, _>("recipient").parse()?,
+        amount: row.get::<&str, _>("amount").parse()?,
+        reason_hash: row.get::<&str, _>("reason_hash").parse()?,
+        reason: row.get::<Option<Vec<u8>>, _>("reason").map(Bytes::from),
+        token_address: row.get::<&str, _>("token_address").parse()?,
+        block_number: row.get::<i64, _>("block_number") as u64,
+        minted_at: row.get::<DateTime<Utc>, _>("minted_at"),
+    })
+}
+
+#[async_trait]
+impl ContributionMintRepository for PostgresContributionMintRepository {
+    async fn list(
+        &self,
+        filter: &ContributionMintFilter,
+    ) -> Result<Vec<ContributionMint>, Box<dyn Error>> {
+        // Amounts can exceed 64 bits, so they cross the wire as text.
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "SELECT id, tx_hash, recipient, amount::text AS amount, reason_hash, reason, \
+             token_address, block_number, minted_at FROM contribution_mints WHERE TRUE",
+        );
+        if let Some(recipient) = &filter.recipient {
+            query
+                .push(" AND recipient = ")
+                .push_bind(recipient.to_string());
+        }
+        if let Some(tx_hash) = &filter.tx_hash {
+            query.push(" AND tx_hash = ").push_bind(tx_hash.to_string());
+        }
+        query.push(" ORDER BY block_number, id");
+
+        let rows = query.build().fetch_all(&self.pool).await?;
+        rows.iter().map(mint_from_row).collect()
+    }
+}
+
+#[async_trait]
+impl EventProjection for PostgresContributionMintRepository {
+    async fn apply(&self, ethereum_events: &[EthereumEvent]) -> Result<(), Box<dyn Error>> {
+        let mints: Vec<ContributionMint> = ethereum_events
+            .iter()
+            .filter_map(ContributionMint::from_event)
+            .collect();
+        if mints.is_empty() {
+            return Ok(());
+        }
+
+        let ids: Vec<String> = mints.iter().map(|m| m.id.clone()).collect();
+        let tx_hashes: Vec<String> = mints.iter().map(|m| m.tx_hash.to_string()).collect();
+        let recipients: Vec<String> = mints.iter().map(|m| m.recipient.to_string()).collect();
+        let amounts: Vec<String> = mints.iter().map(|m| m.amount.to_string()).collect();
+        let reason_hashes: Vec<String> = mints.iter().map(|m| m.reason_hash.to_string()).collect();
+        let reasons: Vec<Option<Vec<u8>>> = mints
+            .iter()
+            .map(|m| m.reason.as_ref().map(|r| r.to_vec()))
+            .collect();
+        let tokens: Vec<String> = mints.iter().map(|m| m.token_address.to_string()).collect();
+        let block_numbers: Vec<i64> = mints
+            .iter()
+            .map(|m| i64::try_from(m.block_number))
+            .collect::<Result<_, _>>()?;
+        let minted_ats: Vec<DateTime<Utc>> = mints.iter().map(|m| m.minted_at).collect();
+
+        sqlx::query(
+            r#"
+            INSERT INTO contribution_mints
+                (id, tx_hash, recipient, amount, reason_hash, reason, token_address,
+                 block_number, minted_at)
+            SELECT * FROM UNNEST(
+                $1::text[], $2::text[], $3::text[], $4::numeric[], $5::text[], $6::bytea[],
+                $7::text[], $8::bigint[], $9::timestamptz[]
+            )
+            ON CONFLICT (id) DO NOTHING
+            "#,
+        )
+        .bind(&ids)
+        .bind(&tx_hashes)
+        .bind(&recipients)
+        .bind(&amounts)
+        .bind(&reason_hashes)
+        .bind(&reasons)
+        .bind(&tokens)
+        .bind(&block_numbers)
+        .bind(&minted_ats)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn rollback(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>> {
+        sqlx::query(
+            r#"
+            DELETE FROM contribution_mints
+            WHERE token_address = $1 AND block_number >= $2
+            "#,
+        )
+        .bind(contract_address.to_string())
+        .bind(i64::try_from(from_block)?)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -1,11 +1,12 @@
 use std::error::Error;
 
+use alloy::primitives::Address;
 use async_trait::async_trait;
-use sqlx::PgPool;
-use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
 
 use crate::domain::{
-    entities::ethereum_event::EthereumEvent,
+    entities::ethereum_event::{EthereumEvent, LogLocation},
     repositories::ethereum_event_repository::EthereumEventRepository,
 };
 
@@ -23,24 +24,34 @@ impl PostgresEthereumEventRepository {
 #[async_trait]
 impl EthereumEventRepository for PostgresEthereumEventRepository {
     async fn list(&self) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
-        let rows = sqlx::query!(
+        let rows = sqlx::query(
             r#"
-            SELECT id, event_type, timestamp, created_at
+            SELECT tx_hash, log_index, payload::text AS payload, contract_address,
+                   block_number, block_hash, timestamp, created_at
             FROM ethereum_events
-            "#
+            ORDER BY block_number, log_index
+            "#,
         )
         .fetch_all(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
-        Ok(rows
-            .iter()
-            .map(|row| EthereumEvent {
-                id: row.id,
-                event_type: row.event_type.clone(),
-                timestamp: row.timestamp.clone(),
-                created_at: row.created_at.clone(),
+        .await?;
+
+        rows.iter()
+            .map(|row| {
+                let location = LogLocation {
+                    contract_address: row.get::<&str, _>("contract_address").parse()?,
+                    block_number: u64::try_from(row.get::<i64, _>("block_number"))?,
+                    block_hash: row.get::<&str, _>("block_hash").parse()?,
+                    block_timestamp: row.get::<DateTime<Utc>, _>("timestamp"),
+                    tx_hash: row.get::<&str, _>("tx_hash").parse()?,
+                    log_index: u64::try_from(row.get::<i64, _>("log_index"))?,
+                };
+                Ok(EthereumEvent::new(
+                    location,
+                    serde_json::from_str(row.get("payload"))?,
+                    row.get::<DateTime<Utc>, _>("created_at"),
+                ))
             })
-            .collect::<Vec<_>>())
+            .collect()
     }
 
     async fn insert_many(&self, ethereum_events: Vec<EthereumEvent>) -> Result<(), Box<dyn Error>> {
@@ -48,34 +59,88 @@ impl EthereumEventRepository for PostgresEthereumEventRepository {
             return Ok(());
         }
 
-        let ids: Vec<Uuid> = ethereum_events
+        let tx_hashes: Vec<String> = ethereum_events
+            .iter()
+            .map(|e| e.tx_hash.to_string())
+            .collect();
+        let log_indexes: Vec<i64> = ethereum_events
+            .iter()
+            .map(|e| i64::try_from(e.log_index))
+            .collect::<Result<_, _>>()?;
+        let event_names: Vec<&str> = ethereum_events
+            .iter()
+            .map(|e| e.event_type.name())
+            .collect();
+        let payloads: Vec<String> = ethereum_events
+            .iter()
+            .map(|e| serde_json::to_string(&e.event_type))
+            .collect::<Result<_, _>>()?;
+        let contract_addresses: Vec<String> = ethereum_events
             .iter()
-            .map(|e| Uuid::parse_str(&e.id).unwrap())
+            .map(|e| e.contract_address.to_string())
             .collect();
-        let event_types: Vec<String> = ethereum_events
+        let block_numbers: Vec<i64> = ethereum_events
             .iter()
-            .map(|e| format!("{:?}", e.event_type))
+            .map(|e| i64::try_from(e.block_number))
+            .collect::<Result<_, _>>()?;
+        let block_hashes: Vec<String> = ethereum_events
+            .iter()
+            .map(|e| e.block_hash.to_string())
             .collect();
-        let timestamps: Vec<chrono::DateTime<chrono::Utc>> =
-            ethereum_events.iter().map(|e| e.timestamp).collect();
-        let created_ats: Vec<chrono::DateTime<chrono::Utc>> =
+        let timestamps: Vec<DateTime<Utc>> = ethereum_events.iter().map(|e| e.timestamp).collect();
+        let created_ats: Vec<DateTime<Utc>> =
             ethereum_events.iter().map(|e| e.created_at).collect();
 
-        let rows = sqlx::query_as!(
-            EthereumEvent,
+        // Re-polling a range after a restart yields the same logs; refresh
+        // them in place and keep when they were first stored.
+        sqlx::query(
             r#"
-            INSERT INTO ethereum_events (id, event_type, timestamp, created_at)
-            SELECT * FROM UNNEST($1::uuid[], $2::text[], $3::timestamptz[], $4::timestamptz[])
-            RETURNING id, event_type, timestamp, created_at
+            INSERT INTO ethereum_events
+                (tx_hash, log_index, event_name, payload, contract_address,
+                 block_number, block_hash, timestamp, created_at)
+            SELECT * FROM UNNEST(
+                $1::text[], $2::bigint[], $3::text[], $4::jsonb[], $5::text[],
+                $6::bigint[], $7::text[], $8::timestamptz[], $9::timestamptz[]
+            )
+            ON CONFLICT (tx_hash, log_index) DO UPDATE SET
+                event_name = EXCLUDED.event_name,
+                payload = EXCLUDED.payload,
+                contract_address = EXCLUDED.contract_address,
+                block_number = EXCLUDED.block_number,
+                block_hash = EXCLUDED.block_hash,
+                timestamp = EXCLUDED.timestamp
             "#,
-            &ids,
-            &event_types,
-            &timestamps,
-            &created_ats
         )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        .bind(&tx_hashes)
+        .bind(&log_indexes)
+        .bind(&event_names)
+        .bind(&payloads)
+        .bind(&contract_addresses)
+        .bind(&block_numbers)
+        .bind(&block_hashes)
+        .bind(&timestamps)
+        .bind(&created_ats)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete_from_block(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>> {
+        sqlx::query(
+            r#"
+            DELETE FROM ethereum_events
+            WHERE contract_address = $1 AND block_number >= $2
+            "#,
+        )
+        .bind(contract_address.to_string())
+        .bind(i64::try_from(from_block)?)
+        .execute(&self.pool)
+        .await?;
 
         Ok(())
     }
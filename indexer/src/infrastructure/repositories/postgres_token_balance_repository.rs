@@ -0,0 +1,183 @@
+use std::error::Error;
+
+use alloy::primitives::Address;
+use async_trait::async_trait;
+use sqlx::{PgPool, Row, postgres::PgRow};
+
+use crate::domain::{
+    entities::{
+        ethereum_event::EthereumEvent,
+        token_balance::TokenBalance,
+        token_transfer::{TokenSymbol, TokenTransfer},
+    },
+    repositories::{
+        event_projection::EventProjection, token_balance_repository::TokenBalanceRepository,
+    },
+};
+
+/// Balances are kept in `token_balances` and moved by the transfers that are
+/// actually inserted into, or deleted from, the `token_transfers` ledger, so
+/// a replayed chunk changes nothing.
+#[derive(Clone)]
+pub struct PostgresTokenBalanceRepository {
+    pool: PgPool,
+}
+
+impl PostgresTokenBalanceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn balance_from_row(row: &PgRow) -> Result<TokenBalance, Box<dyn Error>> {
+    Ok(TokenBalance {
+        symbol: row.get::<&str, _>("symbol").parse()?,
+        token_address: row.get::<&str, _>("token_address").parse()?,
+        holder: row.get::<&str, _>("holder").parse()?,
+        balance: row.get::<&str, _>("balance").parse()?,
+    })
+}
+
+/// Moves balances by the transfers the `changed` statement returns: credited
+/// to `to` and debited from `from` when `sign` is 1, the other way round when
+/// it is -1. The zero address (mints and burns) holds no balance.
+fn fold_balances(changed: &str, sign: i32) -> String {
+    let zero = Address::ZERO;
+    format!(
+        r#"
+        WITH changed AS ({changed}),
+        deltas AS (
+            SELECT token_address, symbol, to_address AS holder, amount AS delta
+            FROM changed WHERE to_address <> '{zero}'
+            UNION ALL
+            SELECT token_address, symbol, from_address, -amount
+            FROM changed WHERE from_address <> '{zero}'
+        )
+        INSERT INTO token_balances (token_address, holder, symbol, balance)
+        SELECT token_address, holder, symbol, {sign} * SUM(delta)
+        FROM deltas
+        GROUP BY token_address, holder, symbol
+        ON CONFLICT (token_address, holder)
+        DO UPDATE SET balance = token_balances.balance + EXCLUDED.balance
+        "#
+    )
+}
+
+#[async_trait]
+impl TokenBalanceRepository for PostgresTokenBalanceRepository {
+    async fn holders(&self, symbol: TokenSymbol) -> Result<Vec<TokenBalance>, Box<dyn Error>> {
+        // Balances can exceed 64 bits, so they cross the wire as text.
+        let rows = sqlx::query(
+            r#"
+            SELECT symbol, token_address, holder, balance::text AS balance
+            FROM token_balances
+            WHERE symbol = $1
+            ORDER BY token_balances.balance DESC, holder
+            "#,
+        )
+        .bind(symbol.as_str())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(balance_from_row).collect()
+    }
+
+    async fn balances_of(&self, holder: &Address) -> Result<Vec<TokenBalance>, Box<dyn Error>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT symbol, token_address, holder, balance::text AS balance
+            FROM token_balances
+            WHERE holder = $1
+            ORDER BY symbol
+            "#,
+        )
+        .bind(holder.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(balance_from_row).collect()
+    }
+}
+
+#[async_trait]
+impl EventProjection for PostgresTokenBalanceRepository {
+    async fn apply(&self, ethereum_events: &[EthereumEvent]) -> Result<(), Box<dyn Error>> {
+        let transfers: Vec<TokenTransfer> = ethereum_events
+            .iter()
+            .filter_map(TokenTransfer::from_event)
+            .collect();
+        if transfers.is_empty() {
+            return Ok(());
+        }
+
+        let ids: Vec<String> = transfers.iter().map(|t| t.id.clone()).collect();
+        let symbols: Vec<&str> = transfers.iter().map(|t| t.symbol.as_str()).collect();
+        let tokens: Vec<String> = transfers
+            .iter()
+            .map(|t| t.token_address.to_string())
+            .collect();
+        let froms: Vec<String> = transfers.iter().map(|t| t.from.to_string()).collect();
+        let tos: Vec<String> = transfers.iter().map(|t| t.to.to_string()).collect();
+        let amounts: Vec<String> = transfers.iter().map(|t| t.amount.to_string()).collect();
+        let block_numbers: Vec<i64> = transfers
+            .iter()
+            .map(|t| i64::try_from(t.block_number))
+            .collect::<Result<_, _>>()?;
+
+        let mut tx = self.pool.begin().await?;
+
+        // Transfers already in the ledger come from a replayed chunk and are
+        // not returned, so they do not move balances twice.
+        let inserted = r#"
+            INSERT INTO token_transfers
+                (id, symbol, token_address, from_address, to_address, amount, block_number)
+            SELECT * FROM UNNEST(
+                $1::text[], $2::text[], $3::text[], $4::text[], $5::text[], $6::numeric[],
+                $7::bigint[]
+            )
+            ON CONFLICT (id) DO NOTHING
+            RETURNING token_address, symbol, from_address, to_address, amount
+        "#;
+        sqlx::query(&fold_balances(inserted, 1))
+            .bind(&ids)
+            .bind(&symbols)
+            .bind(&tokens)
+            .bind(&froms)
+            .bind(&tos)
+            .bind(&amounts)
+            .bind(&block_numbers)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("DELETE FROM token_balances WHERE balance = 0")
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn rollback(
+        &self,
+        contract_address: &Address,
+        from_block: u64,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut tx = self.pool.begin().await?;
+
+        let deleted = r#"
+            DELETE FROM token_transfers
+            WHERE token_address = $1 AND block_number >= $2
+            RETURNING token_address, symbol, from_address, to_address, amount
+        "#;
+        sqlx::query(&fold_balances(deleted, -1))
+            .bind(contract_address.to_string())
+            .bind(i64::try_from(from_block)?)
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("DELETE FROM token_balances WHERE balance = 0")
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+}
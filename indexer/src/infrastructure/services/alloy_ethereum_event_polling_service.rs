@@ -1,64 +1,439 @@
-use std::error::Error;
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    error::Error,
+};
 
 use alloy::{
-    primitives::Address,
+    consensus::Transaction,
+    eips::BlockId,
+    primitives::{Address, B256, Bytes, U256, keccak256},
     providers::{Provider, ProviderBuilder},
-    rpc::types::Filter,
+    rpc::types::{Filter, Log},
     sol,
-    sol_types::SolEvent,
+    sol_types::{SolCall, SolEvent, SolValue},
+    transports::http::reqwest::Url,
 };
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 use crate::domain::{
-    entities::ethereum_event::EthereumEvent,
+    entities::{
+        block_header::BlockHeader,
+        ethereum_event::{EthereumEvent, LogLocation},
+        event_source::{EventSource, EventSourceKind},
+    },
     services::ethereum_event_polling_service::EthereumEventPollingService,
 };
 
+/// Timestamp of each block the polled logs come from.
+type BlockTimestamps = HashMap<u64, DateTime<Utc>>;
+
 #[derive(Clone)]
-pub struct AlloyEthereumEventPollingService {}
+pub struct AlloyEthereumEventPollingService {
+    rpc_url: Url,
+}
 
 impl AlloyEthereumEventPollingService {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(rpc_url: Url) -> Self {
+        Self { rpc_url }
+    }
+
+    fn provider(&self) -> impl Provider {
+        ProviderBuilder::new().connect_http(self.rpc_url.clone())
+    }
+
+    /// Taken from the logs when the node includes it, from the block header
+    /// otherwise.
+    async fn block_timestamps(&self, logs: &[Log]) -> Result<BlockTimestamps, Box<dyn Error>> {
+        let provider = self.provider();
+        let mut timestamps = BlockTimestamps::new();
+        for log in logs {
+            let number = log.block_number.ok_or("log without block number")?;
+            if let Entry::Vacant(entry) = timestamps.entry(number) {
+                let seconds = match log.block_timestamp {
+                    Some(seconds) => seconds,
+                    None => {
+                        provider
+                            .get_block_by_number(number.into())
+                            .await?
+                            .ok_or_else(|| format!("Block {number} not found"))?
+                            .header
+                            .timestamp
+                    }
+                };
+                let timestamp = DateTime::from_timestamp(i64::try_from(seconds)?, 0)
+                    .ok_or_else(|| format!("Block {number} has an invalid timestamp"))?;
+                entry.insert(timestamp);
+            }
+        }
+        Ok(timestamps)
+    }
+
+    /// Attested logs only carry the uid; the badge and justification are read
+    /// back from EAS.
+    async fn eas_events(
+        &self,
+        eas_address: Address,
+        logs: Vec<Log>,
+        timestamps: &BlockTimestamps,
+    ) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
+        let provider = self.provider();
+        let eas = IEAS::new(eas_address, &provider);
+        let mut events: Vec<EthereumEvent> = Vec::new();
+
+        for log in logs {
+            match log.topic0() {
+                Some(&IEAS::Attested::SIGNATURE_HASH) => {
+                    let attested = IEAS::Attested::decode_log_data(log.data())?;
+                    let attestation = eas.getAttestation(attested.uid).call().await?;
+                    let Some((badge_name, justification)) =
+                        decode_badge_attestation_data(&attestation.data)
+                    else {
+                        tracing::warn!(uid = %attested.uid, "skipping attestation with undecodable data");
+                        continue;
+                    };
+                    events.push(EthereumEvent::new_attestation_created(
+                        location(&log, timestamps),
+                        attested.uid,
+                        attested.recipient,
+                        attested.attester,
+                        badge_name,
+                        justification,
+                    ));
+                }
+                Some(&IEAS::Revoked::SIGNATURE_HASH) => {
+                    let revoked = IEAS::Revoked::decode_log_data(log.data())?;
+                    events.push(EthereumEvent::new_attestation_revoked(
+                        location(&log, timestamps),
+                        revoked.uid,
+                        revoked.recipient,
+                        revoked.attester,
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// `MintedWithReason` indexes the reason, so the log only holds its hash;
+    /// the bytes are read back from the calldata of the minting transaction.
+    async fn contribution_token_events(
+        &self,
+        logs: Vec<Log>,
+        timestamps: &BlockTimestamps,
+    ) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
+        let provider = self.provider();
+        // A batch mint emits all its logs from a single transaction.
+        let mut inputs: HashMap<B256, Bytes> = HashMap::new();
+        let mut events: Vec<EthereumEvent> = Vec::new();
+
+        for log in logs {
+            match log.topic0() {
+                Some(&ITheGuildContributionToken::Transfer::SIGNATURE_HASH) => {
+                    let transfer =
+                        ITheGuildContributionToken::Transfer::decode_log_data(log.data())?;
+                    events.push(EthereumEvent::new_contribution_token_transferred(
+                        location(&log, timestamps),
+                        transfer.from,
+                        transfer.to,
+                        transfer.value,
+                    ));
+                }
+                Some(&ITheGuildContributionToken::MintedWithReason::SIGNATURE_HASH) => {
+                    let minted =
+                        ITheGuildContributionToken::MintedWithReason::decode_log_data(log.data())?;
+                    let location = location(&log, timestamps);
+                    let input = match inputs.entry(location.tx_hash) {
+                        Entry::Occupied(entry) => entry.into_mut(),
+                        Entry::Vacant(entry) => {
+                            let transaction = provider
+                                .get_transaction_by_hash(location.tx_hash)
+                                .await?
+                                .ok_or_else(|| {
+                                    format!("Transaction {} not found", location.tx_hash)
+                                })?;
+                            entry.insert(transaction.input().clone())
+                        }
+                    };
+                    let reason = mint_reason_from_calldata(input, minted.reason);
+                    if reason.is_none() {
+                        tracing::warn!(tx_hash = %location.tx_hash, "mint reason not found in calldata");
+                    }
+                    events.push(EthereumEvent::new_contribution_token_minted(
+                        location,
+                        minted.to,
+                        minted.amount,
+                        minted.reason,
+                        reason,
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(events)
     }
 }
 
 sol!(ITokenActivity, "contracts/activity_token_abi.json");
 
+sol! {
+    interface ITheGuildBadgeRegistry {
+        event BadgeCreated(bytes32 indexed name, bytes description, address indexed creator);
+    }
+}
+
+sol! {
+    interface ITheGuildBadgeRanking {
+        event BadgeUpvoted(bytes32 indexed badgeName, address indexed voter);
+    }
+}
+
+sol! {
+    interface ITheGuildContributionToken {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event MintedWithReason(address indexed to, uint256 indexed amount, bytes indexed reason);
+
+        function mintWithReason(address to, uint256 amount, bytes reason) external;
+        function batchMintWithReason(address[] recipients, uint256[] amounts, bytes[] reasons) external;
+    }
+}
+
+sol! {
+    #[sol(rpc)]
+    interface IERC20 {
+        function balanceOf(address account) external view returns (uint256);
+        function totalSupply() external view returns (uint256);
+    }
+}
+
+sol! {
+    #[sol(rpc)]
+    interface IEAS {
+        struct Attestation {
+            bytes32 uid;
+            bytes32 schema;
+            uint64 time;
+            uint64 expirationTime;
+            uint64 revocationTime;
+            bytes32 refUID;
+            address recipient;
+            address attester;
+            bool revocable;
+            bytes data;
+        }
+
+        event Attested(address indexed recipient, address indexed attester, bytes32 uid, bytes32 indexed schemaUID);
+        event Revoked(address indexed recipient, address indexed attester, bytes32 uid, bytes32 indexed schemaUID);
+
+        function getAttestation(bytes32 uid) external view returns (Attestation memory);
+    }
+}
+
+/// Badge names are UTF-8 right-padded with zeros to 32 bytes.
+pub fn bytes32_to_string(value: B256) -> String {
+    let end = value.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+    String::from_utf8_lossy(&value[..end]).into_owned()
+}
+
+fn location(log: &Log, timestamps: &BlockTimestamps) -> LogLocation {
+    let block_number = log.block_number.unwrap();
+    LogLocation {
+        contract_address: log.address(),
+        block_number,
+        block_hash: log.block_hash.unwrap(),
+        block_timestamp: timestamps[&block_number],
+        tx_hash: log.transaction_hash.unwrap(),
+        log_index: log.log_index.unwrap(),
+    }
+}
+
+fn activity_token_events(
+    logs: Vec<Log>,
+    timestamps: &BlockTimestamps,
+) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
+    let mut events: Vec<EthereumEvent> = Vec::<EthereumEvent>::new();
+
+    for log in logs {
+        let transfer = ITokenActivity::Transfer::decode_log_data(log.data())?;
+        let event = if transfer.from == Address::ZERO {
+            EthereumEvent::new_activity_token_minted_from_transfer_data(
+                location(&log, timestamps),
+                transfer.to,
+                transfer.value,
+            )
+        } else {
+            EthereumEvent::new_activity_token_transferred(
+                location(&log, timestamps),
+                transfer.from,
+                transfer.to,
+                transfer.value,
+            )
+        };
+        events.push(event);
+    }
+
+    Ok(events)
+}
+
+fn badge_registry_events(
+    logs: Vec<Log>,
+    timestamps: &BlockTimestamps,
+) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
+    logs.iter()
+        .map(|log| {
+            let created = ITheGuildBadgeRegistry::BadgeCreated::decode_log_data(log.data())?;
+            Ok(EthereumEvent::new_badge_created(
+                location(log, timestamps),
+                bytes32_to_string(created.name),
+                String::from_utf8_lossy(&created.description).into_owned(),
+                created.creator,
+            ))
+        })
+        .collect()
+}
+
+fn badge_ranking_events(
+    logs: Vec<Log>,
+    timestamps: &BlockTimestamps,
+) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
+    logs.iter()
+        .map(|log| {
+            let upvoted = ITheGuildBadgeRanking::BadgeUpvoted::decode_log_data(log.data())?;
+            Ok(EthereumEvent::new_badge_upvoted(
+                location(log, timestamps),
+                bytes32_to_string(upvoted.badgeName),
+                upvoted.voter,
+            ))
+        })
+        .collect()
+}
+
+/// Finds the reason hashing to `reason_hash` in a `mintWithReason` or
+/// `batchMintWithReason` call. None when the mint went through another
+/// contract, e.g. a multisig, whose calldata is not a direct token call.
+pub fn mint_reason_from_calldata(input: &[u8], reason_hash: B256) -> Option<Bytes> {
+    let reasons =
+        if let Ok(call) = ITheGuildContributionToken::mintWithReasonCall::abi_decode(input) {
+            vec![call.reason]
+        } else {
+            ITheGuildContributionToken::batchMintWithReasonCall::abi_decode(input)
+                .ok()?
+                .reasons
+        };
+    reasons
+        .into_iter()
+        .find(|reason| keccak256(reason) == reason_hash)
+}
+
+/// Decodes the guild schema's `(bytes32 badgeName, bytes justification)`
+/// attestation data.
+pub fn decode_badge_attestation_data(data: &[u8]) -> Option<(String, String)> {
+    let (badge_name, justification) = <(B256, Bytes)>::abi_decode_params(data).ok()?;
+    Some((
+        bytes32_to_string(badge_name),
+        String::from_utf8_lossy(&justification).into_owned(),
+    ))
+}
+
 #[async_trait]
 impl EthereumEventPollingService for AlloyEthereumEventPollingService {
-    async fn poll(&self) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
-        let rpc_url = "https://reth-ethereum.ithaca.xyz/rpc".parse()?;
-        let provider = ProviderBuilder::new().connect_http(rpc_url);
+    async fn chain_id(&self) -> Result<u64, Box<dyn Error>> {
+        Ok(self.provider().get_chain_id().await?)
+    }
 
-        // Get logs from the latest block
-        let latest_block = provider.get_block_number().await?;
+    async fn latest_block(&self) -> Result<u64, Box<dyn Error>> {
+        Ok(self.provider().get_block_number().await?)
+    }
 
-        // Create a filter to get all logs from the latest block.
-        let filter = Filter::new()
-            .from_block(latest_block)
-            .event("Transfer(from,to,value)");
+    async fn block_header(&self, number: u64) -> Result<BlockHeader, Box<dyn Error>> {
+        let block = self
+            .provider()
+            .get_block_by_number(number.into())
+            .await?
+            .ok_or_else(|| format!("Block {number} not found"))?;
+        Ok(BlockHeader {
+            number,
+            hash: block.header.hash,
+            parent_hash: block.header.parent_hash,
+        })
+    }
 
-        // Get all logs from the latest block that match the filter.
-        let logs = provider.get_logs(&filter).await?;
+    async fn poll(
+        &self,
+        source: &EventSource,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
+        let filter = Filter::new()
+            .address(source.address)
+            .from_block(from_block)
+            .to_block(to_block);
 
-        let mut events: Vec<EthereumEvent> = Vec::<EthereumEvent>::new();
+        let filter = match source.kind {
+            EventSourceKind::ActivityToken => {
+                filter.event_signature(ITokenActivity::Transfer::SIGNATURE_HASH)
+            }
+            EventSourceKind::BadgeRegistry => {
+                filter.event_signature(ITheGuildBadgeRegistry::BadgeCreated::SIGNATURE_HASH)
+            }
+            EventSourceKind::Eas => {
+                let schema_id = source.schema_id.ok_or("eas source without schema_id")?;
+                filter
+                    .event_signature(vec![
+                        IEAS::Attested::SIGNATURE_HASH,
+                        IEAS::Revoked::SIGNATURE_HASH,
+                    ])
+                    .topic3(schema_id)
+            }
+            EventSourceKind::BadgeRanking => {
+                filter.event_signature(ITheGuildBadgeRanking::BadgeUpvoted::SIGNATURE_HASH)
+            }
+            EventSourceKind::ContributionToken => filter.event_signature(vec![
+                ITheGuildContributionToken::Transfer::SIGNATURE_HASH,
+                ITheGuildContributionToken::MintedWithReason::SIGNATURE_HASH,
+            ]),
+        };
+        let logs = self.provider().get_logs(&filter).await?;
+        let timestamps = self.block_timestamps(&logs).await?;
 
-        for log in logs {
-            let transfer = ITokenActivity::Transfer::decode_log_data(log.data())?;
-            if transfer.from == Address::ZERO {
-                let tx_hash = log.transaction_hash.unwrap();
-                let log_index = log.log_index.unwrap();
-                let event = EthereumEvent::new_activity_token_minted_from_transfer_data(
-                    tx_hash.to_string(),
-                    log_index.to_string(),
-                    transfer.to,
-                    transfer.value,
-                );
-                events.push(event);
+        match source.kind {
+            EventSourceKind::ActivityToken => activity_token_events(logs, &timestamps),
+            EventSourceKind::BadgeRegistry => badge_registry_events(logs, &timestamps),
+            EventSourceKind::Eas => self.eas_events(source.address, logs, &timestamps).await,
+            EventSourceKind::BadgeRanking => badge_ranking_events(logs, &timestamps),
+            EventSourceKind::ContributionToken => {
+                self.contribution_token_events(logs, &timestamps).await
             }
         }
+    }
 
-        Ok(events)
+    async fn token_balance(
+        &self,
+        token: &Address,
+        holder: &Address,
+        block_number: u64,
+    ) -> Result<U256, Box<dyn Error>> {
+        let provider = self.provider();
+        Ok(IERC20::new(*token, &provider)
+            .balanceOf(*holder)
+            .block(BlockId::number(block_number))
+            .call()
+            .await?)
+    }
+
+    async fn token_total_supply(
+        &self,
+        token: &Address,
+        block_number: u64,
+    ) -> Result<U256, Box<dyn Error>> {
+        let provider = self.provider();
+        Ok(IERC20::new(*token, &provider)
+            .totalSupply()
+            .block(BlockId::number(block_number))
+            .call()
+            .await?)
     }
 }
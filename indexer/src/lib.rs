@@ -0,0 +1,5 @@
+pub mod application;
+pub mod config;
+pub mod domain;
+pub mod infrastructure;
+pub mod presentation;
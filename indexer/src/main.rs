@@ -1,10 +1,18 @@
 pub mod application;
+pub mod config;
 pub mod domain;
 pub mod infrastructure;
 pub mod presentation;
 
-use presentation::api::create_app;
+use config::IndexerConfig;
+use domain::services::ethereum_event_polling_service::EthereumEventPollingService;
+use infrastructure::services::alloy_ethereum_event_polling_service::AlloyEthereumEventPollingService;
+use presentation::{
+    api::{build_app, create_state},
+    poller::run_poller,
+};
 use std::{env, net::SocketAddr};
+use tokio::sync::watch;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 #[tokio::main]
@@ -15,11 +23,23 @@ async fn main() -> anyhow::Result<()> {
     tracing_subscriber::registry()
         .with(
             tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "guild_backend=debug,tower_http=debug".into()),
+                .unwrap_or_else(|_| "guild_indexer=debug,tower_http=debug".into()),
         )
         .with(tracing_subscriber::fmt::layer())
         .init();
 
+    let config = IndexerConfig::load().map_err(anyhow::Error::msg)?;
+    let rpc_chain_id = AlloyEthereumEventPollingService::new(config.rpc_url.clone())
+        .chain_id()
+        .await
+        .map_err(|e| anyhow::anyhow!("Cannot reach {}: {e}", config.rpc_url))?;
+    anyhow::ensure!(
+        rpc_chain_id == config.chain_id,
+        "{} serves chain {rpc_chain_id}, but chain_id is {}",
+        config.rpc_url,
+        config.chain_id
+    );
+
     let pool = sqlx::PgPool::connect(&database_url)
         .await
         .unwrap_or_else(|_| {
@@ -29,14 +49,59 @@ async fn main() -> anyhow::Result<()> {
 
     sqlx::migrate!("./migrations").run(&pool).await?;
 
-    let app = create_app(pool).await;
+    let poll_interval = config.poll_interval;
+    let state = create_state(pool, config);
+
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        tracing::info!("Shutting down");
+        let _ = shutdown_tx.send(true);
+    });
+
+    tracing::info!("Polling every {:?}", poll_interval);
+    let poller = tokio::spawn(run_poller(
+        state.clone(),
+        poll_interval,
+        shutdown_rx.clone(),
+    ));
 
     let port = env::var("PORT").unwrap_or_else(|_| "3002".to_string());
     let addr = SocketAddr::from(([0, 0, 0, 0], port.parse::<u16>().unwrap()));
     tracing::info!("Server listening on {}", addr);
 
     let listener = tokio::net::TcpListener::bind(&addr).await?;
-    axum::serve(listener, app).await?;
+    let mut server_shutdown = shutdown_rx;
+    axum::serve(listener, build_app(state))
+        .with_graceful_shutdown(async move {
+            let _ = server_shutdown.changed().await;
+        })
+        .await?;
+    poller.await?;
 
     Ok(())
 }
+
+/// Resolves on Ctrl-C, or SIGTERM (e.g. `docker stop`) on Unix.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to listen for Ctrl-C");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to listen for SIGTERM")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+}
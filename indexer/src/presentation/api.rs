@@ -1,31 +1,102 @@
+use axum::Router;
 use axum::extract::DefaultBodyLimit;
 use axum::http::Method;
 use axum::routing::{get, post};
-use axum::Router;
 use std::sync::Arc;
+use tokio::sync::Mutex;
 use tower::ServiceBuilder;
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::TraceLayer;
 
+use super::handlers::check_token_balances_handler::check_token_balances_handler;
+use super::handlers::get_attestation_handler::get_attestation_handler;
+use super::handlers::get_badge_handler::get_badge_handler;
+use super::handlers::get_badge_ranking_handler::get_badge_ranking_handler;
+use super::handlers::get_token_holders_handler::get_token_holders_handler;
+use super::handlers::list_account_balances_handler::list_account_balances_handler;
+use super::handlers::list_attestations_handler::list_attestations_handler;
+use super::handlers::list_badges_handler::list_badges_handler;
+use super::handlers::list_contribution_mints_handler::list_contribution_mints_handler;
 use super::handlers::list_events_handler::list_events_handler;
+use super::handlers::list_voter_upvotes_handler::list_voter_upvotes_handler;
 use super::handlers::poll_handler::poll_handler;
+use crate::application::commands::poll::PollConfig;
+use crate::config::IndexerConfig;
+use crate::domain::repositories::attestation_repository::AttestationRepository;
+use crate::domain::repositories::badge_repository::BadgeRepository;
+use crate::domain::repositories::badge_upvote_repository::BadgeUpvoteRepository;
+use crate::domain::repositories::block_cursor_repository::BlockCursorRepository;
+use crate::domain::repositories::contribution_mint_repository::ContributionMintRepository;
 use crate::domain::repositories::ethereum_event_repository::EthereumEventRepository;
+use crate::domain::repositories::event_projection::EventProjection;
+use crate::domain::repositories::token_balance_repository::TokenBalanceRepository;
 use crate::domain::services::ethereum_event_polling_service::EthereumEventPollingService;
+use crate::infrastructure::repositories::postgres_attestation_repository::PostgresAttestationRepository;
+use crate::infrastructure::repositories::postgres_badge_repository::PostgresBadgeRepository;
+use crate::infrastructure::repositories::postgres_badge_upvote_repository::PostgresBadgeUpvoteRepository;
+use crate::infrastructure::repositories::postgres_block_cursor_repository::PostgresBlockCursorRepository;
+use crate::infrastructure::repositories::postgres_contribution_mint_repository::PostgresContributionMintRepository;
 use crate::infrastructure::repositories::postgres_ethereum_event_repository::PostgresEthereumEventRepository;
+use crate::infrastructure::repositories::postgres_token_balance_repository::PostgresTokenBalanceRepository;
 use crate::infrastructure::services::alloy_ethereum_event_polling_service::AlloyEthereumEventPollingService;
 
-pub async fn create_app(pool: sqlx::PgPool) -> Router {
-    let postgres_ethereum_event_repository = PostgresEthereumEventRepository::new(pool);
-    let alloy_ethereum_event_polling_service = AlloyEthereumEventPollingService::new();
+/// Wires the Postgres repositories and the RPC client behind the API and the
+/// background poller.
+pub fn create_state(pool: sqlx::PgPool, config: IndexerConfig) -> AppState {
+    let postgres_ethereum_event_repository = PostgresEthereumEventRepository::new(pool.clone());
+    let postgres_block_cursor_repository = PostgresBlockCursorRepository::new(pool.clone());
+    let postgres_badge_repository = Arc::new(PostgresBadgeRepository::new(pool.clone()));
+    let postgres_attestation_repository =
+        Arc::new(PostgresAttestationRepository::new(pool.clone()));
+    let postgres_badge_upvote_repository =
+        Arc::new(PostgresBadgeUpvoteRepository::new(pool.clone()));
+    let postgres_contribution_mint_repository =
+        Arc::new(PostgresContributionMintRepository::new(pool.clone()));
+    let postgres_token_balance_repository = Arc::new(PostgresTokenBalanceRepository::new(pool));
+    let alloy_ethereum_event_polling_service =
+        AlloyEthereumEventPollingService::new(config.rpc_url);
 
-    let state: AppState = AppState {
+    AppState {
         ethereum_event_repository: Arc::from(postgres_ethereum_event_repository),
         ethereum_event_polling_service: Arc::from(alloy_ethereum_event_polling_service),
-    };
+        block_cursor_repository: Arc::from(postgres_block_cursor_repository),
+        badge_repository: postgres_badge_repository.clone(),
+        attestation_repository: postgres_attestation_repository.clone(),
+        badge_upvote_repository: postgres_badge_upvote_repository.clone(),
+        contribution_mint_repository: postgres_contribution_mint_repository.clone(),
+        token_balance_repository: postgres_token_balance_repository.clone(),
+        projections: vec![
+            postgres_badge_repository,
+            postgres_attestation_repository,
+            postgres_badge_upvote_repository,
+            postgres_contribution_mint_repository,
+            postgres_token_balance_repository,
+        ],
+        poll_config: config.poll,
+        poll_lock: Arc::default(),
+    }
+}
 
+pub fn build_app(state: AppState) -> Router {
     let router = Router::new()
         .route("/poll/", post(poll_handler))
         .route("/events/", get(list_events_handler))
+        .route("/badges", get(list_badges_handler))
+        .route("/badges/{name}", get(get_badge_handler))
+        .route("/attestations", get(list_attestations_handler))
+        .route("/attestations/{uid}", get(get_attestation_handler))
+        .route("/upvotes", get(get_badge_ranking_handler))
+        .route("/voters/{address}/upvotes", get(list_voter_upvotes_handler))
+        .route("/contribution-mints", get(list_contribution_mints_handler))
+        .route("/tokens/{symbol}/holders", get(get_token_holders_handler))
+        .route(
+            "/tokens/{symbol}/consistency",
+            get(check_token_balances_handler),
+        )
+        .route(
+            "/accounts/{address}/balances",
+            get(list_account_balances_handler),
+        )
         .with_state(state.clone());
 
     router.with_state(state.clone()).layer(
@@ -45,4 +116,16 @@ pub async fn create_app(pool: sqlx::PgPool) -> Router {
 pub struct AppState {
     pub ethereum_event_repository: Arc<dyn EthereumEventRepository>,
     pub ethereum_event_polling_service: Arc<dyn EthereumEventPollingService>,
+    pub block_cursor_repository: Arc<dyn BlockCursorRepository>,
+    pub badge_repository: Arc<dyn BadgeRepository>,
+    pub attestation_repository: Arc<dyn AttestationRepository>,
+    pub badge_upvote_repository: Arc<dyn BadgeUpvoteRepository>,
+    pub contribution_mint_repository: Arc<dyn ContributionMintRepository>,
+    pub token_balance_repository: Arc<dyn TokenBalanceRepository>,
+    /// Read models fed by every poll, in addition to the raw event log.
+    pub projections: Vec<Arc<dyn EventProjection>>,
+    pub poll_config: PollConfig,
+    /// Held while a poll runs, so the background poller and `/poll/` never
+    /// index at the same time, and balance checks never see half a poll.
+    pub poll_lock: Arc<Mutex<()>>,
 }
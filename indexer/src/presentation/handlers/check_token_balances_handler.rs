@@ -0,0 +1,39 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::{
+    application::commands::check_token_balances::{BalanceCheckReport, check_token_balances},
+    domain::entities::token_transfer::TokenSymbol,
+    presentation::api::AppState,
+};
+
+/// 404 when the token is not configured as a source or not indexed yet. Waits
+/// for a poll already in flight to finish first.
+pub async fn check_token_balances_handler(
+    State(state): State<AppState>,
+    Path(symbol): Path<TokenSymbol>,
+) -> Result<Json<BalanceCheckReport>, StatusCode> {
+    let source = state
+        .poll_config
+        .sources
+        .iter()
+        .find(|s| s.kind == symbol.source_kind())
+        .ok_or(StatusCode::NOT_FOUND)?;
+    // A poll applies projections before it moves the cursor, so balances read
+    // mid-poll would be ahead of the block the check asks the chain about.
+    let _guard = state.poll_lock.lock().await;
+    check_token_balances(
+        state.ethereum_event_polling_service,
+        state.token_balance_repository,
+        state.block_cursor_repository,
+        symbol,
+        source,
+    )
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    .map(Json)
+    .ok_or(StatusCode::NOT_FOUND)
+}
@@ -0,0 +1,22 @@
+use alloy::primitives::B256;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::{
+    application::queries::get_attestation::get_attestation,
+    domain::entities::attestation::Attestation, presentation::api::AppState,
+};
+
+pub async fn get_attestation_handler(
+    State(state): State<AppState>,
+    Path(uid): Path<B256>,
+) -> Result<Json<Attestation>, StatusCode> {
+    get_attestation(state.attestation_repository, &uid)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map(Json)
+        .ok_or(StatusCode::NOT_FOUND)
+}
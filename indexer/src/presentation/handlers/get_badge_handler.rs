@@ -0,0 +1,21 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::{
+    application::queries::get_badge::get_badge, domain::entities::badge::Badge,
+    presentation::api::AppState,
+};
+
+pub async fn get_badge_handler(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<Json<Badge>, StatusCode> {
+    get_badge(state.badge_repository, &name)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map(Json)
+        .ok_or(StatusCode::NOT_FOUND)
+}
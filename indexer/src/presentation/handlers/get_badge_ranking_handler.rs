@@ -0,0 +1,15 @@
+use axum::{Json, extract::State, http::StatusCode};
+
+use crate::{
+    application::queries::get_badge_ranking::get_badge_ranking,
+    domain::entities::badge_upvote::BadgeVotes, presentation::api::AppState,
+};
+
+pub async fn get_badge_ranking_handler(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<BadgeVotes>>, StatusCode> {
+    get_badge_ranking(state.badge_upvote_repository)
+        .await
+        .map(Json)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
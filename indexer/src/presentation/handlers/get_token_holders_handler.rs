@@ -0,0 +1,21 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::{
+    application::queries::get_token_holders::get_token_holders,
+    domain::entities::{token_balance::TokenHolders, token_transfer::TokenSymbol},
+    presentation::api::AppState,
+};
+
+pub async fn get_token_holders_handler(
+    State(state): State<AppState>,
+    Path(symbol): Path<TokenSymbol>,
+) -> Result<Json<TokenHolders>, StatusCode> {
+    get_token_holders(state.token_balance_repository, symbol)
+        .await
+        .map(Json)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
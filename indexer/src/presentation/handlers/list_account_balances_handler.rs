@@ -0,0 +1,21 @@
+use alloy::primitives::Address;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::{
+    application::queries::list_account_balances::list_account_balances,
+    domain::entities::token_balance::TokenBalance, presentation::api::AppState,
+};
+
+pub async fn list_account_balances_handler(
+    State(state): State<AppState>,
+    Path(address): Path<Address>,
+) -> Result<Json<Vec<TokenBalance>>, StatusCode> {
+    list_account_balances(state.token_balance_repository, &address)
+        .await
+        .map(Json)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
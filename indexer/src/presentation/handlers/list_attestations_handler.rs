@@ -0,0 +1,38 @@
+use alloy::primitives::Address;
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+};
+use serde::Deserialize;
+
+use crate::{
+    application::queries::list_attestations::list_attestations,
+    domain::entities::attestation::{Attestation, AttestationFilter},
+    presentation::api::AppState,
+};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ListAttestationsQuery {
+    pub recipient: Option<Address>,
+    pub attester: Option<Address>,
+    pub badge: Option<String>,
+    #[serde(default)]
+    pub include_revoked: bool,
+}
+
+pub async fn list_attestations_handler(
+    State(state): State<AppState>,
+    Query(query): Query<ListAttestationsQuery>,
+) -> Result<Json<Vec<Attestation>>, StatusCode> {
+    let filter = AttestationFilter {
+        recipient: query.recipient,
+        attester: query.attester,
+        badge_name: query.badge,
+        include_revoked: query.include_revoked,
+    };
+    list_attestations(state.attestation_repository, filter)
+        .await
+        .map(Json)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
@@ -0,0 +1,15 @@
+use axum::{Json, extract::State, http::StatusCode};
+
+use crate::{
+    application::queries::list_badges::list_badges, domain::entities::badge::Badge,
+    presentation::api::AppState,
+};
+
+pub async fn list_badges_handler(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<Badge>>, StatusCode> {
+    list_badges(state.badge_repository)
+        .await
+        .map(Json)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
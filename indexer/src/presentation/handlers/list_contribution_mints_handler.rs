@@ -0,0 +1,33 @@
+use alloy::primitives::{Address, B256};
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+};
+use serde::Deserialize;
+
+use crate::{
+    application::queries::list_contribution_mints::list_contribution_mints,
+    domain::entities::contribution_mint::{ContributionMint, ContributionMintFilter},
+    presentation::api::AppState,
+};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ListContributionMintsQuery {
+    pub recipient: Option<Address>,
+    pub tx_hash: Option<B256>,
+}
+
+pub async fn list_contribution_mints_handler(
+    State(state): State<AppState>,
+    Query(query): Query<ListContributionMintsQuery>,
+) -> Result<Json<Vec<ContributionMint>>, StatusCode> {
+    let filter = ContributionMintFilter {
+        recipient: query.recipient,
+        tx_hash: query.tx_hash,
+    };
+    list_contribution_mints(state.contribution_mint_repository, filter)
+        .await
+        .map(Json)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
@@ -0,0 +1,15 @@
+use axum::{Json, extract::State, http::StatusCode};
+
+use crate::{
+    application::queries::list_events::list_events,
+    domain::entities::ethereum_event::EthereumEvent, presentation::api::AppState,
+};
+
+pub async fn list_events_handler(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<EthereumEvent>>, StatusCode> {
+    list_events(state.ethereum_event_repository)
+        .await
+        .map(Json)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
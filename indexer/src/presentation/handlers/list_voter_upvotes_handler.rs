@@ -0,0 +1,21 @@
+use alloy::primitives::Address;
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::{
+    application::queries::list_voter_upvotes::list_voter_upvotes,
+    domain::entities::badge_upvote::BadgeUpvote, presentation::api::AppState,
+};
+
+pub async fn list_voter_upvotes_handler(
+    State(state): State<AppState>,
+    Path(voter): Path<Address>,
+) -> Result<Json<Vec<BadgeUpvote>>, StatusCode> {
+    list_voter_upvotes(state.badge_upvote_repository, &voter)
+        .await
+        .map(Json)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
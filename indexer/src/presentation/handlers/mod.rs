@@ -1,2 +1,12 @@
+pub mod check_token_balances_handler;
+pub mod get_attestation_handler;
+pub mod get_badge_handler;
+pub mod get_badge_ranking_handler;
+pub mod get_token_holders_handler;
+pub mod list_account_balances_handler;
+pub mod list_attestations_handler;
+pub mod list_badges_handler;
+pub mod list_contribution_mints_handler;
 pub mod list_events_handler;
+pub mod list_voter_upvotes_handler;
 pub mod poll_handler;
@@ -1,14 +1,16 @@
 use axum::{extract::State, http::StatusCode};
 
-use crate::{application::commands::poll::poll, presentation::api::AppState};
+use crate::presentation::{api::AppState, poller::poll_once};
 
+/// Polls right away, on top of the background poller. Waits for a poll
+/// already in flight to finish first.
 #[axum::debug_handler]
 pub async fn poll_handler(State(state): State<AppState>) -> StatusCode {
-    poll(
-        state.ethereum_event_polling_service,
-        state.ethereum_event_repository,
-    )
-    .await
-    .unwrap();
-    StatusCode::CREATED
+    match poll_once(&state).await {
+        Ok(()) => StatusCode::CREATED,
+        Err(error) => {
+            tracing::warn!(%error, "manual poll failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
 }
@@ -0,0 +1,58 @@
+use std::{error::Error, time::Duration};
+
+use tokio::sync::watch;
+
+use crate::{application::commands::poll::poll, presentation::api::AppState};
+
+/// Longest pause between two polls while they keep failing.
+pub const MAX_POLL_BACKOFF: Duration = Duration::from_secs(300);
+
+/// Pause before the next poll: `interval` after a success, doubled for each
+/// consecutive failure, up to `MAX_POLL_BACKOFF`.
+pub fn poll_delay(interval: Duration, failures: u32) -> Duration {
+    interval
+        .saturating_mul(2u32.saturating_pow(failures))
+        .min(MAX_POLL_BACKOFF.max(interval))
+}
+
+/// Runs the poll command under the state's poll lock.
+pub async fn poll_once(state: &AppState) -> Result<(), Box<dyn Error>> {
+    let _guard = state.poll_lock.lock().await;
+    poll(
+        state.ethereum_event_polling_service.clone(),
+        state.ethereum_event_repository.clone(),
+        state.block_cursor_repository.clone(),
+        &state.projections,
+        &state.poll_config,
+    )
+    .await
+}
+
+/// Polls every `interval` until `shutdown` turns true. A poll still running
+/// then is dropped; its chunk had not been committed to the cursor, so it is
+/// replayed on the next start.
+pub async fn run_poller(state: AppState, interval: Duration, mut shutdown: watch::Receiver<bool>) {
+    let mut failures: u32 = 0;
+    loop {
+        tokio::select! {
+            result = poll_once(&state) => match result {
+                Ok(()) => failures = 0,
+                Err(error) => {
+                    failures = failures.saturating_add(1);
+                    tracing::warn!(
+                        %error,
+                        failures,
+                        retry_in = ?poll_delay(interval, failures),
+                        "poll failed, backing off"
+                    );
+                }
+            },
+            _ = shutdown.changed() => break,
+        }
+        tokio::select! {
+            _ = tokio::time::sleep(poll_delay(interval, failures)) => {}
+            _ = shutdown.changed() => break,
+        }
+    }
+    tracing::info!("poller stopped");
+}
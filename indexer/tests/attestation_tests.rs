@@ -0,0 +1,193 @@
+mod common;
+
+#[cfg(test)]
+mod attestation_tests {
+    use crate::common::{app_state, block_timestamp, get, location};
+    use std::sync::Arc;
+
+    use alloy::primitives::{Address, B256, Bytes, U256, address};
+    use alloy::sol_types::SolValue;
+    use axum::http::StatusCode;
+
+    use indexer::domain::entities::attestation::AttestationFilter;
+    use indexer::domain::entities::ethereum_event::EthereumEvent;
+    use indexer::domain::repositories::attestation_repository::AttestationRepository;
+    use indexer::domain::repositories::event_projection::EventProjection;
+    use indexer::infrastructure::repositories::in_memory_attestation_repository::InMemoryAttestationRepository;
+    use indexer::infrastructure::services::alloy_ethereum_event_polling_service::{
+        bytes32_to_string, decode_badge_attestation_data,
+    };
+    use indexer::presentation::api::{AppState, build_app};
+
+    const EAS: Address = address!("0x4444444444444444444444444444444444444444");
+    const ALICE: Address = address!("0x5555555555555555555555555555555555555555");
+    const BOB: Address = address!("0x6666666666666666666666666666666666666666");
+    const CAROL: Address = address!("0x7777777777777777777777777777777777777777");
+
+    fn uid(n: u64) -> B256 {
+        B256::from(U256::from(n))
+    }
+
+    fn attested(block_number: u64, n: u64, attester: Address, badge: &str) -> EthereumEvent {
+        EthereumEvent::new_attestation_created(
+            location(EAS, block_number, n),
+            uid(n),
+            BOB,
+            attester,
+            badge.to_string(),
+            format!("{badge} justification"),
+        )
+    }
+
+    fn revoked(block_number: u64, n: u64, attester: Address) -> EthereumEvent {
+        EthereumEvent::new_attestation_revoked(
+            location(EAS, block_number, 100 + n),
+            uid(n),
+            BOB,
+            attester,
+        )
+    }
+
+    fn uids(attestations: &[indexer::domain::entities::attestation::Attestation]) -> Vec<B256> {
+        attestations.iter().map(|a| a.uid).collect()
+    }
+
+    fn app(attestations: InMemoryAttestationRepository) -> axum::Router {
+        let attestations = Arc::new(attestations);
+        build_app(AppState {
+            attestation_repository: attestations.clone(),
+            projections: vec![attestations],
+            ..app_state()
+        })
+    }
+
+    // ========================================================================
+    // Tests
+    // ========================================================================
+
+    #[test]
+    fn decodes_guild_schema_data() {
+        let mut name = [0u8; 32];
+        name[..4].copy_from_slice(b"Rust");
+        let data = (
+            B256::from(name),
+            Bytes::from("Shipped the indexer".as_bytes()),
+        )
+            .abi_encode_params();
+
+        assert_eq!(
+            decode_badge_attestation_data(&data),
+            Some(("Rust".to_string(), "Shipped the indexer".to_string()))
+        );
+        assert_eq!(bytes32_to_string(B256::ZERO), "");
+        assert_eq!(decode_badge_attestation_data(b"not abi"), None);
+    }
+
+    #[tokio::test]
+    async fn projection_tracks_revocations_and_filters() {
+        let attestations = InMemoryAttestationRepository::new();
+        let events = vec![
+            attested(10, 1, ALICE, "Rust"),
+            attested(11, 2, CAROL, "Rust"),
+            attested(12, 3, ALICE, "Solidity"),
+            revoked(12, 2, CAROL),
+        ];
+        attestations.apply(&events).await.unwrap();
+        attestations.apply(&events).await.unwrap();
+
+        let active = attestations
+            .list(&AttestationFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(uids(&active), vec![uid(1), uid(3)]);
+
+        let by_alice = AttestationFilter {
+            attester: Some(ALICE),
+            badge_name: Some("Rust".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            uids(&attestations.list(&by_alice).await.unwrap()),
+            vec![uid(1)]
+        );
+
+        let with_revoked = AttestationFilter {
+            recipient: Some(BOB),
+            include_revoked: true,
+            ..Default::default()
+        };
+        let all = attestations.list(&with_revoked).await.unwrap();
+        assert_eq!(uids(&all), vec![uid(1), uid(2), uid(3)]);
+        assert_eq!(all[1].revoked_block, Some(12));
+        assert_eq!(all[1].created_at, block_timestamp(11));
+        assert_eq!(all[1].revoked_at, Some(block_timestamp(12)));
+
+        let for_alice = AttestationFilter {
+            recipient: Some(ALICE),
+            ..Default::default()
+        };
+        assert!(attestations.list(&for_alice).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn rollback_drops_attestations_and_reverts_revocations() {
+        let attestations = InMemoryAttestationRepository::new();
+        attestations
+            .apply(&[
+                attested(10, 1, ALICE, "Rust"),
+                attested(20, 2, ALICE, "Solidity"),
+                revoked(20, 1, ALICE),
+            ])
+            .await
+            .unwrap();
+
+        attestations.rollback(&EAS, 15).await.unwrap();
+
+        let all = attestations
+            .list(&AttestationFilter {
+                include_revoked: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(uids(&all), vec![uid(1)]);
+        assert!(!all[0].is_revoked());
+    }
+
+    #[tokio::test]
+    async fn lists_and_gets_attestations_over_http() {
+        let attestations = InMemoryAttestationRepository::new();
+        attestations
+            .apply(&[
+                attested(10, 1, ALICE, "Rust"),
+                attested(11, 2, CAROL, "Smart Contracts"),
+            ])
+            .await
+            .unwrap();
+
+        let (status, body) = get(
+            app(attestations.clone()),
+            &format!("/attestations?attester={CAROL}&badge=Smart%20Contracts"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let listed = body.as_array().unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0]["badge_name"], "Smart Contracts");
+        assert_eq!(listed[0]["uid"], uid(2).to_string());
+
+        let (status, _) = get(app(attestations.clone()), "/attestations?recipient=0x12").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, body) = get(
+            app(attestations.clone()),
+            &format!("/attestations/{}", uid(1)),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["justification"], "Rust justification");
+
+        let (status, _) = get(app(attestations), &format!("/attestations/{}", uid(9))).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,123 @@
+mod common;
+
+#[cfg(test)]
+mod badge_tests {
+    use crate::common::{app_state, block_timestamp, get, location};
+    use std::sync::Arc;
+
+    use alloy::primitives::{Address, U256, address};
+    use axum::http::StatusCode;
+
+    use indexer::domain::entities::badge::Badge;
+    use indexer::domain::entities::ethereum_event::EthereumEvent;
+    use indexer::domain::repositories::badge_repository::BadgeRepository;
+    use indexer::domain::repositories::event_projection::EventProjection;
+    use indexer::infrastructure::repositories::in_memory_badge_repository::InMemoryBadgeRepository;
+    use indexer::presentation::api::{AppState, build_app};
+
+    const REGISTRY: Address = address!("0x4444444444444444444444444444444444444444");
+    const CREATOR: Address = address!("0x5555555555555555555555555555555555555555");
+
+    fn badge_created(block_number: u64, name: &str) -> EthereumEvent {
+        EthereumEvent::new_badge_created(
+            location(REGISTRY, block_number, 0),
+            name.to_string(),
+            format!("{name} description"),
+            CREATOR,
+        )
+    }
+
+    fn app(badges: InMemoryBadgeRepository) -> axum::Router {
+        let badges = Arc::new(badges);
+        build_app(AppState {
+            badge_repository: badges.clone(),
+            projections: vec![badges],
+            ..app_state()
+        })
+    }
+
+    // ========================================================================
+    // Tests
+    // ========================================================================
+
+    #[test]
+    fn badge_is_read_from_badge_created_events_only() {
+        let badge = Badge::from_event(&badge_created(7, "Rust")).unwrap();
+        assert_eq!(badge.name, "Rust");
+        assert_eq!(badge.description, "Rust description");
+        assert_eq!(badge.creator, CREATOR);
+        assert_eq!(badge.registry_address, REGISTRY);
+        assert_eq!(badge.block_number, 7);
+        assert_eq!(badge.created_at, block_timestamp(7));
+
+        let mint = EthereumEvent::new_activity_token_minted_from_transfer_data(
+            location(REGISTRY, 7, 1),
+            CREATOR,
+            U256::from(1),
+        );
+        assert!(Badge::from_event(&mint).is_none());
+    }
+
+    #[tokio::test]
+    async fn projection_is_idempotent_and_rolls_back() {
+        let badges = InMemoryBadgeRepository::new();
+        let events = vec![badge_created(10, "Rust"), badge_created(20, "Solidity")];
+
+        badges.apply(&events).await.unwrap();
+        badges.apply(&events).await.unwrap();
+        let names: Vec<String> = badges
+            .list()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|b| b.name)
+            .collect();
+        assert_eq!(names, vec!["Rust", "Solidity"]);
+
+        // Another contract's reorg does not touch the registry's badges.
+        badges.rollback(&CREATOR, 0).await.unwrap();
+        assert_eq!(badges.list().await.unwrap().len(), 2);
+
+        badges.rollback(&REGISTRY, 20).await.unwrap();
+        assert!(badges.find_by_name("Solidity").await.unwrap().is_none());
+        assert!(badges.find_by_name("Rust").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn lists_badges() {
+        let badges = InMemoryBadgeRepository::new();
+        badges
+            .apply(&[badge_created(20, "Solidity"), badge_created(10, "Rust")])
+            .await
+            .unwrap();
+
+        let (status, body) = get(app(badges), "/badges").await;
+
+        assert_eq!(status, StatusCode::OK);
+        let names: Vec<&str> = body
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|b| b["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["Rust", "Solidity"]);
+    }
+
+    #[tokio::test]
+    async fn gets_badge_by_name() {
+        let badges = InMemoryBadgeRepository::new();
+        badges
+            .apply(&[badge_created(10, "Smart Contracts")])
+            .await
+            .unwrap();
+
+        let (status, body) = get(app(badges.clone()), "/badges/Smart%20Contracts").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["name"], "Smart Contracts");
+        assert_eq!(body["description"], "Smart Contracts description");
+        assert_eq!(body["creator"], CREATOR.to_string().to_lowercase());
+
+        let (status, _) = get(app(badges), "/badges/Unknown").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
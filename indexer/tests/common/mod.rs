@@ -0,0 +1,130 @@
+//! Fixtures shared by the integration tests.
+
+// Each test crate uses a different subset of these.
+#![allow(dead_code)]
+
+use std::error::Error;
+use std::sync::Arc;
+
+use alloy::primitives::{Address, B256, U256, keccak256};
+use async_trait::async_trait;
+use axum::body::{Body, to_bytes};
+use axum::http::{Request, StatusCode};
+use chrono::{DateTime, Utc};
+use tower::ServiceExt;
+
+use indexer::application::commands::poll::PollConfig;
+use indexer::domain::entities::block_header::BlockHeader;
+use indexer::domain::entities::ethereum_event::{EthereumEvent, LogLocation};
+use indexer::domain::entities::event_source::EventSource;
+use indexer::domain::services::ethereum_event_polling_service::EthereumEventPollingService;
+use indexer::infrastructure::repositories::in_memory_attestation_repository::InMemoryAttestationRepository;
+use indexer::infrastructure::repositories::in_memory_badge_repository::InMemoryBadgeRepository;
+use indexer::infrastructure::repositories::in_memory_badge_upvote_repository::InMemoryBadgeUpvoteRepository;
+use indexer::infrastructure::repositories::in_memory_block_cursor_repository::InMemoryBlockCursorRepository;
+use indexer::infrastructure::repositories::in_memory_contribution_mint_repository::InMemoryContributionMintRepository;
+use indexer::infrastructure::repositories::in_memory_ethereum_event_repository::InMemoryEthereumEventRepository;
+use indexer::infrastructure::repositories::in_memory_token_balance_repository::InMemoryTokenBalanceRepository;
+use indexer::presentation::api::AppState;
+
+/// Timestamp of `block_number` on the scripted test chains: 12 second blocks
+/// from a fixed genesis, so projections can be checked against it.
+pub fn block_timestamp(block_number: u64) -> DateTime<Utc> {
+    DateTime::from_timestamp(1_700_000_000 + 12 * block_number as i64, 0).unwrap()
+}
+
+/// Where a log of `contract` sits on the scripted test chains. Block hashes
+/// follow the block number; tx hashes are unique to the contract and block,
+/// so tests sharing a database do not overwrite each other's events.
+pub fn location(contract: Address, block_number: u64, log_index: u64) -> LogLocation {
+    LogLocation {
+        contract_address: contract,
+        block_number,
+        block_hash: B256::from(U256::from(block_number)),
+        block_timestamp: block_timestamp(block_number),
+        tx_hash: keccak256([contract.as_slice(), &block_number.to_be_bytes()].concat()),
+        log_index,
+    }
+}
+
+/// A chain whose RPC is unreachable, for apps that only serve what is indexed.
+pub struct NoChain;
+
+fn unreachable<T>() -> Result<T, Box<dyn Error>> {
+    Err("no chain behind this test app".into())
+}
+
+#[async_trait]
+impl EthereumEventPollingService for NoChain {
+    async fn chain_id(&self) -> Result<u64, Box<dyn Error>> {
+        unreachable()
+    }
+
+    async fn latest_block(&self) -> Result<u64, Box<dyn Error>> {
+        unreachable()
+    }
+
+    async fn block_header(&self, _number: u64) -> Result<BlockHeader, Box<dyn Error>> {
+        unreachable()
+    }
+
+    async fn poll(
+        &self,
+        _source: &EventSource,
+        _from_block: u64,
+        _to_block: u64,
+    ) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
+        unreachable()
+    }
+
+    async fn token_balance(
+        &self,
+        _token: &Address,
+        _holder: &Address,
+        _block_number: u64,
+    ) -> Result<U256, Box<dyn Error>> {
+        unreachable()
+    }
+
+    async fn token_total_supply(
+        &self,
+        _token: &Address,
+        _block_number: u64,
+    ) -> Result<U256, Box<dyn Error>> {
+        unreachable()
+    }
+}
+
+/// Empty in-memory repositories behind `NoChain`, with nothing to poll.
+/// Tests override the fields they exercise.
+pub fn app_state() -> AppState {
+    AppState {
+        ethereum_event_repository: Arc::new(InMemoryEthereumEventRepository::new()),
+        ethereum_event_polling_service: Arc::new(NoChain),
+        block_cursor_repository: Arc::new(InMemoryBlockCursorRepository::new()),
+        badge_repository: Arc::new(InMemoryBadgeRepository::new()),
+        attestation_repository: Arc::new(InMemoryAttestationRepository::new()),
+        badge_upvote_repository: Arc::new(InMemoryBadgeUpvoteRepository::new()),
+        contribution_mint_repository: Arc::new(InMemoryContributionMintRepository::new()),
+        token_balance_repository: Arc::new(InMemoryTokenBalanceRepository::new()),
+        projections: vec![],
+        poll_config: PollConfig {
+            sources: vec![],
+            chunk_size: 1,
+            confirmations: 1,
+        },
+        poll_lock: Default::default(),
+    }
+}
+
+/// GETs `uri`, returning the status and the JSON body (Null when empty).
+pub async fn get(app: axum::Router, uri: &str) -> (StatusCode, serde_json::Value) {
+    let response = app
+        .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let json = serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null);
+    (status, json)
+}
@@ -0,0 +1,176 @@
+#[cfg(test)]
+mod config_tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use alloy::primitives::address;
+
+    use indexer::application::commands::poll::{DEFAULT_CHUNK_SIZE, DEFAULT_CONFIRMATIONS};
+    use indexer::config::IndexerConfig;
+    use indexer::domain::entities::event_source::{EventSource, EventSourceKind};
+
+    const FILE: &str = r#"
+        chain_id = 80002
+        rpc_url = "https://rpc-amoy.polygon.technology"
+        chunk_size = 500
+        confirmations = 6
+
+        [[sources]]
+        kind = "activity_token"
+        address = "0x5db978bc69e54250f577ed343273508baea136cd"
+        start_block = 1000
+    "#;
+
+    fn parse(file: Option<&str>, vars: &[(&str, &str)]) -> Result<IndexerConfig, String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        IndexerConfig::parse(file, |name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn reads_config_file() {
+        let config = parse(Some(FILE), &[]).unwrap();
+
+        assert_eq!(config.chain_id, 80002);
+        assert_eq!(
+            config.rpc_url.as_str(),
+            "https://rpc-amoy.polygon.technology/"
+        );
+        assert_eq!(config.poll.chunk_size, 500);
+        assert_eq!(config.poll.confirmations, 6);
+        // Amoy's block time
+        assert_eq!(config.poll_interval, Duration::from_secs(2));
+        assert_eq!(
+            config.poll.sources,
+            vec![EventSource {
+                kind: EventSourceKind::ActivityToken,
+                address: address!("0x5db978bc69e54250f577ed343273508baea136cd"),
+                start_block: 1000,
+                schema_id: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn environment_overrides_file() {
+        let config = parse(
+            Some(FILE),
+            &[
+                ("RPC_URL", "http://localhost:8545"),
+                ("ACTIVITY_TOKEN_START_BLOCK", "2000"),
+                ("POLL_INTERVAL_MS", "500"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(config.rpc_url.as_str(), "http://localhost:8545/");
+        assert_eq!(config.poll.sources[0].start_block, 2000);
+        assert_eq!(config.poll_interval, Duration::from_millis(500));
+        assert_eq!(
+            config.poll.sources[0].address,
+            address!("0x5db978bc69e54250f577ed343273508baea136cd")
+        );
+    }
+
+    #[test]
+    fn environment_alone_is_enough() {
+        let config = parse(
+            None,
+            &[
+                ("CHAIN_ID", "31337"),
+                ("RPC_URL", "http://localhost:8545"),
+                (
+                    "ACTIVITY_TOKEN_ADDRESS",
+                    "0x1111111111111111111111111111111111111111",
+                ),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(config.chain_id, 31337);
+        assert_eq!(config.poll.chunk_size, DEFAULT_CHUNK_SIZE);
+        assert_eq!(config.poll.confirmations, DEFAULT_CONFIRMATIONS);
+        assert_eq!(config.poll_interval, Duration::from_secs(1));
+        assert_eq!(config.poll.sources.len(), 1);
+        assert_eq!(config.poll.sources[0].start_block, 0);
+    }
+
+    #[test]
+    fn eas_source_takes_schema_id() {
+        let config = parse(
+            Some(FILE),
+            &[
+                ("EAS_ADDRESS", "0xb101275a60d8bfb14529C421899aD7CA1Ae5B5Fc"),
+                (
+                    "EAS_SCHEMA_ID",
+                    "0xb167f07504166f717f2a2710dbcfbfdf8fad6e8c6128c1a7fa80768f61b1d0b2",
+                ),
+            ],
+        )
+        .unwrap();
+
+        let eas = &config.poll.sources[1];
+        assert_eq!(eas.kind, EventSourceKind::Eas);
+        assert_eq!(
+            eas.schema_id.unwrap().to_string(),
+            "0xb167f07504166f717f2a2710dbcfbfdf8fad6e8c6128c1a7fa80768f61b1d0b2"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_configs() {
+        let duplicate_kind = format!(
+            "{FILE}\n[[sources]]\nkind = \"activity_token\"\naddress = \"0x1111111111111111111111111111111111111111\""
+        );
+        let unknown_key = format!("{FILE}\nunknown = 1");
+        let cases = vec![
+            (None, vec![], "chain_id"),
+            (Some(FILE), vec![("CHAIN_ID", "0")], "chain_id"),
+            (Some(FILE), vec![("CHAIN_ID", "polygon")], "CHAIN_ID"),
+            (Some(FILE), vec![("RPC_URL", "not a url")], "rpc_url"),
+            (Some(FILE), vec![("RPC_URL", "ws://localhost:8546")], "http"),
+            (Some(FILE), vec![("POLL_CHUNK_SIZE", "0")], "chunk_size"),
+            (Some(FILE), vec![("CONFIRMATIONS", "0")], "confirmations"),
+            (
+                Some(FILE),
+                vec![("POLL_INTERVAL_MS", "0")],
+                "poll_interval_ms",
+            ),
+            (
+                Some(FILE),
+                vec![("ACTIVITY_TOKEN_ADDRESS", "0x123")],
+                "ACTIVITY_TOKEN_ADDRESS",
+            ),
+            (
+                Some(FILE),
+                vec![(
+                    "ACTIVITY_TOKEN_ADDRESS",
+                    "0x0000000000000000000000000000000000000000",
+                )],
+                "zero address",
+            ),
+            (
+                Some("chain_id = 1\nrpc_url = \"http://localhost:8545\""),
+                vec![],
+                "At least one event source",
+            ),
+            (
+                Some("chain_id = 1\nrpc_url = \"http://localhost:8545\""),
+                vec![("ACTIVITY_TOKEN_START_BLOCK", "5")],
+                "without ACTIVITY_TOKEN_ADDRESS",
+            ),
+            (Some(duplicate_kind.as_str()), vec![], "more than once"),
+            (Some(unknown_key.as_str()), vec![], "Invalid indexer config"),
+        ];
+
+        for (file, vars, expected) in cases {
+            let error = parse(file, &vars).unwrap_err();
+            assert!(
+                error.contains(expected),
+                "expected {expected:?} in {error:?} for {vars:?}"
+            );
+        }
+    }
+}
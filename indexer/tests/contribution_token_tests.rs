@@ -0,0 +1,194 @@
+mod common;
+
+#[cfg(test)]
+mod contribution_token_tests {
+    use crate::common::{app_state, block_timestamp, get, location};
+    use std::sync::Arc;
+
+    use alloy::primitives::{Address, Bytes, U256, address, keccak256};
+    use alloy::sol;
+    use alloy::sol_types::SolCall;
+    use axum::http::StatusCode;
+
+    use indexer::domain::entities::contribution_mint::{ContributionMint, ContributionMintFilter};
+    use indexer::domain::entities::ethereum_event::EthereumEvent;
+    use indexer::domain::repositories::contribution_mint_repository::ContributionMintRepository;
+    use indexer::domain::repositories::event_projection::EventProjection;
+    use indexer::infrastructure::repositories::in_memory_contribution_mint_repository::InMemoryContributionMintRepository;
+    use indexer::infrastructure::services::alloy_ethereum_event_polling_service::mint_reason_from_calldata;
+    use indexer::presentation::api::{AppState, build_app};
+
+    const TGC: Address = address!("0x9999999999999999999999999999999999999999");
+    const ALICE: Address = address!("0x7777777777777777777777777777777777777777");
+    const BOB: Address = address!("0x8888888888888888888888888888888888888888");
+
+    sol! {
+        function mintWithReason(address to, uint256 amount, bytes reason) external;
+        function batchMintWithReason(address[] recipients, uint256[] amounts, bytes[] reasons) external;
+    }
+
+    fn minted(
+        block_number: u64,
+        log_index: u64,
+        recipient: Address,
+        reason: &str,
+    ) -> EthereumEvent {
+        EthereumEvent::new_contribution_token_minted(
+            location(TGC, block_number, log_index),
+            recipient,
+            U256::from(100),
+            keccak256(reason),
+            Some(Bytes::copy_from_slice(reason.as_bytes())),
+        )
+    }
+
+    fn app(mints: InMemoryContributionMintRepository) -> axum::Router {
+        let mints = Arc::new(mints);
+        build_app(AppState {
+            contribution_mint_repository: mints.clone(),
+            projections: vec![mints],
+            ..app_state()
+        })
+    }
+
+    // ========================================================================
+    // Tests
+    // ========================================================================
+
+    #[test]
+    fn mint_is_read_from_minted_with_reason_events_only() {
+        let mint = ContributionMint::from_event(&minted(7, 3, ALICE, "issue-123")).unwrap();
+        assert_eq!(mint.tx_hash, location(TGC, 7, 3).tx_hash);
+        assert_eq!(mint.recipient, ALICE);
+        assert_eq!(mint.amount, U256::from(100));
+        assert_eq!(mint.reason_hash, keccak256("issue-123"));
+        assert_eq!(mint.minted_at, block_timestamp(7));
+        assert_eq!(mint.reason.unwrap().as_ref(), b"issue-123");
+        assert_eq!(mint.token_address, TGC);
+
+        let transfer = EthereumEvent::new_contribution_token_transferred(
+            location(TGC, 7, 2),
+            Address::ZERO,
+            ALICE,
+            U256::from(100),
+        );
+        assert!(ContributionMint::from_event(&transfer).is_none());
+    }
+
+    #[test]
+    fn reason_is_recovered_from_mint_calldata() {
+        let single = mintWithReasonCall {
+            to: ALICE,
+            amount: U256::from(1),
+            reason: Bytes::from_static(b"issue-1"),
+        }
+        .abi_encode();
+        assert_eq!(
+            mint_reason_from_calldata(&single, keccak256("issue-1")),
+            Some(Bytes::from_static(b"issue-1"))
+        );
+        assert_eq!(
+            mint_reason_from_calldata(&single, keccak256("issue-2")),
+            None
+        );
+
+        let batch = batchMintWithReasonCall {
+            recipients: vec![ALICE, BOB],
+            amounts: vec![U256::from(1), U256::from(2)],
+            reasons: vec![
+                Bytes::from_static(b"issue-1"),
+                Bytes::from_static(b"issue-2"),
+            ],
+        }
+        .abi_encode();
+        assert_eq!(
+            mint_reason_from_calldata(&batch, keccak256("issue-2")),
+            Some(Bytes::from_static(b"issue-2"))
+        );
+
+        // A multisig execution wrapping the mint is not decoded.
+        assert_eq!(
+            mint_reason_from_calldata(&[0xde, 0xad, 0xbe, 0xef], keccak256("issue-1")),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn projection_is_idempotent_and_rolls_back() {
+        let mints = InMemoryContributionMintRepository::new();
+        let events = vec![
+            minted(10, 1, ALICE, "issue-1"),
+            minted(10, 3, BOB, "issue-2"),
+            minted(20, 1, ALICE, "issue-3"),
+        ];
+
+        mints.apply(&events).await.unwrap();
+        mints.apply(&events).await.unwrap();
+        let all = ContributionMintFilter::default();
+        assert_eq!(mints.list(&all).await.unwrap().len(), 3);
+
+        // Another contract's reorg does not touch the token's mints.
+        mints.rollback(&ALICE, 0).await.unwrap();
+        assert_eq!(mints.list(&all).await.unwrap().len(), 3);
+
+        mints.rollback(&TGC, 20).await.unwrap();
+        let reasons: Vec<Bytes> = mints
+            .list(&all)
+            .await
+            .unwrap()
+            .into_iter()
+            .filter_map(|m| m.reason)
+            .collect();
+        assert_eq!(
+            reasons,
+            vec![
+                Bytes::from_static(b"issue-1"),
+                Bytes::from_static(b"issue-2")
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn lists_mints_by_transaction_and_recipient() {
+        let mints = InMemoryContributionMintRepository::new();
+        mints
+            .apply(&[
+                minted(10, 1, ALICE, "issue-1"),
+                minted(10, 3, BOB, "issue-2"),
+                minted(20, 1, ALICE, "issue-3"),
+            ])
+            .await
+            .unwrap();
+
+        let tx_hash = location(TGC, 10, 0).tx_hash;
+        let (status, body) = get(
+            app(mints.clone()),
+            &format!("/contribution-mints?tx_hash={tx_hash}"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let recipients: Vec<&str> = body
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| m["recipient"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            recipients,
+            vec![
+                ALICE.to_string().to_lowercase(),
+                BOB.to_string().to_lowercase()
+            ]
+        );
+        assert_eq!(body[0]["reason"], "0x69737375652d31");
+        assert_eq!(body[0]["reason_hash"], keccak256("issue-1").to_string());
+
+        let (status, body) = get(
+            app(mints),
+            &format!("/contribution-mints?recipient={ALICE}"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.as_array().unwrap().len(), 2);
+    }
+}
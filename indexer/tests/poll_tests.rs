@@ -0,0 +1,574 @@
+mod common;
+
+#[cfg(test)]
+mod poll_tests {
+    use crate::common::block_timestamp;
+    use std::error::Error;
+    use std::sync::{Arc, Mutex};
+
+    use alloy::primitives::{Address, B256, U256, address};
+    use async_trait::async_trait;
+
+    use indexer::application::commands::poll::{PollConfig, poll};
+    use indexer::domain::entities::block_cursor::BlockCursor;
+    use indexer::domain::entities::block_header::BlockHeader;
+    use indexer::domain::entities::ethereum_event::{
+        EthereumEvent, EthereumEventType, LogLocation,
+    };
+    use indexer::domain::entities::event_source::{EventSource, EventSourceKind};
+    use indexer::domain::repositories::block_cursor_repository::BlockCursorRepository;
+    use indexer::domain::repositories::ethereum_event_repository::EthereumEventRepository;
+    use indexer::domain::repositories::event_projection::EventProjection;
+    use indexer::domain::services::ethereum_event_polling_service::EthereumEventPollingService;
+    use indexer::infrastructure::repositories::in_memory_block_cursor_repository::InMemoryBlockCursorRepository;
+    use indexer::infrastructure::repositories::in_memory_ethereum_event_repository::InMemoryEthereumEventRepository;
+
+    const TOKEN: Address = address!("0x1111111111111111111111111111111111111111");
+    const OTHER_TOKEN: Address = address!("0x3333333333333333333333333333333333333333");
+    const RECIPIENT: Address = address!("0x2222222222222222222222222222222222222222");
+
+    // ========================================================================
+    // Fake implementations for testing
+    // ========================================================================
+
+    fn block_hash(number: u64, fork: u64) -> B256 {
+        B256::from(U256::from((fork << 32) | number))
+    }
+
+    struct ChainState {
+        headers: Vec<BlockHeader>,
+        mint_blocks: Vec<u64>,
+    }
+
+    /// Scripted chain with one mint in each of `mint_blocks`. Blocks on a
+    /// different `fork` get different hashes, which is how tests stage a reorg.
+    struct FakeChain {
+        state: Mutex<ChainState>,
+        requested_ranges: Mutex<Vec<(u64, u64)>>,
+        /// Applied by the next `poll`, after the caller read its headers.
+        reorg_during_poll: Mutex<Option<Reorg>>,
+    }
+
+    struct Reorg {
+        from_block: u64,
+        head: u64,
+        fork: u64,
+        mint_blocks: Vec<u64>,
+    }
+
+    impl FakeChain {
+        fn new(head: u64, mint_blocks: Vec<u64>) -> Self {
+            let chain = Self {
+                state: Mutex::new(ChainState {
+                    headers: vec![],
+                    mint_blocks: vec![],
+                }),
+                requested_ranges: Mutex::new(vec![]),
+                reorg_during_poll: Mutex::new(None),
+            };
+            chain.reorg(0, head, 0, mint_blocks);
+            chain
+        }
+
+        /// Replaces every block from `from_block` onward with blocks of `fork`
+        /// up to the new `head`, and the mints in them with `mint_blocks`.
+        fn reorg(&self, from_block: u64, head: u64, fork: u64, mint_blocks: Vec<u64>) {
+            let mut state = self.state.lock().unwrap();
+            state.headers.truncate(from_block as usize);
+            for number in from_block..=head {
+                let parent_hash = match number {
+                    0 => B256::ZERO,
+                    _ => state.headers[number as usize - 1].hash,
+                };
+                state.headers.push(BlockHeader {
+                    number,
+                    hash: block_hash(number, fork),
+                    parent_hash,
+                });
+            }
+            state.mint_blocks.retain(|block| *block < from_block);
+            state.mint_blocks.extend(mint_blocks);
+            self.requested_ranges.lock().unwrap().clear();
+        }
+
+        fn hash_of(&self, number: u64) -> B256 {
+            self.state.lock().unwrap().headers[number as usize].hash
+        }
+
+        fn requested_ranges(&self) -> Vec<(u64, u64)> {
+            self.requested_ranges.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl EthereumEventPollingService for FakeChain {
+        async fn chain_id(&self) -> Result<u64, Box<dyn Error>> {
+            Ok(31337)
+        }
+
+        async fn latest_block(&self) -> Result<u64, Box<dyn Error>> {
+            Ok(self.state.lock().unwrap().headers.len() as u64 - 1)
+        }
+
+        async fn block_header(&self, number: u64) -> Result<BlockHeader, Box<dyn Error>> {
+            let state = self.state.lock().unwrap();
+            Ok(*state
+                .headers
+                .get(number as usize)
+                .ok_or_else(|| format!("Block {number} not found"))?)
+        }
+
+        async fn poll(
+            &self,
+            source: &EventSource,
+            from_block: u64,
+            to_block: u64,
+        ) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
+            // Only the activity token mints; other sources have no events.
+            if source.address != TOKEN {
+                return Ok(vec![]);
+            }
+            let pending = self.reorg_during_poll.lock().unwrap().take();
+            if let Some(reorg) = pending {
+                self.reorg(reorg.from_block, reorg.head, reorg.fork, reorg.mint_blocks);
+            }
+            self.requested_ranges
+                .lock()
+                .unwrap()
+                .push((from_block, to_block));
+            let state = self.state.lock().unwrap();
+            Ok(state
+                .mint_blocks
+                .iter()
+                .filter(|block| (from_block..=to_block).contains(*block))
+                .map(|block| {
+                    let location = LogLocation {
+                        contract_address: source.address,
+                        block_number: *block,
+                        block_hash: state.headers[*block as usize].hash,
+                        block_timestamp: block_timestamp(*block),
+                        tx_hash: B256::from(U256::from(*block)),
+                        log_index: 0,
+                    };
+                    EthereumEvent::new_activity_token_minted_from_transfer_data(
+                        location,
+                        RECIPIENT,
+                        U256::from(10),
+                    )
+                })
+                .collect())
+        }
+
+        async fn token_balance(
+            &self,
+            _token: &Address,
+            _holder: &Address,
+            _block_number: u64,
+        ) -> Result<U256, Box<dyn Error>> {
+            Err("only logs and headers are scripted".into())
+        }
+
+        async fn token_total_supply(
+            &self,
+            _token: &Address,
+            _block_number: u64,
+        ) -> Result<U256, Box<dyn Error>> {
+            Err("only logs and headers are scripted".into())
+        }
+    }
+
+    /// Records what the poll feeds it.
+    #[derive(Default)]
+    struct RecordingProjection {
+        applied: Mutex<Vec<u64>>,
+        rollbacks: Mutex<Vec<(Address, u64)>>,
+    }
+
+    #[async_trait]
+    impl EventProjection for RecordingProjection {
+        async fn apply(&self, ethereum_events: &[EthereumEvent]) -> Result<(), Box<dyn Error>> {
+            self.applied
+                .lock()
+                .unwrap()
+                .extend(ethereum_events.iter().map(|e| e.block_number));
+            Ok(())
+        }
+
+        async fn rollback(
+            &self,
+            contract_address: &Address,
+            from_block: u64,
+        ) -> Result<(), Box<dyn Error>> {
+            self.rollbacks
+                .lock()
+                .unwrap()
+                .push((*contract_address, from_block));
+            Ok(())
+        }
+    }
+
+    async fn blocks(events: &InMemoryEthereumEventRepository) -> Vec<u64> {
+        events
+            .list()
+            .await
+            .unwrap()
+            .iter()
+            .map(|e| e.block_number)
+            .collect()
+    }
+
+    fn config(start_block: u64, chunk_size: u64, confirmations: u64) -> PollConfig {
+        PollConfig {
+            sources: vec![EventSource {
+                kind: EventSourceKind::ActivityToken,
+                address: TOKEN,
+                start_block,
+                schema_id: None,
+            }],
+            chunk_size,
+            confirmations,
+        }
+    }
+
+    // ========================================================================
+    // Tests
+    // ========================================================================
+
+    #[tokio::test]
+    async fn backfills_from_start_block_in_chunks() {
+        let chain = Arc::new(FakeChain::new(125, vec![99, 100, 117, 125]));
+        let events = Arc::new(InMemoryEthereumEventRepository::new());
+        let cursors = Arc::new(InMemoryBlockCursorRepository::new());
+
+        poll(
+            chain.clone(),
+            events.clone(),
+            cursors.clone(),
+            &[],
+            &config(100, 10, 0),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            chain.requested_ranges(),
+            vec![(100, 109), (110, 119), (120, 125)]
+        );
+        // The mint before the start block is never requested.
+        assert_eq!(blocks(&events).await, vec![100, 117, 125]);
+        let cursor = cursors.find(&TOKEN).await.unwrap().unwrap();
+        assert_eq!(cursor.last_indexed_block, 125);
+        assert_eq!(cursor.block_hash, chain.hash_of(125));
+    }
+
+    #[tokio::test]
+    async fn resumes_from_stored_cursor() {
+        let chain = Arc::new(FakeChain::new(130, vec![105, 121, 130]));
+        let events = Arc::new(InMemoryEthereumEventRepository::new());
+        let cursors = Arc::new(InMemoryBlockCursorRepository::new());
+        cursors
+            .save(&BlockCursor::new(TOKEN, 120, chain.hash_of(120)))
+            .await
+            .unwrap();
+
+        poll(
+            chain.clone(),
+            events.clone(),
+            cursors.clone(),
+            &[],
+            &config(100, 50, 0),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(chain.requested_ranges(), vec![(121, 130)]);
+        assert_eq!(blocks(&events).await, vec![121, 130]);
+        assert_eq!(
+            cursors
+                .find(&TOKEN)
+                .await
+                .unwrap()
+                .unwrap()
+                .last_indexed_block,
+            130
+        );
+    }
+
+    #[tokio::test]
+    async fn does_nothing_when_cursor_is_at_head() {
+        let chain = Arc::new(FakeChain::new(130, vec![130]));
+        let events = Arc::new(InMemoryEthereumEventRepository::new());
+        let cursors = Arc::new(InMemoryBlockCursorRepository::new());
+        cursors
+            .save(&BlockCursor::new(TOKEN, 130, chain.hash_of(130)))
+            .await
+            .unwrap();
+
+        poll(
+            chain.clone(),
+            events.clone(),
+            cursors,
+            &[],
+            &config(100, 50, 0),
+        )
+        .await
+        .unwrap();
+
+        assert!(chain.requested_ranges().is_empty());
+        assert!(blocks(&events).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn stops_at_confirmation_depth() {
+        let chain = Arc::new(FakeChain::new(130, vec![110, 126]));
+        let events = Arc::new(InMemoryEthereumEventRepository::new());
+        let cursors = Arc::new(InMemoryBlockCursorRepository::new());
+
+        poll(
+            chain.clone(),
+            events.clone(),
+            cursors.clone(),
+            &[],
+            &config(100, 50, 5),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(chain.requested_ranges(), vec![(100, 125)]);
+        assert_eq!(blocks(&events).await, vec![110]);
+        assert_eq!(
+            cursors
+                .find(&TOKEN)
+                .await
+                .unwrap()
+                .unwrap()
+                .last_indexed_block,
+            125
+        );
+    }
+
+    #[tokio::test]
+    async fn rolls_back_and_replays_after_reorg() {
+        let chain = Arc::new(FakeChain::new(130, vec![122, 124]));
+        let events = Arc::new(InMemoryEthereumEventRepository::new());
+        let cursors = Arc::new(InMemoryBlockCursorRepository::new());
+        let config = config(100, 10, 5);
+
+        poll(chain.clone(), events.clone(), cursors.clone(), &[], &config)
+            .await
+            .unwrap();
+        assert_eq!(blocks(&events).await, vec![122, 124]);
+
+        // Blocks from 124 are replaced: the mint in 124 is dropped and a new
+        // one lands in 126.
+        chain.reorg(124, 135, 1, vec![126]);
+        poll(chain.clone(), events.clone(), cursors.clone(), &[], &config)
+            .await
+            .unwrap();
+
+        // The fork is noticed at block 126, whose parent is no longer the
+        // stored 125. Walking back, the cursor saved at 119 is still on the
+        // chain, so the replay starts at 120.
+        assert_eq!(chain.requested_ranges(), vec![(120, 129), (130, 130)]);
+        assert_eq!(blocks(&events).await, vec![122, 126]);
+        let stored = events.list().await.unwrap();
+        assert!(
+            stored
+                .iter()
+                .all(|e| e.block_hash == chain.hash_of(e.block_number))
+        );
+        let cursor = cursors.find(&TOKEN).await.unwrap().unwrap();
+        assert_eq!(cursor.last_indexed_block, 130);
+        assert_eq!(cursor.block_hash, chain.hash_of(130));
+    }
+
+    #[tokio::test]
+    async fn rolls_back_the_last_indexed_block_without_confirmations() {
+        let chain = Arc::new(FakeChain::new(130, vec![130]));
+        let events = Arc::new(InMemoryEthereumEventRepository::new());
+        let cursors = Arc::new(InMemoryBlockCursorRepository::new());
+        let config = config(100, 10, 0);
+
+        poll(chain.clone(), events.clone(), cursors.clone(), &[], &config)
+            .await
+            .unwrap();
+
+        // Only the indexed head is replaced, and its mint moves to 131.
+        chain.reorg(130, 132, 1, vec![131]);
+        poll(chain.clone(), events.clone(), cursors.clone(), &[], &config)
+            .await
+            .unwrap();
+
+        assert_eq!(chain.requested_ranges(), vec![(130, 132)]);
+        assert_eq!(blocks(&events).await, vec![131]);
+        let cursor = cursors.find(&TOKEN).await.unwrap().unwrap();
+        assert_eq!(cursor.last_indexed_block, 132);
+        assert_eq!(cursor.block_hash, chain.hash_of(132));
+    }
+
+    #[tokio::test]
+    async fn replays_from_start_block_when_no_saved_cursor_survives() {
+        let chain = Arc::new(FakeChain::new(130, vec![105, 124]));
+        let events = Arc::new(InMemoryEthereumEventRepository::new());
+        let cursors = Arc::new(InMemoryBlockCursorRepository::new());
+        let config = config(100, 50, 0);
+
+        poll(chain.clone(), events.clone(), cursors.clone(), &[], &config)
+            .await
+            .unwrap();
+
+        chain.reorg(110, 135, 1, vec![124]);
+        poll(chain.clone(), events.clone(), cursors.clone(), &[], &config)
+            .await
+            .unwrap();
+
+        assert_eq!(chain.requested_ranges(), vec![(100, 135)]);
+        assert_eq!(blocks(&events).await, vec![105, 124]);
+        assert!(
+            events
+                .list()
+                .await
+                .unwrap()
+                .iter()
+                .all(|e| e.block_hash == chain.hash_of(e.block_number))
+        );
+    }
+
+    #[tokio::test]
+    async fn rereads_a_range_when_the_chain_moves_between_calls() {
+        let chain = Arc::new(FakeChain::new(130, vec![]));
+        let events = Arc::new(InMemoryEthereumEventRepository::new());
+        let cursors = Arc::new(InMemoryBlockCursorRepository::new());
+        // Block 130 is replaced after its header was read, and the logs come
+        // from the new one.
+        *chain.reorg_during_poll.lock().unwrap() = Some(Reorg {
+            from_block: 130,
+            head: 130,
+            fork: 1,
+            mint_blocks: vec![130],
+        });
+
+        poll(
+            chain.clone(),
+            events.clone(),
+            cursors.clone(),
+            &[],
+            &config(100, 50, 0),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(chain.requested_ranges(), vec![(100, 130), (100, 130)]);
+        assert_eq!(blocks(&events).await, vec![130]);
+        let cursor = cursors.find(&TOKEN).await.unwrap().unwrap();
+        assert_eq!(cursor.block_hash, chain.hash_of(130));
+    }
+
+    #[tokio::test]
+    async fn keeps_a_cursor_per_source() {
+        let chain = Arc::new(FakeChain::new(130, vec![110]));
+        let events = Arc::new(InMemoryEthereumEventRepository::new());
+        let cursors = Arc::new(InMemoryBlockCursorRepository::new());
+        let mut config = config(100, 50, 0);
+        config.sources.push(EventSource {
+            kind: EventSourceKind::ActivityToken,
+            address: OTHER_TOKEN,
+            start_block: 120,
+            schema_id: None,
+        });
+        cursors
+            .save(&BlockCursor::new(TOKEN, 125, chain.hash_of(125)))
+            .await
+            .unwrap();
+
+        poll(chain.clone(), events.clone(), cursors.clone(), &[], &config)
+            .await
+            .unwrap();
+
+        assert_eq!(chain.requested_ranges(), vec![(126, 130)]);
+        let other = cursors.find(&OTHER_TOKEN).await.unwrap().unwrap();
+        assert_eq!(other.last_indexed_block, 130);
+        assert_eq!(
+            cursors
+                .find(&TOKEN)
+                .await
+                .unwrap()
+                .unwrap()
+                .last_indexed_block,
+            130
+        );
+    }
+
+    #[tokio::test]
+    async fn feeds_projections_and_rolls_them_back_on_reorg() {
+        let chain = Arc::new(FakeChain::new(130, vec![122, 124]));
+        let events = Arc::new(InMemoryEthereumEventRepository::new());
+        let cursors = Arc::new(InMemoryBlockCursorRepository::new());
+        let projection = Arc::new(RecordingProjection::default());
+        let projections: Vec<Arc<dyn EventProjection>> = vec![projection.clone()];
+        let config = config(100, 10, 5);
+
+        poll(
+            chain.clone(),
+            events.clone(),
+            cursors.clone(),
+            &projections,
+            &config,
+        )
+        .await
+        .unwrap();
+        assert_eq!(*projection.applied.lock().unwrap(), vec![122, 124]);
+
+        chain.reorg(124, 135, 1, vec![126]);
+        poll(chain.clone(), events, cursors, &projections, &config)
+            .await
+            .unwrap();
+
+        assert_eq!(*projection.rollbacks.lock().unwrap(), vec![(TOKEN, 120)]);
+        assert_eq!(
+            *projection.applied.lock().unwrap(),
+            vec![122, 124, 122, 126]
+        );
+    }
+
+    #[tokio::test]
+    async fn stores_one_event_per_log_and_round_trips_the_payload() {
+        let events = InMemoryEthereumEventRepository::new();
+        let location = |log_index: u64| LogLocation {
+            contract_address: TOKEN,
+            block_number: 7,
+            block_hash: block_hash(7, 0),
+            block_timestamp: block_timestamp(7),
+            tx_hash: B256::from(U256::from(7)),
+            log_index,
+        };
+        let minted = |log_index: u64, amount: u64| {
+            EthereumEvent::new_activity_token_minted_from_transfer_data(
+                location(log_index),
+                RECIPIENT,
+                U256::from(amount),
+            )
+        };
+
+        events
+            .insert_many(vec![minted(10, 1), minted(9, 1)])
+            .await
+            .unwrap();
+        events.insert_many(vec![minted(10, 2)]).await.unwrap();
+
+        let stored = events.list().await.unwrap();
+        let log_indexes: Vec<u64> = stored.iter().map(|e| e.log_index).collect();
+        assert_eq!(log_indexes, vec![9, 10]);
+        assert_eq!(stored[1].id, location(10).event_id());
+        assert_eq!(stored[1].event_type.name(), "ActivityTokenMinted");
+
+        let payload = serde_json::to_value(&stored[1].event_type).unwrap();
+        assert!(payload.get("ActivityTokenMinted").is_some());
+        let decoded: EthereumEventType = serde_json::from_value(payload).unwrap();
+        assert!(matches!(
+            decoded,
+            EthereumEventType::ActivityTokenMinted { recipient, amount }
+                if recipient == RECIPIENT && amount == U256::from(2)
+        ));
+    }
+}
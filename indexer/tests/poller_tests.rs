@@ -0,0 +1,212 @@
+mod common;
+
+#[cfg(test)]
+mod poller_tests {
+    use crate::common::app_state;
+    use std::error::Error;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use alloy::primitives::{Address, B256, U256, address};
+    use async_trait::async_trait;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use tokio::sync::watch;
+    use tokio::time::timeout;
+    use tower::ServiceExt;
+
+    use indexer::application::commands::poll::PollConfig;
+    use indexer::domain::entities::block_header::BlockHeader;
+    use indexer::domain::entities::ethereum_event::EthereumEvent;
+    use indexer::domain::entities::event_source::{EventSource, EventSourceKind};
+    use indexer::domain::repositories::block_cursor_repository::BlockCursorRepository;
+    use indexer::domain::services::ethereum_event_polling_service::EthereumEventPollingService;
+    use indexer::infrastructure::repositories::in_memory_block_cursor_repository::InMemoryBlockCursorRepository;
+    use indexer::presentation::api::{AppState, build_app};
+    use indexer::presentation::poller::{MAX_POLL_BACKOFF, poll_delay, run_poller};
+
+    const TOKEN: Address = address!("0x1111111111111111111111111111111111111111");
+    const HEAD: u64 = 10;
+
+    // ========================================================================
+    // Fake implementations for testing
+    // ========================================================================
+
+    /// A chain without events whose RPC fails the first `outages` calls.
+    struct FlakyChain {
+        outages: usize,
+        latest_block_calls: AtomicUsize,
+    }
+
+    impl FlakyChain {
+        fn new(outages: usize) -> Self {
+            Self {
+                outages,
+                latest_block_calls: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl EthereumEventPollingService for FlakyChain {
+        async fn chain_id(&self) -> Result<u64, Box<dyn Error>> {
+            Ok(1)
+        }
+
+        async fn latest_block(&self) -> Result<u64, Box<dyn Error>> {
+            let calls = self.latest_block_calls.fetch_add(1, Ordering::SeqCst);
+            if calls < self.outages {
+                return Err("connection refused".into());
+            }
+            Ok(HEAD)
+        }
+
+        async fn block_header(&self, number: u64) -> Result<BlockHeader, Box<dyn Error>> {
+            Ok(BlockHeader {
+                number,
+                hash: B256::from(U256::from(number + 1)),
+                parent_hash: B256::from(U256::from(number)),
+            })
+        }
+
+        async fn poll(
+            &self,
+            _source: &EventSource,
+            _from_block: u64,
+            _to_block: u64,
+        ) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
+            Ok(vec![])
+        }
+
+        async fn token_balance(
+            &self,
+            _token: &Address,
+            _holder: &Address,
+            _block_number: u64,
+        ) -> Result<U256, Box<dyn Error>> {
+            Ok(U256::ZERO)
+        }
+
+        async fn token_total_supply(
+            &self,
+            _token: &Address,
+            _block_number: u64,
+        ) -> Result<U256, Box<dyn Error>> {
+            Ok(U256::ZERO)
+        }
+    }
+
+    fn state(chain: Arc<FlakyChain>, cursors: InMemoryBlockCursorRepository) -> AppState {
+        AppState {
+            ethereum_event_polling_service: chain,
+            block_cursor_repository: Arc::new(cursors),
+            poll_config: PollConfig {
+                sources: vec![EventSource {
+                    kind: EventSourceKind::ActivityToken,
+                    address: TOKEN,
+                    start_block: 0,
+                    schema_id: None,
+                }],
+                chunk_size: 100,
+                confirmations: 0,
+            },
+            ..app_state()
+        }
+    }
+
+    async fn indexed_block(cursors: &InMemoryBlockCursorRepository) -> Option<u64> {
+        cursors
+            .find(&TOKEN)
+            .await
+            .unwrap()
+            .map(|c| c.last_indexed_block)
+    }
+
+    // ========================================================================
+    // Tests
+    // ========================================================================
+
+    #[test]
+    fn backs_off_exponentially_up_to_a_cap() {
+        let interval = Duration::from_secs(2);
+        assert_eq!(poll_delay(interval, 0), interval);
+        assert_eq!(poll_delay(interval, 1), Duration::from_secs(4));
+        assert_eq!(poll_delay(interval, 3), Duration::from_secs(16));
+        assert_eq!(poll_delay(interval, 20), MAX_POLL_BACKOFF);
+        assert_eq!(poll_delay(interval, u32::MAX), MAX_POLL_BACKOFF);
+
+        // An interval above the cap is never shortened.
+        let slow = MAX_POLL_BACKOFF * 2;
+        assert_eq!(poll_delay(slow, 4), slow);
+    }
+
+    #[tokio::test]
+    async fn keeps_polling_through_rpc_errors_until_shutdown() {
+        let chain = Arc::new(FlakyChain::new(2));
+        let cursors = InMemoryBlockCursorRepository::new();
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let poller = tokio::spawn(run_poller(
+            state(chain.clone(), cursors.clone()),
+            Duration::from_millis(5),
+            shutdown_rx,
+        ));
+
+        timeout(Duration::from_secs(5), async {
+            while indexed_block(&cursors).await.is_none() {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("poller never recovered from the outage");
+        assert_eq!(indexed_block(&cursors).await, Some(HEAD));
+        assert!(chain.latest_block_calls.load(Ordering::SeqCst) >= 3);
+
+        shutdown_tx.send(true).unwrap();
+        timeout(Duration::from_secs(1), poller)
+            .await
+            .expect("poller ignored the shutdown")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn manual_poll_waits_for_the_poll_in_flight() {
+        let cursors = InMemoryBlockCursorRepository::new();
+        let state = state(Arc::new(FlakyChain::new(0)), cursors.clone());
+        let app = build_app(state.clone());
+
+        let in_flight = state.poll_lock.lock().await;
+        let request =
+            tokio::spawn(app.oneshot(Request::post("/poll/").body(Body::empty()).unwrap()));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!request.is_finished());
+        assert_eq!(indexed_block(&cursors).await, None);
+
+        drop(in_flight);
+        let response = request.await.unwrap().unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(indexed_block(&cursors).await, Some(HEAD));
+    }
+
+    #[tokio::test]
+    async fn manual_poll_reports_failures() {
+        let cursors = InMemoryBlockCursorRepository::new();
+        let app = build_app(state(Arc::new(FlakyChain::new(1)), cursors.clone()));
+
+        let response = app
+            .clone()
+            .oneshot(Request::post("/poll/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(indexed_block(&cursors).await, None);
+
+        // The server keeps serving; the next poll goes through.
+        let response = app
+            .oneshot(Request::post("/poll/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(indexed_block(&cursors).await, Some(HEAD));
+    }
+}
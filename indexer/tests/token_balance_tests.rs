@@ -0,0 +1,345 @@
+mod common;
+
+#[cfg(test)]
+mod token_balance_tests {
+    use crate::common::{app_state, get, location};
+    use std::collections::HashMap;
+    use std::error::Error;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use alloy::primitives::{Address, B256, U256, address};
+    use async_trait::async_trait;
+    use axum::http::StatusCode;
+
+    use indexer::application::commands::poll::PollConfig;
+    use indexer::domain::entities::block_cursor::BlockCursor;
+    use indexer::domain::entities::block_header::BlockHeader;
+    use indexer::domain::entities::ethereum_event::EthereumEvent;
+    use indexer::domain::entities::event_source::{EventSource, EventSourceKind};
+    use indexer::domain::entities::token_transfer::{TokenSymbol, TokenTransfer};
+    use indexer::domain::repositories::block_cursor_repository::BlockCursorRepository;
+    use indexer::domain::repositories::event_projection::EventProjection;
+    use indexer::domain::repositories::token_balance_repository::TokenBalanceRepository;
+    use indexer::domain::services::ethereum_event_polling_service::EthereumEventPollingService;
+    use indexer::infrastructure::repositories::in_memory_block_cursor_repository::InMemoryBlockCursorRepository;
+    use indexer::infrastructure::repositories::in_memory_token_balance_repository::InMemoryTokenBalanceRepository;
+    use indexer::presentation::api::{AppState, build_app};
+
+    const TGA: Address = address!("0x1111111111111111111111111111111111111111");
+    const TGC: Address = address!("0x9999999999999999999999999999999999999999");
+    const ALICE: Address = address!("0x7777777777777777777777777777777777777777");
+    const BOB: Address = address!("0x8888888888888888888888888888888888888888");
+    const CAROL: Address = address!("0xcccccccccccccccccccccccccccccccccccccccc");
+
+    // ========================================================================
+    // Fake implementations for testing
+    // ========================================================================
+
+    /// Answers `balanceOf` and `totalSupply` of the TGC contract.
+    #[derive(Default)]
+    struct FakeChain {
+        balances: HashMap<Address, U256>,
+        total_supply: U256,
+    }
+
+    #[async_trait]
+    impl EthereumEventPollingService for FakeChain {
+        async fn chain_id(&self) -> Result<u64, Box<dyn Error>> {
+            Err("only token reads are scripted".into())
+        }
+
+        async fn latest_block(&self) -> Result<u64, Box<dyn Error>> {
+            Err("only token reads are scripted".into())
+        }
+
+        async fn block_header(&self, _number: u64) -> Result<BlockHeader, Box<dyn Error>> {
+            Err("only token reads are scripted".into())
+        }
+
+        async fn poll(
+            &self,
+            _source: &EventSource,
+            _from_block: u64,
+            _to_block: u64,
+        ) -> Result<Vec<EthereumEvent>, Box<dyn Error>> {
+            Err("only token reads are scripted".into())
+        }
+
+        async fn token_balance(
+            &self,
+            token: &Address,
+            holder: &Address,
+            _block_number: u64,
+        ) -> Result<U256, Box<dyn Error>> {
+            assert_eq!(*token, TGC);
+            Ok(self.balances.get(holder).copied().unwrap_or_default())
+        }
+
+        async fn token_total_supply(
+            &self,
+            token: &Address,
+            _block_number: u64,
+        ) -> Result<U256, Box<dyn Error>> {
+            assert_eq!(*token, TGC);
+            Ok(self.total_supply)
+        }
+    }
+
+    fn tga_minted(block_number: u64, to: Address, amount: u64) -> EthereumEvent {
+        EthereumEvent::new_activity_token_minted_from_transfer_data(
+            location(TGA, block_number, 0),
+            to,
+            U256::from(amount),
+        )
+    }
+
+    fn tgc_transferred(
+        block_number: u64,
+        from: Address,
+        to: Address,
+        amount: u64,
+    ) -> EthereumEvent {
+        EthereumEvent::new_contribution_token_transferred(
+            location(TGC, block_number, 0),
+            from,
+            to,
+            U256::from(amount),
+        )
+    }
+
+    /// Alice gets 100 TGC, sends 30 to Bob, who burns 10; Carol holds TGA.
+    fn history() -> Vec<EthereumEvent> {
+        vec![
+            tgc_transferred(10, Address::ZERO, ALICE, 100),
+            tgc_transferred(11, ALICE, BOB, 30),
+            tgc_transferred(12, BOB, Address::ZERO, 10),
+            tga_minted(13, CAROL, 5),
+            tga_minted(14, ALICE, 1),
+        ]
+    }
+
+    async fn state(balances: InMemoryTokenBalanceRepository, chain: FakeChain) -> AppState {
+        let balances = Arc::new(balances);
+        let cursors = InMemoryBlockCursorRepository::new();
+        cursors
+            .save(&BlockCursor::new(TGC, 20, B256::ZERO))
+            .await
+            .unwrap();
+        AppState {
+            ethereum_event_polling_service: Arc::new(chain),
+            block_cursor_repository: Arc::new(cursors),
+            token_balance_repository: balances.clone(),
+            projections: vec![balances],
+            poll_config: PollConfig {
+                sources: vec![EventSource {
+                    kind: EventSourceKind::ContributionToken,
+                    address: TGC,
+                    start_block: 0,
+                    schema_id: None,
+                }],
+                chunk_size: 1,
+                confirmations: 0,
+            },
+            ..app_state()
+        }
+    }
+
+    async fn app(balances: InMemoryTokenBalanceRepository, chain: FakeChain) -> axum::Router {
+        build_app(state(balances, chain).await)
+    }
+
+    fn amount(value: u64) -> serde_json::Value {
+        serde_json::to_value(U256::from(value)).unwrap()
+    }
+
+    // ========================================================================
+    // Tests
+    // ========================================================================
+
+    #[test]
+    fn transfers_are_read_from_both_tokens() {
+        let mint = TokenTransfer::from_event(&tga_minted(1, ALICE, 5)).unwrap();
+        assert_eq!(mint.symbol, TokenSymbol::Tga);
+        assert_eq!(mint.from, Address::ZERO);
+        assert_eq!(mint.to, ALICE);
+
+        let transfer = TokenTransfer::from_event(&tgc_transferred(2, ALICE, BOB, 3)).unwrap();
+        assert_eq!(transfer.symbol, TokenSymbol::Tgc);
+        assert_eq!(transfer.token_address, TGC);
+        assert_eq!((transfer.from, transfer.to), (ALICE, BOB));
+
+        let upvote = EthereumEvent::new_badge_upvoted(location(TGC, 3, 0), "Rust".into(), ALICE);
+        assert!(TokenTransfer::from_event(&upvote).is_none());
+    }
+
+    #[tokio::test]
+    async fn projection_is_idempotent_and_rolls_back() {
+        let balances = InMemoryTokenBalanceRepository::new();
+        balances.apply(&history()).await.unwrap();
+        balances.apply(&history()).await.unwrap();
+
+        let tgc: Vec<(Address, U256)> = balances
+            .holders(TokenSymbol::Tgc)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|b| (b.holder, b.balance))
+            .collect();
+        assert_eq!(tgc, vec![(ALICE, U256::from(70)), (BOB, U256::from(20))]);
+
+        // Undoing the burn and the transfer leaves Alice's mint only.
+        balances.rollback(&TGC, 11).await.unwrap();
+        let tgc = balances.holders(TokenSymbol::Tgc).await.unwrap();
+        assert_eq!(tgc.len(), 1);
+        assert_eq!(tgc[0].balance, U256::from(100));
+        assert_eq!(balances.holders(TokenSymbol::Tga).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn lists_token_holders() {
+        let balances = InMemoryTokenBalanceRepository::new();
+        balances.apply(&history()).await.unwrap();
+
+        let (status, body) = get(
+            app(balances.clone(), FakeChain::default()).await,
+            "/tokens/TGC/holders",
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["symbol"], "TGC");
+        assert_eq!(body["holder_count"], 2);
+        assert_eq!(
+            body["holders"][0]["holder"],
+            ALICE.to_string().to_lowercase()
+        );
+        assert_eq!(body["holders"][0]["balance"], amount(70));
+
+        let (status, body) = get(
+            app(balances.clone(), FakeChain::default()).await,
+            "/tokens/tga/holders",
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["holder_count"], 2);
+
+        let (status, _) = get(
+            app(balances, FakeChain::default()).await,
+            "/tokens/ETH/holders",
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn lists_account_balances() {
+        let balances = InMemoryTokenBalanceRepository::new();
+        balances.apply(&history()).await.unwrap();
+
+        let (status, body) = get(
+            app(balances.clone(), FakeChain::default()).await,
+            &format!("/accounts/{ALICE}/balances"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let symbols: Vec<&str> = body
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|b| b["symbol"].as_str().unwrap())
+            .collect();
+        assert_eq!(symbols, vec!["TGA", "TGC"]);
+        assert_eq!(body[1]["balance"], amount(70));
+
+        // Never held either token.
+        let (status, body) = get(
+            app(balances, FakeChain::default()).await,
+            &format!("/accounts/{}/balances", Address::repeat_byte(0x42)),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, serde_json::json!([]));
+    }
+
+    #[tokio::test]
+    async fn checks_balances_against_the_token_contract() {
+        let balances = InMemoryTokenBalanceRepository::new();
+        balances.apply(&history()).await.unwrap();
+
+        let consistent = FakeChain {
+            balances: HashMap::from([(ALICE, U256::from(70)), (BOB, U256::from(20))]),
+            total_supply: U256::from(90),
+        };
+        let (status, body) = get(
+            app(balances.clone(), consistent).await,
+            "/tokens/TGC/consistency",
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["block_number"], 20);
+        assert_eq!(body["holders_checked"], 2);
+        assert_eq!(body["mismatches"], serde_json::json!([]));
+        assert_eq!(body["indexed_supply"], body["on_chain_supply"]);
+
+        // A transfer the indexer missed shows on both the holder and the supply.
+        let drifted = FakeChain {
+            balances: HashMap::from([(ALICE, U256::from(60)), (BOB, U256::from(20))]),
+            total_supply: U256::from(95),
+        };
+        let (status, body) = get(
+            app(balances.clone(), drifted).await,
+            "/tokens/TGC/consistency",
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body["mismatches"][0]["holder"],
+            ALICE.to_string().to_lowercase()
+        );
+        assert_eq!(body["mismatches"][0]["indexed"], amount(70));
+        assert_eq!(body["mismatches"][0]["on_chain"], amount(60));
+        assert_eq!(body["on_chain_supply"], amount(95));
+
+        // TGA is not a configured source here.
+        let (status, _) = get(
+            app(balances, FakeChain::default()).await,
+            "/tokens/TGA/consistency",
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn check_waits_for_the_poll_in_flight() {
+        let balances = InMemoryTokenBalanceRepository::new();
+        balances.apply(&history()).await.unwrap();
+
+        // On chain as of block 21, where Bob sends 5 back to Alice.
+        let chain = FakeChain {
+            balances: HashMap::from([(ALICE, U256::from(75)), (BOB, U256::from(15))]),
+            total_supply: U256::from(90),
+        };
+        let state = state(balances.clone(), chain).await;
+
+        let in_flight = state.poll_lock.lock().await;
+        let check = tokio::spawn(get(build_app(state.clone()), "/tokens/TGC/consistency"));
+        // The poll projects block 21 before it moves the cursor onto it.
+        balances
+            .apply(&[tgc_transferred(21, BOB, ALICE, 5)])
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!check.is_finished());
+        state
+            .block_cursor_repository
+            .save(&BlockCursor::new(TGC, 21, B256::ZERO))
+            .await
+            .unwrap();
+        drop(in_flight);
+
+        let (status, body) = check.await.unwrap();
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["block_number"], 21);
+        assert_eq!(body["mismatches"], serde_json::json!([]));
+    }
+}
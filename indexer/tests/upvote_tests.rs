@@ -0,0 +1,136 @@
+mod common;
+
+#[cfg(test)]
+mod upvote_tests {
+    use crate::common::{app_state, block_timestamp, get, location};
+    use std::sync::Arc;
+
+    use alloy::primitives::{Address, address};
+    use axum::http::StatusCode;
+
+    use indexer::domain::entities::badge_upvote::{BadgeUpvote, BadgeVotes};
+    use indexer::domain::entities::ethereum_event::EthereumEvent;
+    use indexer::domain::repositories::badge_upvote_repository::BadgeUpvoteRepository;
+    use indexer::domain::repositories::event_projection::EventProjection;
+    use indexer::infrastructure::repositories::in_memory_badge_upvote_repository::InMemoryBadgeUpvoteRepository;
+    use indexer::presentation::api::{AppState, build_app};
+
+    const RANKING: Address = address!("0x6666666666666666666666666666666666666666");
+    const ALICE: Address = address!("0x7777777777777777777777777777777777777777");
+    const BOB: Address = address!("0x8888888888888888888888888888888888888888");
+
+    fn upvoted(block_number: u64, badge_name: &str, voter: Address) -> EthereumEvent {
+        EthereumEvent::new_badge_upvoted(
+            location(RANKING, block_number, 0),
+            badge_name.to_string(),
+            voter,
+        )
+    }
+
+    fn app(upvotes: InMemoryBadgeUpvoteRepository) -> axum::Router {
+        let upvotes = Arc::new(upvotes);
+        build_app(AppState {
+            badge_upvote_repository: upvotes.clone(),
+            projections: vec![upvotes],
+            ..app_state()
+        })
+    }
+
+    fn votes(badge_name: &str, upvotes: u64) -> BadgeVotes {
+        BadgeVotes {
+            badge_name: badge_name.to_string(),
+            upvotes,
+        }
+    }
+
+    // ========================================================================
+    // Tests
+    // ========================================================================
+
+    #[test]
+    fn upvote_is_read_from_badge_upvoted_events() {
+        let upvote = BadgeUpvote::from_event(&upvoted(7, "Rust", ALICE)).unwrap();
+        assert_eq!(upvote.badge_name, "Rust");
+        assert_eq!(upvote.voter, ALICE);
+        assert_eq!(upvote.ranking_address, RANKING);
+        assert_eq!(upvote.block_number, 7);
+        assert_eq!(upvote.voted_at, block_timestamp(7));
+    }
+
+    #[tokio::test]
+    async fn projection_is_idempotent_and_rolls_back() {
+        let upvotes = InMemoryBadgeUpvoteRepository::new();
+        let events = vec![
+            upvoted(10, "Rust", ALICE),
+            upvoted(20, "Rust", BOB),
+            upvoted(30, "Solidity", BOB),
+        ];
+
+        upvotes.apply(&events).await.unwrap();
+        upvotes.apply(&events).await.unwrap();
+        assert_eq!(
+            upvotes.ranking().await.unwrap(),
+            vec![votes("Rust", 2), votes("Solidity", 1)]
+        );
+
+        // Another contract's reorg does not touch the ranking's votes.
+        upvotes.rollback(&ALICE, 0).await.unwrap();
+        assert_eq!(upvotes.list_by_voter(&BOB).await.unwrap().len(), 2);
+
+        upvotes.rollback(&RANKING, 20).await.unwrap();
+        assert_eq!(upvotes.ranking().await.unwrap(), vec![votes("Rust", 1)]);
+        assert!(upvotes.list_by_voter(&BOB).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn ranks_badges_by_upvotes_then_name() {
+        let upvotes = InMemoryBadgeUpvoteRepository::new();
+        upvotes
+            .apply(&[
+                upvoted(10, "Solidity", ALICE),
+                upvoted(11, "Rust", ALICE),
+                upvoted(12, "Design", ALICE),
+                upvoted(13, "Rust", BOB),
+            ])
+            .await
+            .unwrap();
+
+        let (status, body) = get(app(upvotes), "/upvotes").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body,
+            serde_json::json!([
+                { "badge_name": "Rust", "upvotes": 2 },
+                { "badge_name": "Design", "upvotes": 1 },
+                { "badge_name": "Solidity", "upvotes": 1 },
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn lists_voter_history() {
+        let upvotes = InMemoryBadgeUpvoteRepository::new();
+        upvotes
+            .apply(&[
+                upvoted(20, "Solidity", ALICE),
+                upvoted(10, "Rust", ALICE),
+                upvoted(15, "Rust", BOB),
+            ])
+            .await
+            .unwrap();
+
+        let (status, body) = get(app(upvotes.clone()), &format!("/voters/{ALICE}/upvotes")).await;
+        assert_eq!(status, StatusCode::OK);
+        let badges: Vec<&str> = body
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|u| u["badge_name"].as_str().unwrap())
+            .collect();
+        assert_eq!(badges, vec!["Rust", "Solidity"]);
+
+        let (status, _) = get(app(upvotes), "/voters/not-an-address/upvotes").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
@@ -12,17 +12,17 @@ set -euo pipefail
 # 4) list endpoint
 #
 # Requirements: curl, node, npm. Installs ethers locally into /tmp by default.
+# The recipients 0x1111…1111 and 0x2222…2222 need profiles, and the Contributor and
+# Reviewer badges must be indexed; otherwise registration answers 422.
 #
 # Inputs (env):
 #   ADMIN_ADDRESS      (required) - admin wallet address
 #   ADMIN_PRIVATE_KEY  (required) - admin wallet private key (0x-prefixed)
 #   API_URL            (optional) - defaults to http://localhost:3001
-#   DISTRIBUTION_ID    (optional) - defaults to dist-<timestamp>
 
 API_URL="${API_URL:-http://localhost:3001}"
 ADMIN_ADDRESS="${ADMIN_ADDRESS:-}"
 ADMIN_PRIVATE_KEY="${ADMIN_PRIVATE_KEY:-}"
-DISTRIBUTION_ID="${DISTRIBUTION_ID:-dist-$(date +%s)}"
 
 if [[ -z "${ADMIN_ADDRESS}" ]]; then
   read -r -p "Enter ADMIN_ADDRESS (0x...): " ADMIN_ADDRESS
@@ -99,20 +99,18 @@ payload=$(cat <<EOF
   "distributions": [
     {
       "address": "0x1111111111111111111111111111111111111111",
-      "badgeName": "Contributor",
-      "distributionId": "${DISTRIBUTION_ID}"
+      "badgeName": "Contributor"
     },
     {
       "address": "0x2222222222222222222222222222222222222222",
-      "badgeName": "Reviewer",
-      "distributionId": "${DISTRIBUTION_ID}"
+      "badgeName": "Reviewer"
     }
   ]
 }
 EOF
 )
 
-echo "Posting distributions with distributionId=${DISTRIBUTION_ID}..."
+echo "Posting distributions..."
 post_tmp="$(mktemp)"
 post_status="$(curl -sS -o "${post_tmp}" -w "%{http_code}" -X POST \
   -H "x-eth-address: ${ADMIN_ADDRESS}" \
@@ -129,6 +127,9 @@ if [[ "${post_status}" != "201" ]]; then
   exit 1
 fi
 
+DISTRIBUTION_ID="$(RESP="${post_resp}" python3 -c 'import json, os; print(json.loads(os.environ["RESP"])["distributionIds"][0])')"
+echo "Registered distributionId=${DISTRIBUTION_ID}"
+
 echo "Listing distributions..."
 list_tmp="$(mktemp)"
 list_status="$(curl -sS -o "${list_tmp}" -w "%{http_code}" \